@@ -22,11 +22,11 @@ fn eval_into_vec(
 ) -> Result<Rc<ExprVecRefCell>, EvalError> {
     eval_into_foreign(proc_name, expr, context)?
         .downcast::<ExprVecRefCell>()
-        .or_else(|_| {
-            Err(EvalError {
-                message: format!("{proc_name}: `{expr}` does not evaluate to a vector."),
-                span: expr.span(),
-            })
+        .map_err(|_| {
+            EvalError::new(
+                format!("{proc_name}: `{expr}` does not evaluate to a vector."),
+                expr.span(),
+            )
         })
 }
 
@@ -55,10 +55,10 @@ fn vec_pop(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     if let Some(item) = item {
         Ok(item)
     } else {
-        Err(EvalError {
-            message: format!("{proc_name}: vector is empty."),
-            span: vec_expr.span(),
-        })
+        Err(EvalError::new(
+            format!("{proc_name}: vector is empty."),
+            vec_expr.span(),
+        ))
     }
 }
 
@@ -68,19 +68,19 @@ fn vec_get(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let index = eval_into_int(proc_name, "index", index_expr, context)?;
 
     if index < 0 {
-        return Err(EvalError {
-            message: format!("{proc_name}: index must be zero or positive integer."),
-            span: index_expr.span(),
-        });
+        return Err(EvalError::new(
+            format!("{proc_name}: index must be zero or positive integer."),
+            index_expr.span(),
+        ));
     }
 
     let item = vec.borrow().get(index as usize).cloned();
     if let Some(item) = item {
         Ok(item)
     } else {
-        Err(EvalError {
-            message: format!("{proc_name}: index out-of-bounds {index}."),
-            span: index_expr.span(),
-        })
+        Err(EvalError::new(
+            format!("{proc_name}: index out-of-bounds {index}."),
+            index_expr.span(),
+        ))
     }
 }
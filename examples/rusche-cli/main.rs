@@ -36,6 +36,10 @@ fn run_file(evaluator: Evaluator, path: &str) {
                         print_error("incomplete string", &text, Some(span));
                         return;
                     }
+                    LexError::InvalidChar(span) => {
+                        print_error("invalid character", &text, Some(span));
+                        return;
+                    }
                 },
             };
 
@@ -45,7 +49,7 @@ fn run_file(evaluator: Evaluator, path: &str) {
                     Ok(None) => {
                         break;
                     }
-                    Ok(Some(expr)) => match evaluator.eval(&expr) {
+                    Ok(Some(expr)) => match evaluator.eval_values(&expr) {
                         Ok(_) => {}
                         Err(e) => {
                             print_error(&e.message, &text, e.span);
@@ -95,6 +99,7 @@ fn print_error(message: &str, src: &str, span: Option<Span>) {
             print_line(span.begin.line - 1);
         }
 
+        #[allow(clippy::needless_range_loop)]
         for line in span.begin.line..span.end.line + 1 {
             print_line(line);
 
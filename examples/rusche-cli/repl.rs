@@ -1,5 +1,5 @@
 use colored::Colorize;
-use rusche::{tokenize, Evaluator, LexError, Loc, ParseError, Parser};
+use rusche::{tokenize, Evaluator, Expr, LexError, Loc, ParseError, Parser};
 use rustyline::{error::ReadlineError, DefaultEditor};
 
 use crate::print_error;
@@ -39,13 +39,16 @@ pub fn run_repl(evaluator: Evaluator) {
                             LexError::IncompleteString(span) => {
                                 print_error("incomplete string", &error_src, Some(span))
                             }
+                            LexError::InvalidChar(span) => {
+                                print_error("invalid character", &error_src, Some(span))
+                            }
                         }
                         continue;
                     }
                 }
 
                 src.push_str(&text);
-                src.push_str("\n");
+                src.push('\n');
 
                 loop {
                     match parser.parse() {
@@ -53,9 +56,9 @@ pub fn run_repl(evaluator: Evaluator) {
                             consumed_lines = src.lines().count();
                             break;
                         }
-                        Ok(Some(expr)) => match evaluator.eval(&expr) {
-                            Ok(result) => {
-                                println!("{}", result.to_string().green());
+                        Ok(Some(expr)) => match evaluator.eval_values(&expr) {
+                            Ok(values) => {
+                                println!("{}", Expr::Values(values).to_string().green());
                             }
                             Err(error) => {
                                 print_error(&error.message, &src, error.span);
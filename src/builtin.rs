@@ -1,42 +1,312 @@
 pub mod quote;
 
+mod bytevec;
+mod char;
+mod control;
+mod exception;
+mod hash;
 mod num;
 mod primitive;
+mod promise;
+mod record;
 mod str;
+mod sym;
+mod syntax;
+mod vec;
 
 use std::rc::Rc;
 
 use crate::env::Env;
+use crate::proc::Arity;
 
 pub fn load_builtin(env: &Rc<Env>) {
     // lisp primitives
-    env.define_native_proc("atom?", primitive::atom);
-    env.define_native_proc("car", primitive::car);
-    env.define_native_proc("cdr", primitive::cdr);
-    env.define_native_proc("cons", primitive::cons);
+    env.define_native_proc("and", primitive::and);
+    env.define_native_proc_with_arity("atom?", primitive::atom, Arity::exact(1));
+    env.define_native_proc("begin", primitive::begin);
+    env.define_native_proc_with_arity("car", primitive::car, Arity::exact(1));
+    env.define_native_proc("case", primitive::case);
+    env.define_native_proc("case-lambda", primitive::case_lambda);
+    env.define_native_proc_with_arity("cdr", primitive::cdr, Arity::exact(1));
+    env.define_native_proc("cond", primitive::cond);
+    env.define_native_proc_with_arity("cons", primitive::cons, Arity::exact(2));
     env.define_native_proc("define", primitive::define);
     env.define_native_proc("defmacro", primitive::defmacro);
-    env.define_native_proc("eq?", primitive::eq);
-    env.define_native_proc("eval", primitive::eval_);
+    env.define_native_proc("define-record-type", record::define_record_type);
+    env.define_native_proc("do", primitive::do_);
+    env.define_native_proc_with_arity("eq?", primitive::eq, Arity::exact(2));
+    env.define_native_proc_with_arity("equal?", primitive::equal, Arity::exact(2));
+    env.define_native_proc_with_arity("eqv?", primitive::eqv, Arity::exact(2));
+    env.define_native_proc_with_arity("eval", primitive::eval_, Arity::exact(1));
     env.define_native_proc("if", primitive::if_);
     env.define_native_proc("lambda", primitive::lambda);
+    env.define_native_proc("let", primitive::let_);
+    env.define_native_proc("let*", primitive::let_star);
+    env.define_native_proc("letrec", primitive::letrec);
+    env.define_native_proc_with_arity("list", primitive::list_, Arity::at_least(0));
+    env.define_native_proc("letrec*", primitive::letrec_star);
+    env.define_native_proc_with_arity("list?", primitive::is_list, Arity::exact(1));
+    env.define_native_proc("or", primitive::or);
+    env.define_native_proc_with_arity("pair?", primitive::is_pair, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "procedure-arity",
+        primitive::procedure_arity,
+        Arity::exact(1),
+    );
     env.define_native_proc("set!", primitive::set);
+    env.define_native_proc("unless", primitive::unless);
+    env.define_native_proc("when", primitive::when);
+    env.define_native_proc("while", primitive::while_);
+
+    // control
+    env.define_native_proc_with_arity("call/cc", control::call_cc, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "call-with-current-continuation",
+        control::call_cc,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity("dynamic-wind", control::dynamic_wind, Arity::exact(3));
+    env.define_native_proc_with_arity("values", control::values, Arity::at_least(0));
+    env.define_native_proc_with_arity(
+        "call-with-values",
+        control::call_with_values,
+        Arity::exact(2),
+    );
+    env.define_native_proc("receive", control::receive);
+    env.define_native_proc("let-values", control::let_values);
+    env.define_native_proc("let*-values", control::let_star_values);
+    env.define_native_proc("define-values", control::define_values);
+    env.define_native_proc_with_arity(
+        "make-parameter",
+        control::make_parameter,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc("parameterize", control::parameterize);
+
+    // promise
+    env.define_native_proc("delay", promise::delay);
+    env.define_native_proc("delay-force", promise::delay_force);
+    env.define_native_proc_with_arity("force", promise::force, Arity::exact(1));
+    env.define_native_proc_with_arity("make-promise", promise::make_promise, Arity::exact(1));
+    env.define_native_proc_with_arity("promise?", promise::is_promise, Arity::exact(1));
+
+    // syntax
+    env.define_native_proc("syntax-rules", syntax::syntax_rules);
+    env.define_native_proc("define-syntax", syntax::define_syntax);
+    env.define_native_proc("let-syntax", syntax::let_syntax);
+    env.define_native_proc("letrec-syntax", syntax::letrec_syntax);
+    env.define_native_proc_with_arity("macroexpand-1", syntax::macroexpand_1, Arity::exact(1));
+    env.define_native_proc_with_arity("macroexpand", syntax::macroexpand, Arity::exact(1));
+
+    // exception
+    env.define_native_proc_with_arity("raise", exception::raise, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "raise-continuable",
+        exception::raise_continuable,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity("error", exception::error, Arity::at_least(1));
+    env.define_native_proc_with_arity(
+        "with-exception-handler",
+        exception::with_exception_handler,
+        Arity::exact(2),
+    );
+    env.define_native_proc("guard", exception::guard);
+    env.define_native_proc_with_arity("error-object?", exception::is_error_object, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "error-object-message",
+        exception::error_object_message,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity(
+        "error-object-irritants",
+        exception::error_object_irritants,
+        Arity::exact(1),
+    );
 
     // num
-    env.define_native_proc("num?", num::is_num);
-    env.define_native_proc("num-add", num::add);
-    env.define_native_proc("num-subtract", num::subtract);
-    env.define_native_proc("num-multiply", num::multiply);
-    env.define_native_proc("num-divide", num::divide);
-    env.define_native_proc("num-modulo", num::modulo);
-    env.define_native_proc("num-less", num::less);
-    env.define_native_proc("num-greater", num::greater);
-    env.define_native_proc("num-parse", num::parse);
+    env.define_native_proc_with_arity("num?", num::is_num, Arity::exact(1));
+    env.define_native_proc_with_arity("num-add", num::add, Arity::at_least(0));
+    env.define_native_proc_with_arity("num-subtract", num::subtract, Arity::at_least(1));
+    env.define_native_proc_with_arity("num-multiply", num::multiply, Arity::at_least(0));
+    env.define_native_proc_with_arity("num-divide", num::divide, Arity::at_least(1));
+    env.define_native_proc_with_arity("num-modulo", num::modulo, Arity::exact(2));
+    env.define_native_proc_with_arity("num-equal", num::equal, Arity::exact(2));
+    env.define_native_proc_with_arity("num-less", num::less, Arity::exact(2));
+    env.define_native_proc_with_arity("num-greater", num::greater, Arity::exact(2));
+    env.define_native_proc_with_arity("num-parse", num::parse, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "number->string",
+        num::number_to_string,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc_with_arity(
+        "string->number",
+        num::string_to_number,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc_with_arity("exact?", num::is_exact, Arity::exact(1));
+    env.define_native_proc_with_arity("inexact?", num::is_inexact, Arity::exact(1));
+    env.define_native_proc_with_arity("exact->inexact", num::exact_to_inexact, Arity::exact(1));
+    env.define_native_proc_with_arity("inexact->exact", num::inexact_to_exact, Arity::exact(1));
+    env.define_native_proc_with_arity("numerator", num::numerator, Arity::exact(1));
+    env.define_native_proc_with_arity("denominator", num::denominator, Arity::exact(1));
+    env.define_native_proc_with_arity("floor", num::floor, Arity::exact(1));
+    env.define_native_proc_with_arity("ceiling", num::ceiling, Arity::exact(1));
+    env.define_native_proc_with_arity("round", num::round, Arity::exact(1));
+    env.define_native_proc_with_arity("truncate", num::truncate, Arity::exact(1));
 
     // str
-    env.define_native_proc("str?", str::is_str);
-    env.define_native_proc("str-append", str::append);
-    env.define_native_proc("str-compare", str::compare);
-    env.define_native_proc("str-length", str::length);
-    env.define_native_proc("str-slice", str::slice);
+    env.define_native_proc_with_arity("str?", str::is_str, Arity::exact(1));
+    env.define_native_proc_with_arity("str-append", str::append, Arity::at_least(0));
+    env.define_native_proc_with_arity("str-compare", str::compare, Arity::exact(2));
+    env.define_native_proc_with_arity("str-length", str::length, Arity::exact(1));
+    env.define_native_proc_with_arity("str-slice", str::slice, Arity::between(2, 3));
+    env.define_native_proc_with_arity("string-ref", str::string_ref, Arity::exact(2));
+    env.define_native_proc_with_arity("string->list", str::string_to_list, Arity::exact(1));
+    env.define_native_proc_with_arity("list->string", str::list_to_string, Arity::exact(1));
+    env.define_native_proc_with_arity("string-split", str::string_split, Arity::between(1, 2));
+    env.define_native_proc_with_arity("string-join", str::string_join, Arity::between(1, 2));
+    env.define_native_proc_with_arity("string-index", str::string_index, Arity::exact(2));
+    env.define_native_proc_with_arity("string-contains", str::string_contains, Arity::exact(2));
+    env.define_native_proc_with_arity("string-prefix?", str::is_string_prefix, Arity::exact(2));
+    env.define_native_proc_with_arity("string-suffix?", str::is_string_suffix, Arity::exact(2));
+    env.define_native_proc_with_arity("string-upcase", str::string_upcase, Arity::exact(1));
+    env.define_native_proc_with_arity("string-downcase", str::string_downcase, Arity::exact(1));
+    env.define_native_proc_with_arity("string-foldcase", str::string_foldcase, Arity::exact(1));
+    env.define_native_proc_with_arity("string-trim", str::string_trim, Arity::between(1, 2));
+    env.define_native_proc_with_arity(
+        "string-trim-right",
+        str::string_trim_right,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc_with_arity(
+        "string-trim-both",
+        str::string_trim_both,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc_with_arity("string-replace", str::string_replace, Arity::exact(3));
+    env.define_native_proc_with_arity("string-pad", str::string_pad, Arity::between(2, 3));
+    env.define_native_proc_with_arity(
+        "string-pad-right",
+        str::string_pad_right,
+        Arity::between(2, 3),
+    );
+    env.define_native_proc_with_arity("string-copy", str::string_copy, Arity::between(1, 3));
+
+    // sym
+    env.define_native_proc_with_arity("symbol?", sym::is_symbol, Arity::exact(1));
+    env.define_native_proc_with_arity("string->symbol", sym::string_to_symbol, Arity::exact(1));
+    env.define_native_proc_with_arity("symbol->string", sym::symbol_to_string, Arity::exact(1));
+    env.define_native_proc_with_arity("gensym", sym::gensym, Arity::between(0, 1));
+
+    // char
+    env.define_native_proc_with_arity("char?", char::is_char, Arity::exact(1));
+    env.define_native_proc_with_arity("char->integer", char::char_to_integer, Arity::exact(1));
+    env.define_native_proc_with_arity("integer->char", char::integer_to_char, Arity::exact(1));
+    env.define_native_proc_with_arity("char-upcase", char::char_upcase, Arity::exact(1));
+    env.define_native_proc_with_arity("char-downcase", char::char_downcase, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "char-alphabetic?",
+        char::is_char_alphabetic,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity("char-numeric?", char::is_char_numeric, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "char-whitespace?",
+        char::is_char_whitespace,
+        Arity::exact(1),
+    );
+
+    // vec
+    env.define_native_proc_with_arity("vector?", vec::is_vector, Arity::exact(1));
+    env.define_native_proc_with_arity("vector", vec::vector, Arity::at_least(0));
+    env.define_native_proc_with_arity("make-vector", vec::make_vector, Arity::between(1, 2));
+    env.define_native_proc_with_arity("vector-length", vec::vector_length, Arity::exact(1));
+    env.define_native_proc_with_arity("vector-ref", vec::vector_ref, Arity::exact(2));
+    env.define_native_proc_with_arity("vector-set!", vec::vector_set, Arity::exact(3));
+    env.define_native_proc_with_arity("vector->list", vec::vector_to_list, Arity::between(1, 3));
+    env.define_native_proc_with_arity("list->vector", vec::list_to_vector, Arity::exact(1));
+    env.define_native_proc_with_arity("vector-fill!", vec::vector_fill, Arity::between(2, 4));
+    env.define_native_proc_with_arity("vector-map", vec::vector_map, Arity::at_least(2));
+    env.define_native_proc_with_arity("vector-for-each", vec::vector_for_each, Arity::at_least(2));
+
+    // bytevec
+    env.define_native_proc_with_arity("bytevector?", bytevec::is_bytevector, Arity::exact(1));
+    env.define_native_proc_with_arity("bytevector", bytevec::bytevector, Arity::at_least(0));
+    env.define_native_proc_with_arity(
+        "make-bytevector",
+        bytevec::make_bytevector,
+        Arity::between(1, 2),
+    );
+    env.define_native_proc_with_arity(
+        "bytevector-length",
+        bytevec::bytevector_length,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity(
+        "bytevector-u8-ref",
+        bytevec::bytevector_u8_ref,
+        Arity::exact(2),
+    );
+    env.define_native_proc_with_arity(
+        "bytevector-u8-set!",
+        bytevec::bytevector_u8_set,
+        Arity::exact(3),
+    );
+    env.define_native_proc_with_arity(
+        "bytevector-copy",
+        bytevec::bytevector_copy,
+        Arity::between(1, 3),
+    );
+    env.define_native_proc_with_arity(
+        "bytevector-append",
+        bytevec::bytevector_append,
+        Arity::at_least(0),
+    );
+    env.define_native_proc_with_arity(
+        "utf8->string",
+        bytevec::utf8_to_string,
+        Arity::between(1, 3),
+    );
+    env.define_native_proc_with_arity("string->utf8", bytevec::string_to_utf8, Arity::exact(1));
+
+    // hash
+    env.define_native_proc_with_arity("hash-table?", hash::is_hash_table, Arity::exact(1));
+    env.define_native_proc_with_arity("make-hash-table", hash::make_hash_table, Arity::exact(0));
+    env.define_native_proc_with_arity(
+        "alist->hash-table",
+        hash::alist_to_hash_table,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity("hash-table-ref", hash::hash_table_ref, Arity::between(2, 3));
+    env.define_native_proc_with_arity(
+        "hash-table-ref/default",
+        hash::hash_table_ref_default,
+        Arity::exact(3),
+    );
+    env.define_native_proc_with_arity("hash-table-set!", hash::hash_table_set, Arity::exact(3));
+    env.define_native_proc_with_arity(
+        "hash-table-delete!",
+        hash::hash_table_delete,
+        Arity::exact(2),
+    );
+    env.define_native_proc_with_arity(
+        "hash-table-contains?",
+        hash::hash_table_contains,
+        Arity::exact(2),
+    );
+    env.define_native_proc_with_arity("hash-table-size", hash::hash_table_size, Arity::exact(1));
+    env.define_native_proc_with_arity("hash-table-keys", hash::hash_table_keys, Arity::exact(1));
+    env.define_native_proc_with_arity(
+        "hash-table-values",
+        hash::hash_table_values,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity(
+        "hash-table->alist",
+        hash::hash_table_to_alist,
+        Arity::exact(1),
+    );
+    env.define_native_proc_with_arity("hash-table-walk", hash::hash_table_walk, Arity::exact(2));
 }
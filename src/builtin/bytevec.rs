@@ -0,0 +1,246 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::vec::{eval_into_index, eval_into_range};
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    list::List,
+    utils::{
+        eval_into_bytes, eval_into_bytevector, eval_into_int, eval_into_str, get_exact_1_arg,
+        get_exact_2_args, get_exact_3_args,
+    },
+};
+
+fn make_bytevector_expr(bytes: Vec<u8>) -> Expr {
+    Expr::Bytevector(Rc::new(RefCell::new(bytes)), None)
+}
+
+fn eval_into_byte(proc_name: &str, expr: &Expr, context: &EvalContext) -> Result<u8, EvalError> {
+    let value = eval_into_int(proc_name, "byte", expr, context)?;
+    u8::try_from(value)
+        .map_err(|_| EvalError::new(format!("{proc_name}: {value} is not a byte."), expr.span()))
+}
+
+pub fn is_bytevector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    Ok(matches!(eval(arg, context)?, Expr::Bytevector(_, _)).into())
+}
+
+pub fn bytevector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let bytes = args
+        .iter()
+        .map(|arg| eval_into_byte(proc_name, arg, context))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(make_bytevector_expr(bytes))
+}
+
+pub fn make_bytevector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(len_expr), fill_expr, None) = (iter.next(), iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects 1 or 2 arguments."
+        )));
+    };
+
+    let len = eval_into_int(proc_name, "length", len_expr, context)?;
+    if len < 0 {
+        return Err(EvalError::new(
+            format!("{proc_name}: length must be zero or positive integer."),
+            len_expr.span(),
+        ));
+    }
+
+    let fill = match fill_expr {
+        Some(expr) => eval_into_byte(proc_name, expr, context)?,
+        None => 0,
+    };
+
+    Ok(make_bytevector_expr(vec![fill; len as usize]))
+}
+
+pub fn bytevector_length(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let bytes = eval_into_bytes(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let len = bytes.borrow().len();
+    Ok(Expr::from(len as i64))
+}
+
+pub fn bytevector_u8_ref(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (bytevector_expr, index_expr) = get_exact_2_args(proc_name, args)?;
+    let bytes = eval_into_bytes(proc_name, bytevector_expr, context)?;
+    let len = bytes.borrow().len();
+    let index = eval_into_index(proc_name, index_expr, len, context)?;
+
+    let byte = bytes.borrow()[index];
+    Ok(Expr::from(byte as i64))
+}
+
+pub fn bytevector_u8_set(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (bytevector_expr, index_expr, byte_expr) = get_exact_3_args(proc_name, args)?;
+    let bytevector = eval_into_bytevector(proc_name, bytevector_expr, context)?;
+    let len = bytevector.borrow().len();
+    let index = eval_into_index(proc_name, index_expr, len, context)?;
+    let byte = eval_into_byte(proc_name, byte_expr, context)?;
+
+    bytevector.borrow_mut()[index] = byte;
+    Ok(NIL)
+}
+
+pub fn bytevector_copy(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(bytevector_expr) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name} needs an argument.")));
+    };
+    let bytes = eval_into_bytes(proc_name, bytevector_expr, context)?;
+    let len = bytes.borrow().len();
+    let (start, end) = eval_into_range(proc_name, iter, len, context)?;
+
+    let bytes = bytes.borrow()[start..end].to_vec();
+    Ok(make_bytevector_expr(bytes))
+}
+
+pub fn bytevector_append(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut bytes = Vec::new();
+    for arg in args.iter() {
+        let arg_bytes = eval_into_bytes(proc_name, arg, context)?;
+        bytes.extend_from_slice(&arg_bytes.borrow());
+    }
+    Ok(make_bytevector_expr(bytes))
+}
+
+pub fn utf8_to_string(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(bytevector_expr) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name} needs an argument.")));
+    };
+    let bytes = eval_into_bytes(proc_name, bytevector_expr, context)?;
+    let len = bytes.borrow().len();
+    let (start, end) = eval_into_range(proc_name, iter, len, context)?;
+
+    let bytes = bytes.borrow();
+    let text = std::str::from_utf8(&bytes[start..end]).map_err(|_| {
+        EvalError::new(
+            format!("{proc_name}: `{bytevector_expr}` is not a valid UTF-8 sequence."),
+            bytevector_expr.span(),
+        )
+    })?;
+    Ok(Expr::from(text.to_owned()))
+}
+
+pub fn string_to_utf8(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(make_bytevector_expr(text.into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_is_bytevector() {
+        setup_native_proc_test!(is_bytevector);
+
+        assert_eq!(
+            is_bytevector(list!(make_bytevector_expr(vec![1]))),
+            Ok(true.into())
+        );
+        assert_eq!(is_bytevector(list!("abc")), Ok(false.into()));
+    }
+
+    #[test]
+    fn test_bytevector() {
+        setup_native_proc_test!(bytevector);
+
+        assert_eq!(bytevector(list!()), Ok(make_bytevector_expr(vec![])));
+        assert_eq!(
+            bytevector(list!(0, 255)),
+            Ok(make_bytevector_expr(vec![0, 255]))
+        );
+        assert!(bytevector(list!(256)).is_err());
+        assert!(bytevector(list!(-1)).is_err());
+        assert!(bytevector(list!("a")).is_err());
+    }
+
+    #[test]
+    fn test_make_bytevector() {
+        setup_native_proc_test!(make_bytevector);
+
+        assert_eq!(
+            make_bytevector(list!(2)),
+            Ok(make_bytevector_expr(vec![0, 0]))
+        );
+        assert_eq!(
+            make_bytevector(list!(2, 7)),
+            Ok(make_bytevector_expr(vec![7, 7]))
+        );
+        assert!(make_bytevector(list!(2, 300)).is_err());
+        assert!(make_bytevector(list!(-1)).is_err());
+    }
+
+    #[test]
+    fn test_bytevector_u8_ref_set() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        context.env.define("b", make_bytevector_expr(vec![1, 2, 3]));
+        let b = || intern("b");
+
+        assert_eq!(bytevector_u8_ref("", &list!(b(), 0), context), Ok(1.into()));
+        assert!(bytevector_u8_ref("", &list!(b(), 3), context).is_err());
+
+        assert_eq!(bytevector_u8_set("", &list!(b(), 1, 255), context), Ok(NIL));
+        assert_eq!(
+            context.env.lookup("b"),
+            Some(make_bytevector_expr(vec![1, 255, 3]))
+        );
+        assert!(bytevector_u8_set("", &list!(b(), 1, 256), context).is_err());
+        assert!(bytevector_u8_set("", &list!(b(), 3, 0), context).is_err());
+
+        assert_eq!(bytevector_length("", &list!(b()), context), Ok(3.into()));
+    }
+
+    #[test]
+    fn test_bytevector_copy_append() {
+        setup_native_proc_test!(bytevector_copy);
+
+        let bytes = make_bytevector_expr(vec![1, 2, 3]);
+        assert_eq!(
+            bytevector_copy(list!(bytes.clone(), 1)),
+            Ok(make_bytevector_expr(vec![2, 3]))
+        );
+        assert_eq!(
+            bytevector_copy(list!(bytes.clone(), 0, 2)),
+            Ok(make_bytevector_expr(vec![1, 2]))
+        );
+        assert!(bytevector_copy(list!(bytes.clone(), 2, 4)).is_err());
+
+        setup_native_proc_test!(bytevector_append);
+        assert_eq!(
+            bytevector_append(list!(bytes.clone(), bytes)),
+            Ok(make_bytevector_expr(vec![1, 2, 3, 1, 2, 3]))
+        );
+        assert_eq!(bytevector_append(list!()), Ok(make_bytevector_expr(vec![])));
+    }
+
+    #[test]
+    fn test_utf8_conversion() {
+        setup_native_proc_test!(utf8_to_string);
+        setup_native_proc_test!(string_to_utf8);
+
+        assert_eq!(
+            string_to_utf8(list!("aλ")),
+            Ok(make_bytevector_expr(vec![0x61, 0xce, 0xbb]))
+        );
+        assert_eq!(
+            utf8_to_string(list!(make_bytevector_expr(vec![0x61, 0xce, 0xbb]))),
+            Ok("aλ".into())
+        );
+        assert_eq!(
+            utf8_to_string(list!(make_bytevector_expr(vec![0x61, 0x62]), 1)),
+            Ok("b".into())
+        );
+        assert!(utf8_to_string(list!(make_bytevector_expr(vec![0xce]))).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::Expr,
+    list::List,
+    utils::{eval_into_char, eval_into_int, get_exact_1_arg},
+};
+
+pub fn is_char(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    Ok(matches!(eval(arg, context)?, Expr::Char(_, _)).into())
+}
+
+pub fn char_to_integer(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let ch = eval_into_char(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::from(ch as i64))
+}
+
+pub fn integer_to_char(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    let code = eval_into_int(proc_name, "code point", expr, context)?;
+    match u32::try_from(code).ok().and_then(char::from_u32) {
+        Some(ch) => Ok(Expr::from(ch)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: {code} is not a valid code point."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Maps a character with `f`, keeping the character as is if the mapping does not yield
+/// exactly one character, e.g. `ß` to `SS`.
+fn map_char<I>(proc_name: &str, args: &List, context: &EvalContext, f: fn(char) -> I) -> EvalResult
+where
+    I: Iterator<Item = char>,
+{
+    let ch = eval_into_char(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let mut mapped = f(ch);
+    match (mapped.next(), mapped.next()) {
+        (Some(mapped), None) => Ok(Expr::from(mapped)),
+        _ => Ok(Expr::from(ch)),
+    }
+}
+
+pub fn char_upcase(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    map_char(proc_name, args, context, char::to_uppercase)
+}
+
+pub fn char_downcase(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    map_char(proc_name, args, context, char::to_lowercase)
+}
+
+fn check_char(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+    pred: fn(char) -> bool,
+) -> EvalResult {
+    let ch = eval_into_char(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(pred(ch).into())
+}
+
+pub fn is_char_alphabetic(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    check_char(proc_name, args, context, char::is_alphabetic)
+}
+
+pub fn is_char_numeric(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    check_char(proc_name, args, context, char::is_numeric)
+}
+
+pub fn is_char_whitespace(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    check_char(proc_name, args, context, char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::macros::*;
+
+    #[test]
+    fn test_is_char() {
+        setup_native_proc_test!(is_char);
+
+        assert_eq!(is_char(list!('a')), Ok(true.into()));
+        assert_eq!(is_char(list!("a")), Ok(false.into()));
+        assert!(is_char(list!('a', 'b')).is_err());
+    }
+
+    #[test]
+    fn test_char_integer_conversion() {
+        setup_native_proc_test!(char_to_integer);
+        setup_native_proc_test!(integer_to_char);
+
+        assert_eq!(char_to_integer(list!('A')), Ok(65.into()));
+        assert_eq!(char_to_integer(list!('λ')), Ok(955.into()));
+        assert!(char_to_integer(list!(65)).is_err());
+
+        assert_eq!(integer_to_char(list!(65)), Ok('A'.into()));
+        assert_eq!(integer_to_char(list!(955)), Ok('λ'.into()));
+        assert!(integer_to_char(list!(-1)).is_err());
+        assert!(integer_to_char(list!(0xD800)).is_err());
+        assert!(integer_to_char(list!('A')).is_err());
+    }
+
+    #[test]
+    fn test_char_case() {
+        setup_native_proc_test!(char_upcase);
+        setup_native_proc_test!(char_downcase);
+
+        assert_eq!(char_upcase(list!('a')), Ok('A'.into()));
+        assert_eq!(char_upcase(list!('A')), Ok('A'.into()));
+        assert_eq!(char_upcase(list!('1')), Ok('1'.into()));
+        assert_eq!(char_upcase(list!('ß')), Ok('ß'.into()));
+        assert_eq!(char_downcase(list!('Λ')), Ok('λ'.into()));
+        assert!(char_upcase(list!("a")).is_err());
+    }
+
+    #[test]
+    fn test_char_predicates() {
+        setup_native_proc_test!(is_char_alphabetic);
+        setup_native_proc_test!(is_char_numeric);
+        setup_native_proc_test!(is_char_whitespace);
+
+        assert_eq!(is_char_alphabetic(list!('a')), Ok(true.into()));
+        assert_eq!(is_char_alphabetic(list!('1')), Ok(false.into()));
+        assert_eq!(is_char_numeric(list!('1')), Ok(true.into()));
+        assert_eq!(is_char_numeric(list!('a')), Ok(false.into()));
+        assert_eq!(is_char_whitespace(list!(' ')), Ok(true.into()));
+        assert_eq!(is_char_whitespace(list!('a')), Ok(false.into()));
+    }
+}
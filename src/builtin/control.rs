@@ -0,0 +1,348 @@
+use super::primitive::eval_body;
+use crate::{
+    continuation::Continuation,
+    eval::{eval, eval_values, EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    list::List,
+    parameter::Parameter,
+    proc::Proc,
+    utils::{
+        eval_into_proc, get_exact_1_arg, get_exact_2_args, get_exact_3_args, make_formal_args,
+    },
+};
+
+/// `(call/cc proc)`
+///
+/// Calls `proc` with the current continuation, which can be used to return from `call/cc`
+/// early. Only escaping continuations are supported, i.e. a continuation cannot be invoked
+/// once its `call/cc` has returned.
+pub fn call_cc(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let proc = eval_into_proc(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+
+    let continuation = Continuation::new();
+    let result = proc.apply(
+        [Expr::Proc(Proc::Continuation(continuation.clone()), None)],
+        context,
+    );
+    continuation.deactivate();
+
+    match result {
+        Err(_) => match context.take_escape(&continuation) {
+            Some(value) => Ok(value),
+            None => result,
+        },
+        _ => result,
+    }
+}
+
+/// `(dynamic-wind before thunk after)`
+///
+/// Calls `before`, `thunk` and then `after`, which is called even if `thunk` exits
+/// by an error or by invoking a continuation.
+pub fn dynamic_wind(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (before, thunk, after) = get_exact_3_args(proc_name, args)?;
+    let before = eval_into_proc(proc_name, before, context)?;
+    let thunk = eval_into_proc(proc_name, thunk, context)?;
+    let after = eval_into_proc(proc_name, after, context)?;
+
+    before.apply([], context)?;
+    let result = thunk.apply([], context);
+
+    // Keep a pending escape aside while calling `after`, which may use continuations too.
+    let escape = context.shared.escape.borrow_mut().take();
+    after.apply([], context)?;
+    *context.shared.escape.borrow_mut() = escape;
+
+    result
+}
+
+/// `(values obj ...)`
+///
+/// Returns the objects as multiple values, or the object itself if there is only one.
+pub fn values(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut values = args
+        .iter()
+        .map(|expr| eval(expr, context))
+        .collect::<Result<Vec<_>, _>>()?;
+    if values.len() == 1 {
+        Ok(values.remove(0))
+    } else {
+        Ok(Expr::Values(values))
+    }
+}
+
+/// `(call-with-values producer consumer)`
+///
+/// Calls `producer` with no arguments, and then `consumer` with the values it returns.
+pub fn call_with_values(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (producer, consumer) = get_exact_2_args(proc_name, args)?;
+    let producer = eval_into_proc(proc_name, producer, context)?;
+    let consumer = eval_into_proc(proc_name, consumer, context)?;
+
+    let values = producer.apply([], context)?.into_values();
+    consumer.apply(values, context)
+}
+
+/// Evaluates `init` and binds its values to `formals` in `context`.
+fn bind_values(
+    proc_name: &str,
+    formals: &Expr,
+    init: &Expr,
+    init_context: &EvalContext,
+    context: &EvalContext,
+) -> Result<(), EvalError> {
+    let formal_args = make_formal_args(formals)?;
+    let values = eval_values(init, init_context)?;
+    formal_args
+        .bind(proc_name, values, context)
+        .map_err(|error| EvalError {
+            span: error.span.or(init.span()),
+            ..error
+        })
+}
+
+/// Parses the bindings of a `let-values` or `parameterize` form, i.e. `((formals init) ...)`
+/// or `((param value) ...)`, where `form` describes a binding for error messages.
+fn parse_expr_bindings<'a>(
+    proc_name: &str,
+    expr: Option<&'a Expr>,
+    form: &str,
+) -> Result<Vec<(&'a Expr, &'a Expr)>, EvalError> {
+    let list = match expr {
+        Some(Expr::List(list, _)) if list.is_proper() => list,
+        _ => {
+            return Err(EvalError::new(
+                format!("{proc_name}: expects a list of bindings."),
+                expr.and_then(|e| e.span()),
+            ))
+        }
+    };
+
+    list.iter()
+        .map(|binding| match binding {
+            Expr::List(pair, _) if pair.is_proper() && pair.len() == 2 => {
+                get_exact_2_args(proc_name, pair)
+            }
+            _ => Err(EvalError::new(
+                format!("{proc_name}: `{binding}` is not a binding of {form}."),
+                binding.span(),
+            )),
+        })
+        .collect()
+}
+
+/// `(receive formals expr body ...)`
+///
+/// Binds the values of `expr` to `formals`, which are formal arguments as in `lambda`,
+/// and evaluates the body.
+pub fn receive(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(formals), Some(init)) = (iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects formals and an expression."
+        )));
+    };
+
+    let receive_context = EvalContext::derive_from(context);
+    bind_values(proc_name, formals, init, context, &receive_context)?;
+    eval_body(iter, &receive_context)
+}
+
+/// `(let-values ((formals init) ...) body ...)`
+///
+/// All `init`s are evaluated in the outer scope.
+pub fn let_values(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_expr_bindings(proc_name, iter.next(), "(formals init)")?;
+
+    let let_context = EvalContext::derive_from(context);
+    for (formals, init) in bindings {
+        bind_values(proc_name, formals, init, context, &let_context)?;
+    }
+    eval_body(iter, &let_context)
+}
+
+/// `(let*-values ((formals init) ...) body ...)`
+///
+/// Each binding is made in its own scope, so that it can see the previous bindings.
+pub fn let_star_values(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_expr_bindings(proc_name, iter.next(), "(formals init)")?;
+
+    let mut let_context = EvalContext::derive_from(context);
+    for (formals, init) in bindings {
+        let inner_context = EvalContext::derive_from(&let_context);
+        bind_values(proc_name, formals, init, &let_context, &inner_context)?;
+        let_context = inner_context;
+    }
+    eval_body(iter, &let_context)
+}
+
+/// `(define-values formals expr)`
+pub fn define_values(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (formals, init) = get_exact_2_args(proc_name, args)?;
+    bind_values(proc_name, formals, init, context, context)?;
+    Ok(NIL)
+}
+
+/// `(make-parameter value [converter])`
+pub fn make_parameter(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(value), converter, None) = (iter.next(), iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects a value and an optional converter."
+        )));
+    };
+
+    let value = eval(value, context)?;
+    let parameter = match converter {
+        Some(converter) => Parameter::with_converter(
+            value,
+            eval_into_proc(proc_name, converter, context)?,
+            context,
+        )?,
+        None => Parameter::new(value),
+    };
+    Ok(Expr::Proc(Proc::Parameter(parameter), None))
+}
+
+/// `(parameterize ((param value) ...) body ...)`
+///
+/// Binds each parameter object to its value while the body is evaluated. The last
+/// expression of the body is not in tail position, as the bindings must stay active
+/// until it returns.
+pub fn parameterize(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let mut bindings = Vec::new();
+    for (param, value) in parse_expr_bindings(proc_name, iter.next(), "(param value)")? {
+        let parameter = match eval(param, context)? {
+            Expr::Proc(Proc::Parameter(parameter), _) => parameter,
+            other => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{other}` is not a parameter object."),
+                    param.span(),
+                ))
+            }
+        };
+        bindings.push((parameter, eval(value, context)?));
+    }
+
+    let body_context = EvalContext::derive_from(context);
+    body_context.parameterize(bindings, |context| {
+        iter.try_fold(NIL, |_, expr| eval(expr, context))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::{eval, Evaluator};
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_call_cc() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        // (call/cc (lambda (k) 1)) => 1
+        let lambda = list!(intern("lambda"), list!(intern("k")), 1);
+        assert_eq!(call_cc("call/cc", &list!(lambda), context), Ok(1.into()));
+
+        // (call/cc (lambda (k) (k 2) 1)) => 2
+        let lambda = list!(
+            intern("lambda"),
+            list!(intern("k")),
+            list!(intern("k"), 2),
+            1
+        );
+        assert_eq!(call_cc("call/cc", &list!(lambda), context), Ok(2.into()));
+        assert!(!context.is_escaping());
+
+        // (call/cc (lambda (k) k)) returns an inactive continuation
+        let lambda = list!(intern("lambda"), list!(intern("k")), intern("k"));
+        let Ok(Expr::Proc(Proc::Continuation(continuation), _)) =
+            call_cc("call/cc", &list!(lambda), context)
+        else {
+            panic!("call/cc should return the continuation");
+        };
+        assert!(!continuation.is_active());
+        let k = Expr::Proc(Proc::Continuation(continuation), None);
+        assert!(eval(&list!(k, 1).into(), context).is_err());
+        assert!(!context.is_escaping());
+    }
+
+    #[test]
+    fn test_values() {
+        setup_native_proc_test!(values);
+
+        assert_eq!(values(list!(1)), Ok(1.into()));
+        assert_eq!(values(list!()), Ok(Expr::Values(vec![])));
+        assert_eq!(
+            values(list!(1, 2)),
+            Ok(Expr::Values(vec![1.into(), 2.into()]))
+        );
+    }
+
+    #[test]
+    fn test_call_with_values() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        // (call-with-values (lambda () (values 1 2)) cons) => (1 . 2)
+        let producer = list!(intern("lambda"), list!(), list!(intern("values"), 1, 2));
+        assert_eq!(
+            call_with_values(
+                "call-with-values",
+                &list!(producer, intern("cons")),
+                context
+            ),
+            Ok(crate::list::cons(1, 2).into())
+        );
+
+        // (call-with-values (lambda () 1) list?) => #f
+        let producer = list!(intern("lambda"), list!(), 1);
+        assert_eq!(
+            call_with_values(
+                "call-with-values",
+                &list!(producer, intern("list?")),
+                context
+            ),
+            Ok(false.into())
+        );
+
+        // (call-with-values values list?) fails, as list? expects 1 arg
+        assert!(call_with_values(
+            "call-with-values",
+            &list!(intern("values"), intern("list?")),
+            context
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_make_parameter() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        let Ok(Expr::Proc(Proc::Parameter(parameter), _)) =
+            make_parameter("make-parameter", &list!(1), context)
+        else {
+            panic!("make-parameter should return a parameter object");
+        };
+        assert_eq!(parameter.value(context), 1.into());
+
+        // (make-parameter '(1) car)
+        let Ok(Expr::Proc(Proc::Parameter(parameter), _)) = make_parameter(
+            "make-parameter",
+            &list!(list!(intern("quote"), list!(1)), intern("car")),
+            context,
+        ) else {
+            panic!("make-parameter should return a parameter object");
+        };
+        assert_eq!(parameter.value(context), 1.into());
+
+        assert!(make_parameter("make-parameter", &list!(), context).is_err());
+        assert!(make_parameter("make-parameter", &list!(1, 2), context).is_err());
+    }
+}
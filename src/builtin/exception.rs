@@ -0,0 +1,291 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::primitive::eval_cond_clauses;
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult, ExceptionHandler},
+    expr::{Expr, NIL},
+    list::List,
+    record::{Record, RecordType},
+    utils::{eval_into_proc, eval_into_str, get_exact_1_arg, get_exact_2_args},
+};
+
+thread_local! {
+    static ERROR_OBJECT_TYPE: Rc<RecordType> = Rc::new(RecordType::new(
+        "error-object",
+        vec!["message".into(), "irritants".into()],
+    ));
+}
+
+/// Creates an error object, which is a record of a built-in record type.
+fn make_error_object(message: &str, irritants: Expr) -> Expr {
+    let record = Record {
+        record_type: ERROR_OBJECT_TYPE.with(Rc::clone),
+        values: RefCell::new(vec![Expr::from(message), irritants]),
+    };
+    Expr::Record(Rc::new(record), None)
+}
+
+fn as_error_object(expr: &Expr) -> Option<&Rc<Record>> {
+    match expr {
+        Expr::Record(record, _)
+            if ERROR_OBJECT_TYPE.with(|error_type| Rc::ptr_eq(&record.record_type, error_type)) =>
+        {
+            Some(record)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the object to be handled for the given error. An error from a native procedure,
+/// which has no payload, is turned into an error object with the message of the error.
+fn error_to_condition(error: &EvalError) -> Expr {
+    match &error.payload {
+        Some(payload) => payload.as_ref().clone(),
+        None => make_error_object(&error.message, NIL),
+    }
+}
+
+/// Creates the error that carries `obj` up to a `guard` form, or out of the evaluator.
+fn make_raised_error(obj: Expr) -> EvalError {
+    let message = match as_error_object(&obj) {
+        Some(record) => {
+            let values = record.values.borrow();
+            let mut message = match &values[0] {
+                Expr::Str(text, _) => text.clone(),
+                expr => expr.to_string(),
+            };
+            if let Expr::List(irritants, _) = &values[1] {
+                for irritant in irritants.iter() {
+                    message += &format!(" {irritant}");
+                }
+            }
+            message
+        }
+        None => format!("uncaught exception: {obj}"),
+    };
+    EvalError {
+        message,
+        span: None,
+        payload: Some(Box::new(obj)),
+    }
+}
+
+/// Calls the current exception handler with `obj`, while the outer handlers are installed.
+///
+/// Returns `None` if there is no handler to call, i.e. the exception should unwind to
+/// the nearest `guard` form, if any.
+fn call_handler(obj: &Expr, context: &EvalContext) -> Result<Option<Expr>, EvalError> {
+    let handlers = &context.shared.exception_handlers;
+    let Some(ExceptionHandler::Proc(handler)) = handlers.borrow().last().cloned() else {
+        return Ok(None);
+    };
+
+    let saved = handlers.borrow_mut().pop();
+    let result = handler.apply([obj.clone()], context);
+    handlers.borrow_mut().extend(saved);
+    result.map(Some)
+}
+
+/// Raises `obj` as a non-continuable exception.
+///
+/// If the current handler returns, the exception keeps unwinding to the nearest `guard`.
+fn raise_obj(obj: Expr, context: &EvalContext) -> EvalResult {
+    call_handler(&obj, context)?;
+    Err(make_raised_error(obj))
+}
+
+pub fn raise(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let obj = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    raise_obj(obj, context)
+}
+
+pub fn raise_continuable(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let obj = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    match call_handler(&obj, context)? {
+        Some(value) => Ok(value),
+        None => Err(make_raised_error(obj)),
+    }
+}
+
+/// `(error message irritant ...)`
+pub fn error(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(message_expr) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name}: expects a message.")));
+    };
+    let message = eval_into_str(proc_name, message_expr, context)?;
+    let irritants = iter
+        .map(|expr| eval(expr, context))
+        .collect::<Result<Vec<_>, _>>()?;
+    let irritants = irritants
+        .into_iter()
+        .rev()
+        .fold(List::Nil, |cdr, car| crate::list::cons(car, cdr));
+
+    raise_obj(make_error_object(&message, irritants.into()), context)
+}
+
+/// `(with-exception-handler handler thunk)`
+///
+/// Errors from native procedures are handled once they have unwound to this form,
+/// as they cannot call the handler where they occur.
+pub fn with_exception_handler(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (handler_expr, thunk_expr) = get_exact_2_args(proc_name, args)?;
+    let handler = eval_into_proc(proc_name, handler_expr, context)?;
+    let thunk = eval_into_proc(proc_name, thunk_expr, context)?;
+
+    let handlers = &context.shared.exception_handlers;
+    let depth = handlers.borrow().len();
+    handlers
+        .borrow_mut()
+        .push(ExceptionHandler::Proc(handler.clone()));
+    let result = thunk.apply([], context);
+    handlers.borrow_mut().truncate(depth);
+
+    match result {
+        Err(error) if error.payload.is_none() && !context.is_escaping() => {
+            let obj = error_to_condition(&error);
+            handler.apply([obj.clone()], context)?;
+            Err(EvalError {
+                payload: Some(Box::new(obj)),
+                ..error
+            })
+        }
+        _ => result,
+    }
+}
+
+/// `(guard (var clause ...) body ...)`
+///
+/// The clauses are the same as those of `cond`. If no clause is selected, the exception
+/// is raised again in the dynamic environment of the `guard`, so the handlers installed
+/// outside of it are called.
+pub fn guard(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let spec_expr = iter.next();
+    let Some(Expr::List(spec @ List::Cons(cons), _)) = spec_expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects (var clause ...)."),
+            spec_expr.and_then(|e| e.span()),
+        ));
+    };
+    let Expr::Sym(var, _) = cons.car.as_ref() else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a symbol for the variable."),
+            cons.car.span(),
+        ));
+    };
+
+    let handlers = &context.shared.exception_handlers;
+    let depth = handlers.borrow().len();
+    handlers.borrow_mut().push(ExceptionHandler::Guard);
+    // The body is not in tail position, as its exceptions must be caught here.
+    let body_context = EvalContext::derive_from(context);
+    let result = iter.try_fold(NIL, |_, expr| eval(expr, &body_context));
+    handlers.borrow_mut().truncate(depth);
+
+    let Err(error) = result else {
+        return result;
+    };
+    if context.is_escaping() {
+        return Err(error);
+    }
+
+    let condition = error_to_condition(&error);
+    let guard_context = EvalContext::derive_from(context);
+    guard_context.env.define(var, condition.clone());
+
+    let mut clauses = spec.iter();
+    clauses.next();
+    match eval_cond_clauses(proc_name, clauses, &guard_context)? {
+        Some(value) => Ok(value),
+        None => {
+            call_handler(&condition, context)?;
+            Err(EvalError {
+                payload: Some(Box::new(condition)),
+                ..error
+            })
+        }
+    }
+}
+
+pub fn is_error_object(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let obj = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(as_error_object(&obj).is_some().into())
+}
+
+fn eval_into_error_object(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+) -> Result<Rc<Record>, EvalError> {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    let obj = eval(expr, context)?;
+    match as_error_object(&obj) {
+        Some(record) => Ok(record.clone()),
+        None => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to an error object."),
+            expr.span(),
+        )),
+    }
+}
+
+pub fn error_object_message(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let record = eval_into_error_object(proc_name, args, context)?;
+    let message = record.values.borrow()[0].clone();
+    Ok(message)
+}
+
+pub fn error_object_irritants(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let record = eval_into_error_object(proc_name, args, context)?;
+    let irritants = record.values.borrow()[1].clone();
+    Ok(irritants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_raise() {
+        setup_native_proc_test!(raise);
+
+        let quoted_boom = list!(intern("quote"), intern("boom"));
+        let error = raise(list!(quoted_boom)).unwrap_err();
+        assert_eq!(error.payload, Some(Box::new(intern("boom"))));
+        assert_eq!(error.message, "uncaught exception: boom");
+    }
+
+    #[test]
+    fn test_error() {
+        setup_native_proc_test!(error);
+
+        let raised = error(list!("bad thing:", 1, "two")).unwrap_err();
+        assert_eq!(raised.message, "bad thing: 1 \"two\"");
+        let payload = raised.payload.unwrap();
+        let record = as_error_object(&payload).unwrap();
+        assert_eq!(record.values.borrow()[0], "bad thing:".into());
+        assert_eq!(record.values.borrow()[1], list!(1, "two").into());
+
+        assert!(error(list!()).unwrap_err().payload.is_none());
+        assert!(error(list!(1)).unwrap_err().payload.is_none());
+    }
+
+    #[test]
+    fn test_error_to_condition() {
+        let condition = error_to_condition(&EvalError::from("oops".to_owned()));
+        let record = as_error_object(&condition).unwrap();
+        assert_eq!(record.values.borrow()[0], "oops".into());
+        assert_eq!(record.values.borrow()[1], NIL);
+
+        let error = EvalError {
+            message: String::new(),
+            span: None,
+            payload: Some(Box::new(1.into())),
+        };
+        assert_eq!(error_to_condition(&error), 1.into());
+    }
+}
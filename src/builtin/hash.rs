@@ -0,0 +1,356 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    hash_table::HashTable,
+    list::{cons, List},
+    utils::{
+        eval_into_hash_table, eval_into_proc, get_exact_1_arg, get_exact_2_args, get_exact_3_args,
+    },
+};
+
+fn make_hash_table_expr(table: HashTable) -> Expr {
+    Expr::HashTable(Rc::new(RefCell::new(table)), None)
+}
+
+fn eval_into_key(proc_name: &str, expr: &Expr, context: &EvalContext) -> EvalResult {
+    let key = eval(expr, context)?;
+    if HashTable::is_valid_key(&key) {
+        Ok(key)
+    } else {
+        Err(EvalError::new(
+            format!("{proc_name}: `{key}` cannot be used as a hash table key."),
+            expr.span(),
+        ))
+    }
+}
+
+pub fn is_hash_table(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    Ok(matches!(eval(arg, context)?, Expr::HashTable(_, _)).into())
+}
+
+pub fn make_hash_table(proc_name: &str, args: &List, _: &EvalContext) -> EvalResult {
+    if !args.is_nil() {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects no arguments."
+        )));
+    }
+    Ok(make_hash_table_expr(HashTable::new()))
+}
+
+pub fn alist_to_hash_table(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    let Expr::List(list, _) = eval(expr, context)? else {
+        return Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a list."),
+            expr.span(),
+        ));
+    };
+
+    let mut table = HashTable::new();
+    for entry in list.iter() {
+        let Expr::List(List::Cons(pair), _) = entry else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{entry}` is not a pair."),
+                expr.span(),
+            ));
+        };
+        // As with `assoc`, the first entry for a key takes precedence.
+        if !table.contains_key(&pair.car) {
+            table
+                .insert(pair.car.as_ref().clone(), pair.cdr.as_ref().clone())
+                .map_err(|_| {
+                    EvalError::new(
+                        format!(
+                            "{proc_name}: `{}` cannot be used as a hash table key.",
+                            pair.car
+                        ),
+                        expr.span(),
+                    )
+                })?;
+        }
+    }
+    Ok(make_hash_table_expr(table))
+}
+
+pub fn hash_table_ref(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(table_expr), Some(key_expr), failure_expr, None) =
+        (iter.next(), iter.next(), iter.next(), iter.next())
+    else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects 2 or 3 arguments."
+        )));
+    };
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let key = eval_into_key(proc_name, key_expr, context)?;
+
+    let value = table.borrow().get(&key).cloned();
+    match (value, failure_expr) {
+        (Some(value), _) => Ok(value),
+        (None, Some(failure_expr)) => {
+            let failure = eval_into_proc(proc_name, failure_expr, context)?;
+            failure.apply([], context)
+        }
+        (None, None) => Err(EvalError::new(
+            format!("{proc_name}: key `{key}` not found."),
+            key_expr.span(),
+        )),
+    }
+}
+
+pub fn hash_table_ref_default(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (table_expr, key_expr, default_expr) = get_exact_3_args(proc_name, args)?;
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let key = eval_into_key(proc_name, key_expr, context)?;
+
+    let value = table.borrow().get(&key).cloned();
+    match value {
+        Some(value) => Ok(value),
+        None => eval(default_expr, context),
+    }
+}
+
+pub fn hash_table_set(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (table_expr, key_expr, value_expr) = get_exact_3_args(proc_name, args)?;
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let key = eval_into_key(proc_name, key_expr, context)?;
+    let value = eval(value_expr, context)?;
+
+    table
+        .borrow_mut()
+        .insert(key, value)
+        .expect("key has been validated");
+    Ok(NIL)
+}
+
+pub fn hash_table_delete(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (table_expr, key_expr) = get_exact_2_args(proc_name, args)?;
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let key = eval_into_key(proc_name, key_expr, context)?;
+
+    table.borrow_mut().remove(&key);
+    Ok(NIL)
+}
+
+pub fn hash_table_contains(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (table_expr, key_expr) = get_exact_2_args(proc_name, args)?;
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let key = eval_into_key(proc_name, key_expr, context)?;
+
+    let contains = table.borrow().contains_key(&key);
+    Ok(contains.into())
+}
+
+pub fn hash_table_size(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let table = eval_into_hash_table(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let len = table.borrow().len();
+    Ok(Expr::from(len as i64))
+}
+
+pub fn hash_table_keys(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let table = eval_into_hash_table(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let keys = table.borrow().keys().cloned().collect::<Vec<_>>();
+    Ok(keys.into())
+}
+
+pub fn hash_table_values(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let table = eval_into_hash_table(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let values = table.borrow().values().cloned().collect::<Vec<_>>();
+    Ok(values.into())
+}
+
+pub fn hash_table_to_alist(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let table = eval_into_hash_table(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let pairs = table
+        .borrow()
+        .iter()
+        .map(|(key, value)| cons(key.clone(), value.clone()).into())
+        .collect::<Vec<Expr>>();
+    Ok(pairs.into())
+}
+
+pub fn hash_table_walk(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (table_expr, proc_expr) = get_exact_2_args(proc_name, args)?;
+    let table = eval_into_hash_table(proc_name, table_expr, context)?;
+    let proc = eval_into_proc(proc_name, proc_expr, context)?;
+
+    // The procedure may mutate the table, so walk over a snapshot of the entries.
+    let entries = table
+        .borrow()
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Vec<_>>();
+    for (key, value) in entries {
+        proc.apply([key, value], context)?;
+    }
+    Ok(NIL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    fn table_of(entries: Vec<(Expr, Expr)>) -> Expr {
+        let mut table = HashTable::new();
+        for (key, value) in entries {
+            table.insert(key, value).unwrap();
+        }
+        make_hash_table_expr(table)
+    }
+
+    fn quoted(expr: Expr) -> Expr {
+        list!(intern("quote"), expr).into()
+    }
+
+    #[test]
+    fn test_is_hash_table() {
+        setup_native_proc_test!(is_hash_table);
+
+        assert_eq!(is_hash_table(list!(table_of(vec![]))), Ok(true.into()));
+        assert_eq!(is_hash_table(list!(1)), Ok(false.into()));
+    }
+
+    #[test]
+    fn test_make_hash_table() {
+        setup_native_proc_test!(make_hash_table);
+
+        assert_eq!(make_hash_table(list!()), Ok(table_of(vec![])));
+        assert!(make_hash_table(list!(1)).is_err());
+    }
+
+    #[test]
+    fn test_alist_to_hash_table() {
+        setup_native_proc_test!(alist_to_hash_table);
+
+        let alist = list!(
+            cons(intern("a"), 1),
+            cons(intern("b"), 2),
+            cons(intern("a"), 3)
+        );
+        assert_eq!(
+            alist_to_hash_table(list!(quoted(alist.into()))),
+            Ok(table_of(vec![
+                (intern("a"), 1.into()),
+                (intern("b"), 2.into())
+            ]))
+        );
+        assert!(alist_to_hash_table(list!(quoted(list!(1).into()))).is_err());
+        assert!(alist_to_hash_table(list!(quoted(list!(cons(list!(1), 1)).into()))).is_err());
+    }
+
+    #[test]
+    fn test_hash_table_ref_set_delete() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+        context.env.define("t", table_of(vec![]));
+        let t = || intern("t");
+        let key = || quoted(intern("key"));
+
+        assert!(hash_table_ref("", &list!(t(), key()), context).is_err());
+        assert_eq!(
+            hash_table_ref_default("", &list!(t(), key(), 0), context),
+            Ok(0.into())
+        );
+
+        assert_eq!(hash_table_set("", &list!(t(), key(), 1), context), Ok(NIL));
+        assert_eq!(
+            hash_table_ref("", &list!(t(), key()), context),
+            Ok(1.into())
+        );
+        assert_eq!(
+            hash_table_contains("", &list!(t(), key()), context),
+            Ok(true.into())
+        );
+        assert_eq!(hash_table_size("", &list!(t()), context), Ok(1.into()));
+
+        assert_eq!(hash_table_delete("", &list!(t(), key()), context), Ok(NIL));
+        assert_eq!(
+            hash_table_contains("", &list!(t(), key()), context),
+            Ok(false.into())
+        );
+        assert_eq!(hash_table_delete("", &list!(t(), key()), context), Ok(NIL));
+
+        let key = quoted(list!(1).into());
+        assert!(hash_table_set("", &list!(t(), key.clone(), 1), context).is_err());
+        assert!(hash_table_ref("", &list!(t(), key), context).is_err());
+        assert!(hash_table_set("", &list!(1, 1, 1), context).is_err());
+    }
+
+    #[test]
+    fn test_hash_table_ref_failure() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+        context
+            .env
+            .define("t", table_of(vec![(1.into(), 2.into())]));
+
+        let thunk = list!(intern("lambda"), list!(), "missing");
+        assert_eq!(
+            hash_table_ref("", &list!(intern("t"), 1, thunk.clone()), context),
+            Ok(2.into())
+        );
+        assert_eq!(
+            hash_table_ref("", &list!(intern("t"), 2, thunk), context),
+            Ok("missing".into())
+        );
+        assert!(hash_table_ref("", &list!(intern("t"), 2, 3), context).is_err());
+    }
+
+    #[test]
+    fn test_hash_table_contents() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        let table = table_of(vec![(intern("a"), 1.into()), ("b".into(), 2.into())]);
+        context.env.define("t", table);
+        let args = list!(intern("t"));
+
+        assert_eq!(
+            hash_table_keys("", &args, context),
+            Ok(list!(intern("a"), "b").into())
+        );
+        assert_eq!(
+            hash_table_values("", &args, context),
+            Ok(list!(1, 2).into())
+        );
+        assert_eq!(
+            hash_table_to_alist("", &args, context),
+            Ok(list!(cons(intern("a"), 1), cons("b", 2)).into())
+        );
+    }
+
+    #[test]
+    fn test_hash_table_walk() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+        context.env.define(
+            "t",
+            table_of(vec![(1.into(), 10.into()), (2.into(), 20.into())]),
+        );
+        context.env.define("sum", 0);
+
+        // (hash-table-walk t (lambda (k v) (set! sum (num-add sum k v))))
+        let proc = list!(
+            intern("lambda"),
+            list!(intern("k"), intern("v")),
+            list!(
+                intern("set!"),
+                intern("sum"),
+                list!(intern("num-add"), intern("sum"), intern("k"), intern("v"))
+            )
+        );
+        assert_eq!(
+            hash_table_walk("", &list!(intern("t"), proc), context),
+            Ok(NIL)
+        );
+        assert_eq!(context.env.lookup("sum"), Some(33.into()));
+
+        assert!(hash_table_walk("", &list!(intern("t"), 1), context).is_err());
+    }
+}
@@ -1,8 +1,12 @@
 use crate::{
-    eval::{eval, EvalContext, EvalResult},
-    expr::{Expr, NIL},
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::Expr,
     list::List,
-    utils::{eval_into_num, eval_into_str, get_exact_1_arg, get_exact_2_args},
+    number::Number,
+    utils::{
+        eval_into_int, eval_into_num, eval_into_str, get_1_or_2_args, get_exact_1_arg,
+        get_exact_2_args,
+    },
 };
 
 pub fn is_num(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
@@ -17,9 +21,9 @@ fn binary_operation(
     proc_name: &str,
     args: &List,
     context: &EvalContext,
-    identity: f64,
+    identity: Number,
     is_associative: bool,
-    func: fn(lhs: f64, rhs: f64) -> f64,
+    func: fn(lhs: Number, rhs: Number) -> Option<Number>,
 ) -> EvalResult {
     let mut result = identity;
 
@@ -28,7 +32,9 @@ fn binary_operation(
         if index == 0 && args.len() > 1 && !is_associative {
             result = value;
         } else {
-            result = func(result, value);
+            result = func(result, value).ok_or_else(|| {
+                EvalError::new(format!("{proc_name}: division by zero."), arg.span())
+            })?;
         }
     }
 
@@ -36,42 +42,65 @@ fn binary_operation(
 }
 
 pub fn add(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
-    binary_operation(proc_name, args, context, 0_f64, true, |lhs, rhs| lhs + rhs)
+    binary_operation(proc_name, args, context, 0.into(), true, |lhs, rhs| {
+        Some(lhs + rhs)
+    })
 }
 
 pub fn subtract(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
-    binary_operation(proc_name, args, context, 0_f64, false, |lhs, rhs| lhs - rhs)
+    binary_operation(proc_name, args, context, 0.into(), false, |lhs, rhs| {
+        Some(lhs - rhs)
+    })
 }
 
 pub fn multiply(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
-    binary_operation(proc_name, args, context, 1_f64, true, |lhs, rhs| lhs * rhs)
+    binary_operation(proc_name, args, context, 1.into(), true, |lhs, rhs| {
+        Some(lhs * rhs)
+    })
 }
 
 pub fn divide(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
-    binary_operation(proc_name, args, context, 1_f64, false, |lhs, rhs| lhs / rhs)
+    binary_operation(
+        proc_name,
+        args,
+        context,
+        1.into(),
+        false,
+        Number::checked_div,
+    )
 }
 
 pub fn modulo(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let (lhs, rhs) = get_exact_2_args(proc_name, args)?;
-    let lhs = eval_into_num(proc_name, lhs, context)?;
-    let rhs = eval_into_num(proc_name, rhs, context)?;
-
-    Ok(Expr::Num(lhs % rhs, None))
+    let lhs_num = eval_into_num(proc_name, lhs, context)?;
+    let rhs_num = eval_into_num(proc_name, rhs, context)?;
+
+    match lhs_num.checked_rem(rhs_num) {
+        Some(result) => Ok(Expr::Num(result, None)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: division by zero."),
+            rhs.span(),
+        )),
+    }
 }
 
 fn logical_operation(
     proc_name: &str,
     args: &List,
     context: &EvalContext,
-    func: fn(lhs: f64, rhs: f64) -> bool,
+    func: fn(lhs: &Number, rhs: &Number) -> bool,
 ) -> EvalResult {
     let (lhs, rhs) = get_exact_2_args(proc_name, args)?;
     Ok(Expr::from(func(
-        eval_into_num(proc_name, lhs, context)?,
-        eval_into_num(proc_name, rhs, context)?,
+        &eval_into_num(proc_name, lhs, context)?,
+        &eval_into_num(proc_name, rhs, context)?,
     )))
 }
 
+pub fn equal(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    logical_operation(proc_name, args, context, |lhs, rhs| lhs == rhs)
+}
+
 pub fn less(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     logical_operation(proc_name, args, context, |lhs, rhs| lhs < rhs)
 }
@@ -80,12 +109,129 @@ pub fn greater(proc_name: &str, args: &List, context: &EvalContext) -> EvalResul
     logical_operation(proc_name, args, context, |lhs, rhs| lhs > rhs)
 }
 
+pub fn is_exact(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let num = eval_into_num(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(num.is_exact().into())
+}
+
+pub fn is_inexact(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let num = eval_into_num(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok((!num.is_exact()).into())
+}
+
+pub fn exact_to_inexact(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let num = eval_into_num(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::Num(num.to_inexact(), None))
+}
+
+pub fn inexact_to_exact(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    let num = eval_into_num(proc_name, arg, context)?;
+
+    match num.to_exact() {
+        Some(exact) => Ok(Expr::Num(exact, None)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: no exact representation for {num}."),
+            arg.span(),
+        )),
+    }
+}
+
+fn unary_operation(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+    func: fn(num: &Number) -> Option<Number>,
+) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    let num = eval_into_num(proc_name, arg, context)?;
+
+    match func(&num) {
+        Some(result) => Ok(Expr::Num(result, None)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: {num} is not a rational number."),
+            arg.span(),
+        )),
+    }
+}
+
+pub fn numerator(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, Number::numerator)
+}
+
+pub fn denominator(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, Number::denominator)
+}
+
+pub fn floor(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, |num| Some(num.floor()))
+}
+
+pub fn ceiling(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, |num| Some(num.ceiling()))
+}
+
+pub fn round(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, |num| Some(num.round()))
+}
+
+pub fn truncate(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    unary_operation(proc_name, args, context, |num| Some(num.truncate()))
+}
+
 pub fn parse(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
 
-    match text.parse::<f64>() {
+    match text.parse::<Number>() {
+        Ok(num) => Ok(Expr::Num(num, None)),
+        Err(_) => Ok(false.into()),
+    }
+}
+
+/// Evaluates the optional radix argument of `number->string` and `string->number`.
+fn eval_into_radix(
+    proc_name: &str,
+    expr: Option<&Expr>,
+    context: &EvalContext,
+) -> Result<u32, EvalError> {
+    let Some(expr) = expr else {
+        return Ok(10);
+    };
+    match eval_into_int(proc_name, "radix", expr, context)? {
+        radix @ 2..=36 => Ok(radix as u32),
+        radix => Err(EvalError::new(
+            format!("{proc_name}: radix must be between 2 and 36, but got {radix}."),
+            expr.span(),
+        )),
+    }
+}
+
+/// `(number->string z [radix])`
+pub fn number_to_string(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_1_or_2_args(proc_name, args)?;
+    let num = eval_into_num(proc_name, arg1, context)?;
+    let radix = eval_into_radix(proc_name, arg2, context)?;
+
+    match num.to_str_radix(radix) {
+        Some(text) => Ok(Expr::Str(text, None)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: inexact number {num} cannot be written in radix {radix}."),
+            arg1.span(),
+        )),
+    }
+}
+
+/// `(string->number text [radix])`
+///
+/// Returns `#f` if `text` is not a number.
+pub fn string_to_number(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_1_or_2_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let radix = eval_into_radix(proc_name, arg2, context)?;
+
+    match Number::from_str_radix(&text, radix) {
         Ok(num) => Ok(Expr::Num(num, None)),
-        Err(_) => Ok(NIL),
+        Err(_) => Ok(false.into()),
     }
 }
 
@@ -93,8 +239,8 @@ pub fn parse(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
 mod tests {
     use super::*;
     use crate::eval::Evaluator;
+    use crate::expr::intern;
     use crate::expr::test_utils::num;
-    use crate::expr::{intern, NIL};
     use crate::macros::*;
 
     #[test]
@@ -103,23 +249,23 @@ mod tests {
 
         // (is-num 1) => #t
         let args = list!(1);
-        assert_eq!(is_num(args), Ok(num(1)));
+        assert_eq!(is_num(args), Ok(true.into()));
 
         // (is-num "str") => #f
         let args = list!("str");
-        assert_eq!(is_num(args), Ok(NIL));
+        assert_eq!(is_num(args), Ok(false.into()));
 
         // (is-num 'sym) => #f
         let args = list!(list!(intern("quote"), intern("sym")));
-        assert_eq!(is_num(args), Ok(NIL));
+        assert_eq!(is_num(args), Ok(false.into()));
 
         // (is-num '()) => #f
         let args = list!(list!(intern("quote"), list!()));
-        assert_eq!(is_num(args), Ok(NIL));
+        assert_eq!(is_num(args), Ok(false.into()));
 
         // (is-num '(1 2 3)) => #f
         let args = list!(list!(intern("quote"), list!(1, 2, 3)));
-        assert_eq!(is_num(args), Ok(NIL));
+        assert_eq!(is_num(args), Ok(false.into()));
     }
 
     #[test]
@@ -181,13 +327,74 @@ mod tests {
     fn test_divide() {
         setup_native_proc_test!(divide);
 
-        // (/ 2) => 0.5
+        // (/ 2) => 1/2
         let args = list!(2);
+        assert_eq!(divide(args).unwrap().to_string(), "1/2");
+
+        // (/ 2.0) => 0.5
+        let args = list!(2.0);
         assert_eq!(divide(args), Ok(num(0.5)));
 
+        // (/ 1 3) => 1/3
+        let args = list!(1, 3);
+        assert_eq!(divide(args).unwrap().to_string(), "1/3");
+
         // (/ 4 2) => 2
         let args = list!(4, 2);
         assert_eq!(divide(args), Ok(num(2)));
+
+        // (/ 1 0) => error
+        assert!(divide(list!(1, 0)).is_err());
+
+        // (/ 1 0.0) => +inf.0
+        assert_eq!(divide(list!(1, 0.0)), Ok(num(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_exactness() {
+        setup_native_proc_test!(add);
+
+        let is_exact_result = |result: EvalResult| match result {
+            Ok(Expr::Num(value, _)) => value.is_exact(),
+            _ => panic!("expected a number"),
+        };
+
+        // (+ 1 2) => 3 (exact)
+        assert!(is_exact_result(add(list!(1, 2))));
+
+        // (+ 1 2.0) => 3.0 (inexact)
+        assert!(!is_exact_result(add(list!(1, 2.0))));
+    }
+
+    #[test]
+    fn test_bigint_promotion() {
+        setup_native_proc_test!(multiply);
+
+        // (* 9223372036854775807 2) => 18446744073709551614
+        let result = multiply(list!(i64::MAX, 2)).unwrap();
+        assert_eq!(result.to_string(), "18446744073709551614");
+    }
+
+    #[test]
+    fn test_exact_inexact() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        assert_eq!(is_exact("", &list!(1), context), Ok(true.into()));
+        assert_eq!(is_exact("", &list!(1.5), context), Ok(false.into()));
+        assert_eq!(is_inexact("", &list!(1.5), context), Ok(true.into()));
+        assert!(is_exact("", &list!("1"), context).is_err());
+
+        let result = exact_to_inexact("", &list!(1), context).unwrap();
+        assert_eq!(result.to_string(), "1.0");
+
+        let result = inexact_to_exact("", &list!(2.0), context).unwrap();
+        assert_eq!(result.to_string(), "2");
+
+        let result = inexact_to_exact("", &list!(2.5), context).unwrap();
+        assert_eq!(result.to_string(), "5/2");
+
+        assert!(inexact_to_exact("", &list!(f64::INFINITY), context).is_err());
     }
 
     #[test]
@@ -203,6 +410,9 @@ mod tests {
         // (% 11 4) => 3
         assert_eq!(modulo(list!(11, 4)), Ok(num(3)));
 
+        // (% 1 0) => error
+        assert!(modulo(list!(1, 0)).is_err());
+
         // (% 1) => error
         assert!(modulo(list!(1)).is_err());
 
@@ -243,6 +453,27 @@ mod tests {
         assert_eq!(greater(list!(2, 1)), Ok(true.into()));
     }
 
+    #[test]
+    fn test_rational_procs() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        let call = |func: fn(&str, &List, &EvalContext) -> EvalResult, arg: &str| {
+            let args = list!(arg.parse::<Number>().unwrap());
+            func("", &args, context).map(|result| result.to_string())
+        };
+
+        assert_eq!(call(numerator, "6/4"), Ok("3".to_owned()));
+        assert_eq!(call(denominator, "6/4"), Ok("2".to_owned()));
+        assert_eq!(call(denominator, "0.25"), Ok("4.0".to_owned()));
+        assert_eq!(call(floor, "-7/2"), Ok("-4".to_owned()));
+        assert_eq!(call(ceiling, "-7/2"), Ok("-3".to_owned()));
+        assert_eq!(call(round, "7/2"), Ok("4".to_owned()));
+        assert_eq!(call(truncate, "-7/2"), Ok("-3".to_owned()));
+        assert_eq!(call(round, "2.5"), Ok("2.0".to_owned()));
+        assert!(call(numerator, "+inf.0").is_err());
+        assert!(floor("", &list!("1"), context).is_err());
+    }
+
     #[test]
     fn test_parse() {
         setup_native_proc_test!(parse);
@@ -250,8 +481,8 @@ mod tests {
         // (num-parse "123") => 123
         assert_eq!(parse(list!("123")), Ok(num(123)));
 
-        // (num-parse "abc") => ()
-        assert_eq!(parse(list!("abc")), Ok(NIL));
+        // (num-parse "abc") => #f
+        assert_eq!(parse(list!("abc")), Ok(false.into()));
 
         // (num-parse "123" "456") => error
         assert!(parse(list!("123", "456")).is_err());
@@ -265,4 +496,40 @@ mod tests {
         // (num-parse 'sym) => error
         assert!(parse(list!(intern("sym"))).is_err());
     }
+
+    #[test]
+    fn test_number_to_string() {
+        setup_native_proc_test!(number_to_string);
+
+        // (number->string 255) => "255"
+        assert_eq!(number_to_string(list!(255)), Ok("255".into()));
+        // (number->string 255 16) => "ff"
+        assert_eq!(number_to_string(list!(255, 16)), Ok("ff".into()));
+        // (number->string 1.5) => "1.5"
+        assert_eq!(number_to_string(list!(1.5)), Ok("1.5".into()));
+        // error: (number->string 1.5 2)
+        assert!(number_to_string(list!(1.5, 2)).is_err());
+        // error: (number->string 1 1)
+        assert!(number_to_string(list!(1, 1)).is_err());
+        // error: (number->string "1")
+        assert!(number_to_string(list!("1")).is_err());
+    }
+
+    #[test]
+    fn test_string_to_number() {
+        setup_native_proc_test!(string_to_number);
+
+        // (string->number "42") => 42
+        assert_eq!(string_to_number(list!("42")), Ok(num(42)));
+        // (string->number "-1e2") => -100.0
+        assert_eq!(string_to_number(list!("-1e2")), Ok(num(-100.0)));
+        // (string->number "ff" 16) => 255
+        assert_eq!(string_to_number(list!("ff", 16)), Ok(num(255)));
+        // (string->number "12" 2) => #f
+        assert_eq!(string_to_number(list!("12", 2)), Ok(false.into()));
+        // (string->number "abc") => #f
+        assert_eq!(string_to_number(list!("abc")), Ok(false.into()));
+        // error: (string->number 1)
+        assert!(string_to_number(list!(1)).is_err());
+    }
 }
@@ -1,11 +1,21 @@
+use std::rc::Rc;
+
 use crate::{
-    eval::{eval, eval_tail, EvalContext, EvalError, EvalResult},
-    expr::{Expr, NIL},
-    list::List,
+    builtin::quote::QUOTE,
+    eval::{eval, eval_tail, eval_values, EvalContext, EvalError, EvalResult},
+    expr::{intern, Expr, NIL},
+    formal_args::FormalArgs,
+    list::{self, List, ListIter},
+    macros::list,
     proc::Proc,
-    utils::{get_2_or_3_args, get_exact_1_arg, get_exact_2_args, make_formal_args},
+    symbol::Symbol,
+    syntax_rules::strip_renames,
+    utils::{eval_into_proc, get_2_or_3_args, get_exact_1_arg, get_exact_2_args, make_formal_args},
 };
 
+const ARROW: &str = "=>";
+const ELSE: &str = "else";
+
 pub fn atom(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let expr = get_exact_1_arg(proc_name, args)?;
 
@@ -18,10 +28,10 @@ pub fn car(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     if let Expr::List(List::Cons(cons), _) = eval(expr, context)? {
         Ok(cons.car.as_ref().clone())
     } else {
-        Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a list."),
-            span: expr.span(),
-        })
+        Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a list."),
+            expr.span(),
+        ))
     }
 }
 
@@ -29,12 +39,12 @@ pub fn cdr(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let expr = get_exact_1_arg(proc_name, args)?;
 
     if let Expr::List(List::Cons(cons), _) = eval(expr, context)? {
-        Ok(cons.cdr.as_ref().clone().into())
+        Ok(cons.cdr.as_ref().clone())
     } else {
-        Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a list."),
-            span: expr.span(),
-        })
+        Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a list."),
+            expr.span(),
+        ))
     }
 }
 
@@ -42,25 +52,41 @@ pub fn cons(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let (car, cdr) = get_exact_2_args(proc_name, args)?;
 
     let car = eval(car, context)?;
-    let Expr::List(cdr, _) = eval(cdr, context)? else {
-        return Err(EvalError {
-            message: format!("{proc_name}: `{cdr}` does not evaluate to a list."),
-            span: cdr.span(),
-        });
-    };
+    let cdr = eval(cdr, context)?;
 
     Ok(crate::list::cons(car, cdr).into())
 }
 
+/// `(list obj ...)`
+pub fn list_(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let items = args
+        .iter()
+        .map(|arg| eval(arg, context))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(items.into())
+}
+
+pub fn is_pair(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+
+    Ok(matches!(eval(expr, context)?, Expr::List(List::Cons(_), _)).into())
+}
+
+pub fn is_list(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+
+    Ok(matches!(eval(expr, context)?, Expr::List(list, _) if list.is_proper()).into())
+}
+
 pub fn define(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let mut iter = args.iter();
     match iter.next() {
         Some(Expr::Sym(name, span)) => {
             let Some(expr) = iter.next() else {
-                return Err(EvalError {
-                    message: format!("{proc_name}: define expects a expression after symbol"),
-                    span: *span,
-                });
+                return Err(EvalError::new(
+                    format!("{proc_name}: define expects a expression after symbol"),
+                    *span,
+                ));
             };
 
             context.env.define(name, eval(expr, context)?);
@@ -68,10 +94,10 @@ pub fn define(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
         }
         Some(Expr::List(List::Cons(cons), _)) => {
             let Expr::Sym(name, _) = cons.car.as_ref() else {
-                return Err(EvalError {
-                    message: format!("{proc_name}: expects a symbol for a procedure name"),
-                    span: cons.car.span(),
-                });
+                return Err(EvalError::new(
+                    format!("{proc_name}: expects a symbol for a procedure name"),
+                    cons.car.span(),
+                ));
             };
 
             context.env.define(
@@ -79,8 +105,8 @@ pub fn define(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
                 Expr::Proc(
                     Proc::Closure {
                         name: Some(name.to_string()),
-                        formal_args: make_formal_args(&cons.cdr)?,
-                        body: Box::new(iter.into()),
+                        formal_args: Rc::new(make_formal_args(&cons.cdr)?),
+                        body: Rc::new(iter.into()),
                         outer_context: context.clone(),
                     },
                     args.span(),
@@ -101,35 +127,31 @@ pub fn defmacro(proc_name: &str, args: &List, context: &EvalContext) -> EvalResu
         // (defmacro name (args) body)
         Some(Expr::Sym(macro_name, _)) => {
             let expr = iter.next();
-            let Some(Expr::List(list, _)) = expr else {
-                return Err(EvalError {
-                    message: format!(
-                        "{proc_name}: expected a list of formal arguments after a macro name."
-                    ),
-                    span: expr.map(|e| e.span()).unwrap_or(None),
-                });
+            let Some(list_expr @ Expr::List(_, _)) = expr else {
+                return Err(EvalError::new(
+                    format!("{proc_name}: expected a list of formal arguments after a macro name."),
+                    expr.map(|e| e.span()).unwrap_or(None),
+                ));
             };
 
-            (macro_name, make_formal_args(list)?)
+            (macro_name, make_formal_args(list_expr)?)
         }
         // (defmacro (name args) body)
         Some(Expr::List(List::Cons(cons), _)) => {
             let Expr::Sym(macro_name, _) = cons.car.as_ref() else {
-                return Err(EvalError {
-                    message: format!(
-                        "{proc_name}: a macro name expected as the first element of the list."
-                    ),
-                    span: cons.car.span(),
-                });
+                return Err(EvalError::new(
+                    format!("{proc_name}: a macro name expected as the first element of the list."),
+                    cons.car.span(),
+                ));
             };
 
             (macro_name, make_formal_args(&cons.cdr)?)
         }
         _ => {
-            return Err(EvalError {
-                message: format!("{proc_name}: invalid macro form -- expected a symbol or a list."),
-                span: expr.map(|e| e.span()).unwrap_or(None),
-            });
+            return Err(EvalError::new(
+                format!("{proc_name}: invalid macro form -- expected a symbol or a list."),
+                expr.map(|e| e.span()).unwrap_or(None),
+            ));
         }
     };
 
@@ -137,9 +159,9 @@ pub fn defmacro(proc_name: &str, args: &List, context: &EvalContext) -> EvalResu
         macro_name,
         Expr::Proc(
             Proc::Macro {
-                name: Some(macro_name.clone()),
-                formal_args,
-                body: Box::new(iter.into()),
+                name: Some(macro_name.to_string()),
+                formal_args: Rc::new(formal_args),
+                body: Rc::new(iter.into()),
             },
             None, // TODO: add span
         ),
@@ -148,10 +170,28 @@ pub fn defmacro(proc_name: &str, args: &List, context: &EvalContext) -> EvalResu
     Ok(NIL)
 }
 
+/// `(eq? obj1 obj2)`
+///
+/// Tests whether the objects are the same object. As numbers, characters and strings have
+/// no identity in Rusche, this is the same as `eqv?`.
 pub fn eq(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let (left, right) = get_exact_2_args(proc_name, args)?;
 
-    Ok((eval(left, context)? == eval(right, context)?).into())
+    Ok(eval(left, context)?.is_eqv(&eval(right, context)?).into())
+}
+
+/// `(eqv? obj1 obj2)`
+pub fn eqv(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (left, right) = get_exact_2_args(proc_name, args)?;
+
+    Ok(eval(left, context)?.is_eqv(&eval(right, context)?).into())
+}
+
+/// `(equal? obj1 obj2)`
+pub fn equal(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (left, right) = get_exact_2_args(proc_name, args)?;
+
+    Ok(eval(left, context)?.is_equal(&eval(right, context)?).into())
 }
 
 pub fn eval_(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
@@ -172,49 +212,530 @@ pub fn if_(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     }
 }
 
+/// `(and expr ...)`
+///
+/// Evaluates the expressions from left to right, stopping at the first one that evaluates
+/// to `#f`. Returns the value of the last evaluated expression, or `#t` if there are none.
+pub fn and(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter().peekable();
+    while let Some(expr) = iter.next() {
+        if iter.peek().is_none() {
+            return eval_tail(expr, context);
+        }
+        let value = eval(expr, context)?;
+        if !value.is_truthy() {
+            return Ok(value);
+        }
+    }
+    Ok(true.into())
+}
+
+/// `(or expr ...)`
+///
+/// Evaluates the expressions from left to right, stopping at the first one that does not
+/// evaluate to `#f`. Returns the value of the last evaluated expression, or `#f` if there
+/// are none.
+pub fn or(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter().peekable();
+    while let Some(expr) = iter.next() {
+        if iter.peek().is_none() {
+            return eval_tail(expr, context);
+        }
+        let value = eval(expr, context)?;
+        if value.is_truthy() {
+            return Ok(value);
+        }
+    }
+    Ok(false.into())
+}
+
 pub fn lambda(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let mut iter = args.iter();
 
-    let expr = iter.next();
-    let Some(Expr::List(list, _)) = expr else {
-        return Err(EvalError {
-            message: format!("{proc_name}: expected a list of formal arguments."),
-            span: expr.map(|e| e.span()).unwrap_or(None),
-        });
+    let Some(expr) = iter.next() else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expected a list of formal arguments."
+        )));
     };
 
     Ok(Expr::Proc(
         Proc::Closure {
             name: None,
-            formal_args: make_formal_args(list)?,
-            body: Box::new(iter.into()),
+            formal_args: Rc::new(make_formal_args(expr)?),
+            body: Rc::new(iter.into()),
             outer_context: context.clone(),
         },
         None, // TODO: add span
     ))
 }
 
+/// `(case-lambda (formals body ...) ...)`
+pub fn case_lambda(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut clauses = Vec::new();
+    for clause in args.iter() {
+        let Expr::List(List::Cons(cons), _) = clause else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{clause}` is not a clause of (formals body ...)."),
+                clause.span(),
+            ));
+        };
+        let Some(body) = cons.cdr_list() else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{clause}` is not a proper list."),
+                clause.span(),
+            ));
+        };
+        clauses.push((make_formal_args(&cons.car)?, body.clone()));
+    }
+
+    Ok(Expr::Proc(
+        Proc::CaseLambda {
+            name: None,
+            clauses: clauses.into(),
+            outer_context: context.clone(),
+        },
+        None,
+    ))
+}
+
+/// `(procedure-arity proc)`
+///
+/// Returns `(min . max)`, where `max` is `#f` if there is no limit, or `#f` if the arity
+/// of the procedure is unknown.
+pub fn procedure_arity(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let proc = eval_into_proc(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(match proc.arity() {
+        Some(arity) => {
+            let max = arity.max.map_or(false.into(), |max| Expr::from(max as i64));
+            list::cons(Expr::from(arity.min as i64), max).into()
+        }
+        None => false.into(),
+    })
+}
+
+/// Evaluates the expressions of a body in order, with the last one in tail position.
+pub(super) fn eval_body(body: ListIter, context: &EvalContext) -> EvalResult {
+    let mut iter = body.peekable();
+    while let Some(expr) = iter.next() {
+        if iter.peek().is_none() {
+            return eval_tail(expr, context);
+        }
+        eval_values(expr, context)?;
+    }
+    Ok(NIL)
+}
+
+/// Parses the bindings of a `let` family form, i.e. `((name init) ...)`.
+pub(super) fn parse_bindings<'a>(
+    proc_name: &str,
+    expr: Option<&'a Expr>,
+) -> Result<Vec<(&'a Symbol, &'a Expr)>, EvalError> {
+    let Some(Expr::List(list, _)) = expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a list of bindings."),
+            expr.and_then(|e| e.span()),
+        ));
+    };
+
+    let mut bindings = Vec::new();
+    let mut iter = list.iter();
+    for binding in iter.by_ref() {
+        if let Expr::List(pair, _) = binding {
+            if pair.is_proper() && pair.len() == 2 {
+                let mut items = pair.iter();
+                if let (Some(Expr::Sym(name, _)), Some(init)) = (items.next(), items.next()) {
+                    bindings.push((name, init));
+                    continue;
+                }
+            }
+        }
+        return Err(EvalError::new(
+            format!("{proc_name}: `{binding}` is not a binding of (name init)."),
+            binding.span(),
+        ));
+    }
+
+    if let Some(tail) = iter.tail() {
+        return Err(EvalError::new(
+            format!("{proc_name}: `{list}` is not a proper list of bindings."),
+            tail.span(),
+        ));
+    }
+
+    Ok(bindings)
+}
+
+/// `(let ((name init) ...) body ...)` or `(let loop ((name init) ...) body ...)`
+///
+/// The named form binds `loop` to a procedure that takes the names as its arguments,
+/// visible only from within the body.
+pub fn let_(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let mut expr = iter.next();
+
+    let loop_name = match expr {
+        Some(Expr::Sym(name, _)) => {
+            expr = iter.next();
+            Some(name)
+        }
+        _ => None,
+    };
+    let bindings = parse_bindings(proc_name, expr)?;
+
+    if let Some(loop_name) = loop_name {
+        let loop_context = EvalContext::derive_from(context);
+        let closure = Proc::Closure {
+            name: Some(loop_name.to_string()),
+            formal_args: Rc::new(FormalArgs::new(
+                bindings.iter().map(|(name, _)| (*name).clone()).collect(),
+            )),
+            body: Rc::new(iter.into()),
+            outer_context: loop_context.clone(),
+        };
+        loop_context
+            .env
+            .define(loop_name, Expr::Proc(closure.clone(), None));
+
+        // The initial values are evaluated in the outer context, where `loop` is not visible.
+        let init_args = bindings
+            .iter()
+            .rev()
+            .fold(List::Nil, |cdr, (_, init)| list::cons((*init).clone(), cdr));
+        return eval_tail(
+            &list::cons(Expr::Proc(closure, None), init_args).into(),
+            context,
+        );
+    }
+
+    let let_context = EvalContext::derive_from(context);
+    for (name, init) in bindings {
+        let_context.env.define(name, eval(init, context)?);
+    }
+    eval_body(iter, &let_context)
+}
+
+/// `(let* ((name init) ...) body ...)`
+///
+/// Each binding is made in its own scope, so that it can see the previous bindings.
+pub fn let_star(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_bindings(proc_name, iter.next())?;
+
+    let mut let_context = EvalContext::derive_from(context);
+    for (name, init) in bindings {
+        let value = eval(init, &let_context)?;
+        let_context = EvalContext::derive_from(&let_context);
+        let_context.env.define(name, value);
+    }
+    eval_body(iter, &let_context)
+}
+
+/// `(letrec ((name init) ...) body ...)`
+///
+/// All the initial values are evaluated in a scope where all the names are visible,
+/// and then assigned to the names.
+pub fn letrec(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_bindings(proc_name, iter.next())?;
+
+    let let_context = EvalContext::derive_from(context);
+    for (name, _) in &bindings {
+        let_context.env.define(*name, NIL);
+    }
+    let values = bindings
+        .iter()
+        .map(|(_, init)| eval(init, &let_context))
+        .collect::<Result<Vec<_>, _>>()?;
+    for ((name, _), value) in bindings.into_iter().zip(values) {
+        let_context.env.define(name, value);
+    }
+    eval_body(iter, &let_context)
+}
+
+/// `(letrec* ((name init) ...) body ...)`
+///
+/// Like `letrec`, but each name is assigned as soon as its initial value is evaluated.
+pub fn letrec_star(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_bindings(proc_name, iter.next())?;
+
+    let let_context = EvalContext::derive_from(context);
+    for (name, _) in &bindings {
+        let_context.env.define(*name, NIL);
+    }
+    for (name, init) in bindings {
+        let value = eval(init, &let_context)?;
+        let_context.env.define(name, value);
+    }
+    eval_body(iter, &let_context)
+}
+
 pub fn set(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
     let (name_expr, value_expr) = get_exact_2_args(proc_name, args)?;
 
     let Expr::Sym(name, _) = name_expr else {
-        return Err(EvalError {
-            message: format!("{proc_name}: expects a symbol as the first argument"),
-            span: name_expr.span(),
-        });
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a symbol as the first argument"),
+            name_expr.span(),
+        ));
     };
 
-    context.env.update(name, eval(value_expr, context)?);
+    if !context
+        .env
+        .update_identifier(name, eval(value_expr, context)?)
+    {
+        return Err(EvalError::new(
+            format!("{proc_name}: undefined symbol `{name}`."),
+            name_expr.span(),
+        ));
+    }
+
+    Ok(NIL)
+}
+
+/// `(while condition body ...)`
+pub fn while_(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(condition) = iter.next() else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects a condition."
+        )));
+    };
 
+    while eval(condition, context)?.is_truthy() {
+        for expr in iter.clone() {
+            eval(expr, context)?;
+        }
+    }
     Ok(NIL)
 }
 
+/// `(begin expr ...)`
+///
+/// Evaluates the expressions in the current environment, with the last one in tail position.
+pub fn begin(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    eval_body(args.iter(), context)
+}
+
+/// `(when test body ...)`
+pub fn when(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(test) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name}: expects a test.")));
+    };
+
+    if eval(test, context)?.is_truthy() {
+        eval_body(iter, context)
+    } else {
+        Ok(NIL)
+    }
+}
+
+/// `(unless test body ...)`
+pub fn unless(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(test) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name}: expects a test.")));
+    };
+
+    if eval(test, context)?.is_truthy() {
+        Ok(NIL)
+    } else {
+        eval_body(iter, context)
+    }
+}
+
+/// Evaluates the body of a `case` or `cond` clause that has been selected by `value`.
+///
+/// If the body is `=> proc`, calls `proc` with `value` in tail position. Otherwise, evaluates
+/// the body as usual, or returns `value` if the body is empty.
+fn eval_clause_body(
+    proc_name: &str,
+    clause: &Expr,
+    body: ListIter,
+    value: Expr,
+    context: &EvalContext,
+) -> EvalResult {
+    let mut peek = body.clone();
+    match (peek.next(), peek.next(), peek.next()) {
+        (Some(Expr::Sym(arrow, _)), Some(receiver), None) if arrow == ARROW => {
+            let quoted_value = list!(intern(QUOTE), value);
+            eval_tail(&list!(receiver.clone(), quoted_value).into(), context)
+        }
+        (Some(Expr::Sym(arrow, _)), _, _) if arrow == ARROW => Err(EvalError::new(
+            format!("{proc_name}: `{clause}` expects a procedure after `=>`."),
+            clause.span(),
+        )),
+        (None, _, _) => Ok(value),
+        _ => eval_body(body, context),
+    }
+}
+
+/// Evaluates the clauses of a `cond` form, returning `None` if no clause is selected.
+pub(super) fn eval_cond_clauses(
+    proc_name: &str,
+    clauses: ListIter,
+    context: &EvalContext,
+) -> Result<Option<Expr>, EvalError> {
+    for clause in clauses {
+        let Expr::List(list @ List::Cons(cons), _) = clause else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{clause}` is not a valid clause."),
+                clause.span(),
+            ));
+        };
+        let mut body = list.iter();
+        body.next();
+
+        if matches!(cons.car.as_ref(), Expr::Sym(name, _) if name == ELSE) {
+            return eval_body(body, context).map(Some);
+        }
+
+        let value = eval(&cons.car, context)?;
+        if value.is_truthy() {
+            return eval_clause_body(proc_name, clause, body, value, context).map(Some);
+        }
+    }
+
+    Ok(None)
+}
+
+/// `(cond (test body ...) ... (else body ...))`
+///
+/// The body of a clause can also be `=> proc`, to call `proc` with the value of the test,
+/// or be omitted, to return the value of the test. Returns `#f` if no clause is selected.
+pub fn cond(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let value = eval_cond_clauses(proc_name, args.iter(), context)?;
+    Ok(value.unwrap_or(false.into()))
+}
+
+/// `(case key ((datum ...) body ...) ... (else body ...))`
+///
+/// A clause is selected if one of its data is `eqv?` to the key.
+/// The body of a clause can also be `=> proc`, to call `proc` with the key.
+/// Returns `#f` if no clause is selected, as `cond` does.
+pub fn case(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(key_expr) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name}: expects a key.")));
+    };
+    let key = eval(key_expr, context)?;
+
+    for clause in iter {
+        let Expr::List(list @ List::Cons(cons), _) = clause else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{clause}` is not a valid clause."),
+                clause.span(),
+            ));
+        };
+        let mut body = list.iter();
+        body.next();
+
+        let is_selected = match cons.car.as_ref() {
+            Expr::Sym(name, _) if name == ELSE => true,
+            Expr::List(data, _) if data.is_proper() => {
+                data.iter().any(|datum| strip_renames(datum).is_eqv(&key))
+            }
+            _ => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{clause}` does not start with a list of data."),
+                    clause.span(),
+                ))
+            }
+        };
+        if is_selected {
+            // An empty body is not allowed in `case`, unlike `cond`.
+            if cons.cdr.is_nil() {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{clause}` has no body."),
+                    clause.span(),
+                ));
+            }
+            return eval_clause_body(proc_name, clause, body, key, context);
+        }
+    }
+
+    Ok(false.into())
+}
+
+/// `(do ((name init [step]) ...) (test result ...) body ...)`
+///
+/// The bindings are updated in place with the values of the steps on each iteration.
+pub fn do_(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+
+    let specs_expr = iter.next();
+    let Some(Expr::List(specs_list, _)) = specs_expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a list of (name init [step])."),
+            specs_expr.and_then(|e| e.span()),
+        ));
+    };
+    let mut specs = Vec::new();
+    for spec in specs_list.iter() {
+        let items = match spec {
+            Expr::List(list, _) if list.is_proper() => list.iter().collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+        let (Some(Expr::Sym(name, _)), Some(init), step, None) =
+            (items.first(), items.get(1), items.get(2), items.get(3))
+        else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{spec}` is not a spec of (name init [step])."),
+                spec.span(),
+            ));
+        };
+        specs.push((name, *init, step.copied()));
+    }
+
+    let test_expr = iter.next();
+    let Some(Expr::List(test_clause @ List::Cons(_), _)) = test_expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a clause of (test result ...)."),
+            test_expr.and_then(|e| e.span()),
+        ));
+    };
+    let mut results = test_clause.iter();
+    let test = results.next().unwrap();
+
+    let mut do_context = EvalContext::derive_from(context);
+    for (name, init, _) in &specs {
+        do_context.env.define(*name, eval(init, context)?);
+    }
+
+    loop {
+        if eval(test, &do_context)?.is_truthy() {
+            return eval_body(results, &do_context);
+        }
+
+        for expr in iter.clone() {
+            eval(expr, &do_context)?;
+        }
+
+        // Each iteration binds the variables in a fresh environment, so that closures
+        // created by an iteration keep the values of that iteration.
+        let mut values = Vec::new();
+        for (name, _, step) in &specs {
+            let value = match step {
+                Some(step) => eval(step, &do_context)?,
+                None => do_context.env.lookup(*name).unwrap_or(NIL),
+            };
+            values.push((*name, value));
+        }
+        do_context = EvalContext::derive_from(context);
+        for (name, value) in values {
+            do_context.env.define(name, value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::expr::intern;
     use crate::expr::test_utils::num;
     use crate::macros::*;
+    use crate::proc::Arity;
 
     #[test]
     fn test_atom() {
@@ -266,6 +787,12 @@ mod tests {
             Ok(list!(2, 3).into())
         );
 
+        // (cdr '(1 . 2)) => 2
+        assert_eq!(
+            cdr(list!(list!(intern("quote"), crate::list::cons(1, 2)))),
+            Ok(Expr::from(2))
+        );
+
         // (cdr (1 2 3)) => err
         assert!(cdr(list!(list!(1, 2, 3))).is_err());
 
@@ -276,6 +803,20 @@ mod tests {
         assert!(cdr(list!(list!(intern("quote"), list!(1, 2, 3)), 4)).is_err());
     }
 
+    #[test]
+    fn test_list() {
+        setup_native_proc_test!(list_);
+
+        // (list) => ()
+        assert_eq!(list_(list!()), Ok(NIL));
+
+        // (list 1 '(2) "3") => (1 (2) "3")
+        assert_eq!(
+            list_(list!(1, list!(intern("quote"), list!(2)), "3")),
+            Ok(list!(1, list!(2), "3").into())
+        );
+    }
+
     #[test]
     fn test_cons() {
         setup_native_proc_test!(cons);
@@ -286,13 +827,56 @@ mod tests {
             Ok(list!(1, 2, 3).into())
         );
 
-        // (car 1 2) => err (cdr is not a list)
-        assert!(cons(list!(1, 2)).is_err());
+        // (cons 1 2) => (1 . 2)
+        assert_eq!(cons(list!(1, 2)), Ok(crate::list::cons(1, 2).into()));
 
         // (car 1 2 3) => err (wrong number of arguments)
         assert!(cons(list!(1, 2, 3)).is_err());
     }
 
+    #[test]
+    fn test_is_pair() {
+        setup_native_proc_test!(is_pair);
+
+        // (pair? '(1 . 2)) => #t
+        let args = list!(list!(intern("quote"), crate::list::cons(1, 2)));
+        assert_eq!(is_pair(args), Ok(true.into()));
+
+        // (pair? '(1 2)) => #t
+        let args = list!(list!(intern("quote"), list!(1, 2)));
+        assert_eq!(is_pair(args), Ok(true.into()));
+
+        // (pair? '()) => #f
+        let args = list!(list!(intern("quote"), list!()));
+        assert_eq!(is_pair(args), Ok(false.into()));
+
+        // (pair? 1) => #f
+        assert_eq!(is_pair(list!(1)), Ok(false.into()));
+    }
+
+    #[test]
+    fn test_is_list() {
+        setup_native_proc_test!(is_list);
+
+        // (list? '(1 2)) => #t
+        let args = list!(list!(intern("quote"), list!(1, 2)));
+        assert_eq!(is_list(args), Ok(true.into()));
+
+        // (list? '()) => #t
+        let args = list!(list!(intern("quote"), list!()));
+        assert_eq!(is_list(args), Ok(true.into()));
+
+        // (list? '(1 2 . 3)) => #f
+        let args = list!(list!(
+            intern("quote"),
+            crate::list::cons(1, crate::list::cons(2, 3))
+        ));
+        assert_eq!(is_list(args), Ok(false.into()));
+
+        // (list? 1) => #f
+        assert_eq!(is_list(list!(1)), Ok(false.into()));
+    }
+
     #[test]
     fn test_define() {
         setup_native_proc_test!(define, env);
@@ -367,13 +951,254 @@ mod tests {
         setup_native_proc_test!(eq);
 
         // (eq 1 1) => #t
-        assert_ne!(eq(list!(1, 1)).unwrap(), NIL);
-        // (eq 1 2) => ()
-        assert_eq!(eq(list!(1, 2)).unwrap(), NIL);
+        assert_eq!(eq(list!(1, 1)), Ok(true.into()));
+        // (eq 1 2) => #f
+        assert_eq!(eq(list!(1, 2)), Ok(false.into()));
         // (eq "str" "str") => #t
-        assert_ne!(eq(list!("str", "str")).unwrap(), NIL);
-        // (eq 1 "1") => ()
-        assert_eq!(eq(list!(1, "1")).unwrap(), NIL);
+        assert_eq!(eq(list!("str", "str")), Ok(true.into()));
+        // (eq 1 "1") => #f
+        assert_eq!(eq(list!(1, "1")), Ok(false.into()));
+        // (eq #f '()) => #f
+        assert_eq!(
+            eq(list!(false, list!(intern("quote"), NIL))),
+            Ok(false.into())
+        );
+        // (eq '(1) '(1)) => #f
+        assert_eq!(
+            eq(list!(
+                list!(intern("quote"), list!(1)),
+                list!(intern("quote"), list!(1))
+            )),
+            Ok(false.into())
+        );
+    }
+
+    #[test]
+    fn test_eqv() {
+        setup_native_proc_test!(eqv, env);
+
+        // (eqv 1 1) => #t
+        assert_eq!(eqv(list!(1, 1)), Ok(true.into()));
+        // (eqv 1 1.0) => #f
+        assert_eq!(eqv(list!(1, 1.0)), Ok(false.into()));
+        // (eqv '() '()) => #t
+        let nil = list!(intern("quote"), NIL);
+        assert_eq!(eqv(list!(nil.clone(), nil)), Ok(true.into()));
+        // (eqv x x) => #t, where x is '(1)
+        env.define("x", list!(1));
+        assert_eq!(eqv(list!(intern("x"), intern("x"))), Ok(true.into()));
+    }
+
+    #[test]
+    fn test_equal() {
+        setup_native_proc_test!(equal);
+
+        // (equal '(1 (2)) '(1 (2))) => #t
+        let quoted = list!(intern("quote"), list!(1, list!(2)));
+        assert_eq!(equal(list!(quoted.clone(), quoted)), Ok(true.into()));
+        // (equal '(1 2) '(1 . 2)) => #f
+        assert_eq!(
+            equal(list!(
+                list!(intern("quote"), list!(1, 2)),
+                list!(intern("quote"), list::cons(1, 2))
+            )),
+            Ok(false.into())
+        );
+        // (equal 1 1.0) => #f
+        assert_eq!(equal(list!(1, 1.0)), Ok(false.into()));
+    }
+
+    #[test]
+    fn test_and() {
+        setup_native_proc_test!(and);
+
+        // (and) => #t
+        assert_eq!(and(list!()), Ok(true.into()));
+        // (and 1 2) => 2
+        assert_eq!(and(list!(1, 2)), Ok(2.into()));
+        // (and 1 #f 2) => #f
+        assert_eq!(and(list!(1, false, 2)), Ok(false.into()));
+        // (and #f undefined) => #f
+        assert_eq!(and(list!(false, intern("undefined"))), Ok(false.into()));
+        // (and undefined #f) => Err
+        assert!(and(list!(intern("undefined"), false)).is_err());
+    }
+
+    #[test]
+    fn test_or() {
+        setup_native_proc_test!(or);
+
+        // (or) => #f
+        assert_eq!(or(list!()), Ok(false.into()));
+        // (or #f 2) => 2
+        assert_eq!(or(list!(false, 2)), Ok(2.into()));
+        // (or #f #f) => #f
+        assert_eq!(or(list!(false, false)), Ok(false.into()));
+        // (or 1 undefined) => 1
+        assert_eq!(or(list!(1, intern("undefined"))), Ok(1.into()));
+    }
+
+    #[test]
+    fn test_let() {
+        setup_native_proc_test!(let_, env);
+        env.define("x", 1);
+
+        // (let ((x 2) (y x)) y) => 1
+        assert_eq!(
+            let_(list!(
+                list!(list!(intern("x"), 2), list!(intern("y"), intern("x"))),
+                intern("y")
+            )),
+            Ok(1.into())
+        );
+        // (let ()) => ()
+        assert_eq!(let_(list!(list!())), Ok(NIL));
+        // (let loop ((x 3)) x) => 3
+        assert_eq!(
+            let_(list!(
+                intern("loop"),
+                list!(list!(intern("x"), 3)),
+                intern("x")
+            )),
+            Ok(3.into())
+        );
+        // (let (x) x) => Err
+        assert!(let_(list!(list!(intern("x")), intern("x"))).is_err());
+        // (let ((x 1 2)) x) => Err
+        assert!(let_(list!(list!(list!(intern("x"), 1, 2)), intern("x"))).is_err());
+        assert_eq!(env.lookup("y"), None);
+        assert_eq!(env.lookup("loop"), None);
+    }
+
+    #[test]
+    fn test_let_star() {
+        setup_native_proc_test!(let_star);
+
+        // (let* ((x 1) (y x)) y) => 1
+        assert_eq!(
+            let_star(list!(
+                list!(list!(intern("x"), 1), list!(intern("y"), intern("x"))),
+                intern("y")
+            )),
+            Ok(1.into())
+        );
+    }
+
+    #[test]
+    fn test_letrec() {
+        setup_native_proc_test!(letrec);
+        setup_native_proc_test!(letrec_star);
+
+        // (letrec ((x 1) (y x)) y) => (), as x is not assigned yet
+        assert_eq!(
+            letrec(list!(
+                list!(list!(intern("x"), 1), list!(intern("y"), intern("x"))),
+                intern("y")
+            )),
+            Ok(NIL)
+        );
+
+        // (letrec* ((x 1) (y x)) y) => 1
+        assert_eq!(
+            letrec_star(list!(
+                list!(list!(intern("x"), 1), list!(intern("y"), intern("x"))),
+                intern("y")
+            )),
+            Ok(1.into())
+        );
+    }
+
+    #[test]
+    fn test_begin() {
+        setup_native_proc_test!(begin, env);
+
+        // (begin) => ()
+        assert_eq!(begin(list!()), Ok(NIL));
+        // (begin 1 x) => 2
+        env.define("x", 2);
+        assert_eq!(begin(list!(1, intern("x"))), Ok(2.into()));
+    }
+
+    #[test]
+    fn test_when_unless() {
+        setup_native_proc_test!(when);
+        setup_native_proc_test!(unless);
+
+        assert_eq!(when(list!(true, 1, 2)), Ok(2.into()));
+        assert_eq!(when(list!(false, intern("undefined"))), Ok(NIL));
+        assert_eq!(unless(list!(false, 1, 2)), Ok(2.into()));
+        assert_eq!(unless(list!(true, intern("undefined"))), Ok(NIL));
+        assert!(when(list!()).is_err());
+    }
+
+    #[test]
+    fn test_cond() {
+        setup_native_proc_test!(cond);
+
+        // (cond (#f 1) (#t 2)) => 2
+        assert_eq!(cond(list!(list!(false, 1), list!(true, 2))), Ok(2.into()));
+        // (cond (#f 1)) => #f
+        assert_eq!(cond(list!(list!(false, 1))), Ok(false.into()));
+        // (cond (#f 1) (3)) => 3
+        assert_eq!(cond(list!(list!(false, 1), list!(3))), Ok(3.into()));
+        // (cond (#f undefined) (else 1 2)) => 2
+        assert_eq!(
+            cond(list!(
+                list!(false, intern("undefined")),
+                list!(intern("else"), 1, 2)
+            )),
+            Ok(2.into())
+        );
+        // (cond (1 =>)) => Err
+        assert!(cond(list!(list!(1, intern("=>")))).is_err());
+        // (cond 1) => Err
+        assert!(cond(list!(1)).is_err());
+    }
+
+    #[test]
+    fn test_case() {
+        setup_native_proc_test!(case);
+
+        // (case 2 ((1) 'one) ((2 3) 'two-or-three) (else 'other)) => two-or-three
+        let clauses = || {
+            list!(
+                list!(list!(1), list!(intern("quote"), intern("one"))),
+                list!(list!(2, 3), list!(intern("quote"), intern("two-or-three"))),
+                list!(intern("else"), list!(intern("quote"), intern("other")))
+            )
+        };
+        assert_eq!(
+            case(crate::list::cons(2, clauses())),
+            Ok(intern("two-or-three"))
+        );
+        assert_eq!(case(crate::list::cons(4, clauses())), Ok(intern("other")));
+        // (case 4 ((1) 'one)) => #f
+        assert_eq!(
+            case(list!(4, list!(list!(1), intern("undefined")))),
+            Ok(false.into())
+        );
+        // (case 1 (1 'one)) => Err
+        assert!(case(list!(1, list!(1, 2))).is_err());
+        // (case 1 ((1))) => Err
+        assert!(case(list!(1, list!(list!(1)))).is_err());
+    }
+
+    #[test]
+    fn test_do() {
+        setup_native_proc_test!(do_);
+
+        // (do ((i 5)) (#t i)) => 5
+        assert_eq!(
+            do_(list!(
+                list!(list!(intern("i"), 5)),
+                list!(true, intern("i"))
+            )),
+            Ok(5.into())
+        );
+        // (do ((i)) (#t)) => Err
+        assert!(do_(list!(list!(list!(intern("i"))), list!(true))).is_err());
+        // (do () ()) => Err
+        assert!(do_(list!(list!(), list!())).is_err());
     }
 
     #[test]
@@ -388,5 +1213,29 @@ mod tests {
 
         // (set! 1 "value") -> Err
         assert!(set(list!(1, "value")).is_err());
+
+        // (set! undefined "value") -> Err
+        assert!(set(list!(intern("undefined"), "value")).is_err());
+    }
+
+    #[test]
+    fn test_case_lambda() {
+        let evaluator = crate::eval::Evaluator::new();
+        let context = evaluator.context();
+        let case_lambda = |args| case_lambda("case-lambda", &args, context);
+
+        // (case-lambda ((a) a) ((a b) b))
+        let Ok(Expr::Proc(proc, _)) = case_lambda(list!(
+            list!(list!(intern("a")), intern("a")),
+            list!(list!(intern("a"), intern("b")), intern("b"))
+        )) else {
+            panic!("case-lambda should return a procedure");
+        };
+        assert_eq!(proc.arity(), Some(Arity::between(1, 2)));
+        assert_eq!(proc.apply([1.into(), 2.into()], context), Ok(2.into()));
+        assert!(proc.apply([], context).is_err());
+
+        // (case-lambda 1) -> Err
+        assert!(case_lambda(list!(1)).is_err());
     }
 }
@@ -0,0 +1,100 @@
+use crate::{
+    eval::{eval, EvalContext, EvalResult},
+    expr::Expr,
+    list::List,
+    promise::Promise,
+    utils::get_exact_1_arg,
+};
+
+/// `(delay expr)`
+pub fn delay(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    Ok(Expr::Promise(
+        Promise::delayed(expr.clone(), context.clone(), false),
+        None,
+    ))
+}
+
+/// `(delay-force expr)`
+///
+/// `expr` must evaluate to a promise, which is forced in place of the returned promise.
+/// Unlike `(delay (force expr))`, a long chain of such promises is forced iteratively.
+pub fn delay_force(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    Ok(Expr::Promise(
+        Promise::delayed(expr.clone(), context.clone(), true),
+        None,
+    ))
+}
+
+/// `(force obj)`
+///
+/// Returns the value of `obj` if it is a promise, or `obj` itself otherwise.
+pub fn force(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    match eval(get_exact_1_arg(proc_name, args)?, context)? {
+        Expr::Promise(promise, _) => promise.force(proc_name),
+        obj => Ok(obj),
+    }
+}
+
+/// `(make-promise obj)`
+///
+/// Returns a promise that is already forced to `obj`, or `obj` itself if it is a promise.
+pub fn make_promise(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    match eval(get_exact_1_arg(proc_name, args)?, context)? {
+        obj @ Expr::Promise(_, _) => Ok(obj),
+        obj => Ok(Expr::Promise(Promise::done(obj), None)),
+    }
+}
+
+/// `(promise? obj)`
+pub fn is_promise(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let obj = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(matches!(obj, Expr::Promise(_, _)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_delay() {
+        setup_native_proc_test!(delay, env);
+        env.define("x", 1);
+
+        let Ok(Expr::Promise(promise, _)) = delay(list!(intern("x"))) else {
+            panic!("delay should return a promise");
+        };
+        assert!(!promise.is_done());
+        assert_eq!(promise.force("force"), Ok(1.into()));
+
+        // memoized
+        env.define("x", 2);
+        assert_eq!(promise.force("force"), Ok(1.into()));
+
+        assert!(delay(list!()).is_err());
+        assert!(delay(list!(1, 2)).is_err());
+    }
+
+    #[test]
+    fn test_make_promise() {
+        setup_native_proc_test!(make_promise);
+
+        let Ok(Expr::Promise(promise, _)) = make_promise(list!(1)) else {
+            panic!("make-promise should return a promise");
+        };
+        assert!(promise.is_done());
+        assert_eq!(promise.force("force"), Ok(1.into()));
+    }
+
+    #[test]
+    fn test_is_promise() {
+        setup_native_proc_test!(is_promise);
+
+        let promise = Expr::Promise(Promise::done(1.into()), None);
+        assert_eq!(is_promise(list!(promise)), Ok(true.into()));
+        assert_eq!(is_promise(list!(1)), Ok(false.into()));
+    }
+}
@@ -1,6 +1,10 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::eval::{eval, EvalContext, EvalError, EvalResult};
 use crate::expr::{Expr, NIL};
 use crate::list::List;
+use crate::syntax_rules::strip_renames;
 use crate::utils::get_exact_1_arg;
 
 pub const QUOTE: &str = "quote";
@@ -9,7 +13,7 @@ pub const UNQUOTE: &str = "unquote";
 pub const UNQUOTE_SPLICING: &str = "unquote-splicing";
 
 pub fn quote(proc_name: &str, args: &List, _context: &EvalContext) -> EvalResult {
-    Ok(get_exact_1_arg(proc_name, args)?.clone())
+    Ok(strip_renames(get_exact_1_arg(proc_name, args)?).into_owned())
 }
 
 pub fn quasiquote(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
@@ -25,8 +29,16 @@ pub fn quasiquote(proc_name: &str, args: &List, context: &EvalContext) -> EvalRe
 }
 
 fn quasiquote_expr(expr: &Expr, context: &EvalContext) -> Result<Vec<Expr>, EvalError> {
+    if let Expr::Vector(vector, span) = expr {
+        let mut items = Vec::with_capacity(vector.borrow().len());
+        for item in vector.borrow().iter() {
+            items.extend(quasiquote_expr(item, context)?);
+        }
+        return Ok(vec![Expr::Vector(Rc::new(RefCell::new(items)), *span)]);
+    }
+
     let Expr::List(list, _) = expr else {
-        return Ok(vec![expr.clone()]);
+        return Ok(vec![strip_renames(expr).into_owned()]);
     };
 
     let List::Cons(cons) = list else {
@@ -44,10 +56,10 @@ fn quasiquote_expr(expr: &Expr, context: &EvalContext) -> Result<Vec<Expr>, Eval
             if let Some(cdar) = cons.cdar() {
                 exprs.push(eval(cdar, context)?);
             } else {
-                return Err(EvalError {
-                    message: format!("{UNQUOTE}: missing argument"),
-                    span: expr.span(),
-                });
+                return Err(EvalError::new(
+                    format!("{UNQUOTE}: missing argument"),
+                    expr.span(),
+                ));
             }
         }
         Some(UNQUOTE_SPLICING) => {
@@ -58,27 +70,45 @@ fn quasiquote_expr(expr: &Expr, context: &EvalContext) -> Result<Vec<Expr>, Eval
                         exprs.extend(list.iter().cloned());
                     }
                     _ => {
-                        return Err(EvalError {
-                            message: format!(
-                                "{UNQUOTE_SPLICING}: `{cdar}` does not evaluate to a list"
-                            ),
-                            span: cdar.span(),
-                        });
+                        return Err(EvalError::new(
+                            format!("{UNQUOTE_SPLICING}: `{cdar}` does not evaluate to a list"),
+                            cdar.span(),
+                        ));
                     }
                 }
             } else {
-                return Err(EvalError {
-                    message: format!("{UNQUOTE_SPLICING}: argument missing"),
-                    span: expr.span(),
-                });
+                return Err(EvalError::new(
+                    format!("{UNQUOTE_SPLICING}: argument missing"),
+                    expr.span(),
+                ));
             }
         }
         _ => {
             let mut v = Vec::with_capacity(list.len());
-            for expr in list.iter() {
-                v.extend(quasiquote_expr(expr, context)?);
+            let mut rest = list;
+            let mut tail = NIL;
+            while let List::Cons(cons) = rest {
+                if !std::ptr::eq(rest, list)
+                    && matches!(cons.car.as_ref(), Expr::Sym(name, _) if name == UNQUOTE)
+                {
+                    // `(a . ,b)` is read as `(a unquote b)`
+                    tail = quasiquote_expr(&rest.clone().into(), context)?.remove(0);
+                    break;
+                }
+                v.extend(quasiquote_expr(&cons.car, context)?);
+                match cons.cdr.as_ref() {
+                    Expr::List(list, _) => rest = list,
+                    expr => {
+                        tail = expr.clone();
+                        break;
+                    }
+                }
             }
-            exprs.push(Expr::from(v));
+            exprs.push(
+                v.into_iter()
+                    .rev()
+                    .fold(tail, |cdr, car| crate::list::cons(car, cdr).into()),
+            );
         }
     }
 
@@ -173,6 +203,26 @@ mod tests {
         assert_eq!(result, Ok(list!(0, 1, 2, 3, 4).into()));
     }
 
+    #[test]
+    fn test_quasiquote_vector() {
+        setup_native_proc_test!(quasiquote, env);
+
+        env.define_native_proc("+", crate::builtin::num::add);
+
+        let vector = |items: Vec<Expr>| Expr::Vector(Rc::new(RefCell::new(items)), None);
+
+        // `#(0 ,(+ 1 2) ,@'(4 5)) => #(0 3 4 5)
+        let result = quasiquote(list!(vector(vec![
+            0.into(),
+            list!(intern(UNQUOTE), list!(intern("+"), 1, 2)).into(),
+            list!(intern(UNQUOTE_SPLICING), list!(intern(QUOTE), list!(4, 5))).into(),
+        ])));
+        assert_eq!(
+            result,
+            Ok(vector(vec![0.into(), 3.into(), 4.into(), 5.into()]))
+        );
+    }
+
     #[test]
     fn test_quasiquote_unquote_splicing_error() {
         setup_native_proc_test!(quasiquote);
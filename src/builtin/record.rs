@@ -0,0 +1,224 @@
+use std::rc::Rc;
+
+use crate::{
+    eval::{EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    list::List,
+    proc::Proc,
+    record::{RecordProc, RecordType},
+};
+
+fn expect_symbol<'a>(proc_name: &str, what: &str, expr: &'a Expr) -> Result<&'a str, EvalError> {
+    match expr {
+        Expr::Sym(name, _) => Ok(name),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: expects a symbol for {what}, but got `{expr}`."),
+            expr.span(),
+        )),
+    }
+}
+
+/// `(define-record-type <type> (constructor field ...) predicate (field accessor [modifier]) ...)`
+///
+/// The constructor may be `#f` to omit it.
+pub fn define_record_type(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(type_expr), Some(constructor_expr), Some(predicate_expr)) =
+        (iter.next(), iter.next(), iter.next())
+    else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects a type name, a constructor and a predicate."
+        )));
+    };
+
+    let type_name = expect_symbol(proc_name, "a type name", type_expr)?;
+    let predicate_name = expect_symbol(proc_name, "a predicate name", predicate_expr)?;
+
+    // (field accessor [modifier])
+    let mut field_specs = Vec::new();
+    for spec_expr in iter {
+        let spec = match spec_expr {
+            Expr::List(list @ List::Cons(_), _) if list.is_proper() => list
+                .iter()
+                .map(|expr| expect_symbol(proc_name, "a field spec", expr))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => Vec::new(),
+        };
+        let (Some(field), Some(accessor), modifier, None) =
+            (spec.first(), spec.get(1), spec.get(2).copied(), spec.get(3))
+        else {
+            return Err(EvalError::new(
+                format!(
+                    "{proc_name}: `{spec_expr}` is not a valid field spec of (field accessor [modifier])."
+                ),
+                spec_expr.span(),
+            ));
+        };
+        if field_specs.iter().any(|(name, _, _)| name == field) {
+            return Err(EvalError::new(
+                format!("{proc_name}: duplicate field `{field}`."),
+                spec_expr.span(),
+            ));
+        }
+        field_specs.push((*field, *accessor, modifier));
+    }
+
+    let record_type = Rc::new(RecordType::new(
+        type_name,
+        field_specs
+            .iter()
+            .map(|(field, _, _)| field.to_string())
+            .collect(),
+    ));
+
+    let make_proc = |name: &str, op: RecordProc| {
+        Expr::Proc(
+            Proc::Record {
+                name: name.to_owned(),
+                record_type: record_type.clone(),
+                op,
+            },
+            None,
+        )
+    };
+
+    match constructor_expr {
+        Expr::Bool(false, _) => {}
+        Expr::List(list @ List::Cons(cons), _) if list.is_proper() => {
+            let constructor_name = expect_symbol(proc_name, "a constructor name", &cons.car)?;
+            let mut indices = Vec::new();
+            for field_expr in list.iter().skip(1) {
+                let field = expect_symbol(proc_name, "a field name", field_expr)?;
+                let Some(index) = record_type.field_index(field) else {
+                    return Err(EvalError::new(
+                        format!("{proc_name}: `{field}` is not a field of {type_name}."),
+                        field_expr.span(),
+                    ));
+                };
+                indices.push(index);
+            }
+            context.env.define(
+                constructor_name,
+                make_proc(constructor_name, RecordProc::Constructor(indices)),
+            );
+        }
+        _ => {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{constructor_expr}` is not a valid constructor spec."),
+                constructor_expr.span(),
+            ))
+        }
+    }
+
+    context.env.define(
+        predicate_name,
+        make_proc(predicate_name, RecordProc::Predicate),
+    );
+
+    for (index, (_, accessor, modifier)) in field_specs.into_iter().enumerate() {
+        context
+            .env
+            .define(accessor, make_proc(accessor, RecordProc::Accessor(index)));
+        if let Some(modifier) = modifier {
+            context
+                .env
+                .define(modifier, make_proc(modifier, RecordProc::Modifier(index)));
+        }
+    }
+
+    Ok(NIL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::{eval, Evaluator};
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    fn define_point(context: &EvalContext) -> EvalResult {
+        // (define-record-type <point> (make-point x y) point? (x point-x set-point-x!) (y point-y))
+        define_record_type(
+            "define-record-type",
+            &list!(
+                intern("<point>"),
+                list!(intern("make-point"), intern("x"), intern("y")),
+                intern("point?"),
+                list!(intern("x"), intern("point-x"), intern("set-point-x!")),
+                list!(intern("y"), intern("point-y"))
+            ),
+            context,
+        )
+    }
+
+    #[test]
+    fn test_define_record_type() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        assert_eq!(define_point(context), Ok(NIL));
+
+        let point = eval(&list!(intern("make-point"), 1, 2).into(), context).unwrap();
+        assert_eq!(point.to_string(), "#<record point x: 1 y: 2>");
+        context.env.define("p", point);
+
+        let call =
+            |name: &str, args: List| eval(&crate::list::cons(intern(name), args).into(), context);
+        assert_eq!(call("point?", list!(intern("p"))), Ok(true.into()));
+        assert_eq!(call("point?", list!(1)), Ok(false.into()));
+        assert_eq!(call("point-x", list!(intern("p"))), Ok(1.into()));
+        assert_eq!(call("point-y", list!(intern("p"))), Ok(2.into()));
+        assert_eq!(call("set-point-x!", list!(intern("p"), 5)), Ok(NIL));
+        assert_eq!(call("point-x", list!(intern("p"))), Ok(5.into()));
+
+        assert!(call("point-x", list!(1)).is_err());
+        assert!(call("point-x", list!()).is_err());
+        assert!(call("make-point", list!(1)).is_err());
+        assert!(context.env.lookup("set-point-y!").is_none());
+    }
+
+    #[test]
+    fn test_record_types_are_distinct() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        define_point(context).unwrap();
+        let old_point = eval(&list!(intern("make-point"), 1, 2).into(), context).unwrap();
+        context.env.define("old", old_point);
+
+        // redefining the type creates a new, unrelated type
+        define_point(context).unwrap();
+        let result = eval(&list!(intern("point?"), intern("old")).into(), context);
+        assert_eq!(result, Ok(false.into()));
+    }
+
+    #[test]
+    fn test_define_record_type_errors() {
+        setup_native_proc_test!(define_record_type);
+
+        assert!(define_record_type(list!(intern("<a>"), list!(intern("make-a")))).is_err());
+        assert!(define_record_type(list!(
+            intern("<a>"),
+            list!(intern("make-a"), intern("z")),
+            intern("a?")
+        ))
+        .is_err());
+        assert!(define_record_type(list!(
+            intern("<a>"),
+            false,
+            intern("a?"),
+            list!(intern("x"))
+        ))
+        .is_err());
+        assert!(define_record_type(list!(
+            intern("<a>"),
+            false,
+            intern("a?"),
+            list!(intern("x"), intern("a-x")),
+            list!(intern("x"), intern("a-x2"))
+        ))
+        .is_err());
+        assert_eq!(
+            define_record_type(list!(intern("<a>"), false, intern("a?"))),
+            Ok(NIL)
+        );
+    }
+}
@@ -2,7 +2,11 @@ use crate::{
     eval::{eval, EvalContext, EvalError, EvalResult},
     expr::Expr,
     list::List,
-    utils::{eval_into_int, eval_into_str, get_2_or_3_args, get_exact_1_arg, get_exact_2_args},
+    proc::Proc,
+    utils::{
+        eval_into_char, eval_into_int, eval_into_str, get_1_or_2_args, get_2_or_3_args,
+        get_exact_1_arg, get_exact_2_args, get_exact_3_args,
+    },
 };
 
 pub fn is_str(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
@@ -20,10 +24,10 @@ pub fn append(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
         match eval(expr, context)? {
             Expr::Str(text, _) => result += &text,
             _ => {
-                return Err(EvalError {
-                    message: format!("{proc_name}: `{expr}` does not evaluate to a string."),
-                    span: expr.span(),
-                })
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{expr}` does not evaluate to a string."),
+                    expr.span(),
+                ))
             }
         }
     }
@@ -44,10 +48,10 @@ pub fn length(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
     if let Expr::Str(text, _) = eval(expr, context)? {
         Ok(Expr::from(text.chars().count() as i32))
     } else {
-        Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a string."),
-            span: expr.span(),
-        })
+        Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a string."),
+            expr.span(),
+        ))
     }
 }
 
@@ -61,7 +65,7 @@ pub fn slice(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
     let end = if let Some(arg3) = opt_arg3 {
         eval_into_int(proc_name, "end index", arg3, context)?
     } else {
-        text_len as i32
+        text_len
     };
 
     let to_index = |pos: i32| -> usize {
@@ -83,6 +87,371 @@ pub fn slice(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult
     ))
 }
 
+pub fn string_ref(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_exact_2_args(proc_name, args)?;
+
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let index = eval_into_int(proc_name, "index", arg2, context)?;
+
+    match usize::try_from(index)
+        .ok()
+        .and_then(|i| text.chars().nth(i))
+    {
+        Some(ch) => Ok(Expr::from(ch)),
+        None => Err(EvalError::new(
+            format!("{proc_name}: index out-of-bounds {index}."),
+            arg2.span(),
+        )),
+    }
+}
+
+pub fn string_to_list(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(text.chars().map(Expr::from).collect::<Vec<_>>().into())
+}
+
+pub fn list_to_string(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    let list = match eval(expr, context)? {
+        Expr::List(list, _) if list.is_proper() => list,
+        _ => {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{expr}` does not evaluate to a list."),
+                expr.span(),
+            ))
+        }
+    };
+
+    let mut text = String::new();
+    for item in list.iter() {
+        match item {
+            Expr::Char(ch, _) => text.push(*ch),
+            _ => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{item}` is not a character."),
+                    expr.span(),
+                ))
+            }
+        }
+    }
+    Ok(Expr::Str(text, None))
+}
+
+/// What `string-index` and the `string-trim` family look for: a character, a predicate
+/// on characters, or whitespace if not given.
+enum CharMatcher {
+    Char(char),
+    Pred(Proc),
+    Whitespace,
+}
+
+impl CharMatcher {
+    fn eval(
+        proc_name: &str,
+        expr: Option<&Expr>,
+        context: &EvalContext,
+    ) -> Result<Self, EvalError> {
+        let Some(expr) = expr else {
+            return Ok(CharMatcher::Whitespace);
+        };
+        match eval(expr, context)? {
+            Expr::Char(ch, _) => Ok(CharMatcher::Char(ch)),
+            Expr::Proc(proc, _) => Ok(CharMatcher::Pred(proc)),
+            _ => Err(EvalError::new(
+                format!("{proc_name}: `{expr}` does not evaluate to a character or a procedure."),
+                expr.span(),
+            )),
+        }
+    }
+
+    fn matches(&self, ch: char, context: &EvalContext) -> Result<bool, EvalError> {
+        match self {
+            CharMatcher::Char(expected) => Ok(*expected == ch),
+            CharMatcher::Pred(proc) => Ok(proc.apply([Expr::from(ch)], context)?.is_truthy()),
+            CharMatcher::Whitespace => Ok(ch.is_whitespace()),
+        }
+    }
+}
+
+/// Converts a byte offset in `text` into a character index.
+fn char_index(text: &str, byte_offset: usize) -> Expr {
+    Expr::from(text[..byte_offset].chars().count() as i64)
+}
+
+/// `(string-split text [delimiter])`
+///
+/// Splits `text` by `delimiter`, which is a character or a non-empty string. Without
+/// a delimiter, splits `text` by whitespace and drops empty strings.
+pub fn string_split(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_1_or_2_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+
+    let parts: Vec<Expr> = match arg2 {
+        None => text.split_whitespace().map(Expr::from).collect(),
+        Some(arg2) => match eval(arg2, context)? {
+            Expr::Char(ch, _) => text.split(ch).map(Expr::from).collect(),
+            Expr::Str(delimiter, _) if !delimiter.is_empty() => {
+                text.split(delimiter.as_str()).map(Expr::from).collect()
+            }
+            _ => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{arg2}` is not a character or a non-empty string."),
+                    arg2.span(),
+                ))
+            }
+        },
+    };
+    Ok(parts.into())
+}
+
+/// `(string-join list [delimiter])`
+///
+/// Joins a list of strings with `delimiter`, which defaults to a space.
+pub fn string_join(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_1_or_2_args(proc_name, args)?;
+    let list = match eval(arg1, context)? {
+        Expr::List(list, _) if list.is_proper() => list,
+        _ => {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{arg1}` does not evaluate to a list."),
+                arg1.span(),
+            ))
+        }
+    };
+    let delimiter = match arg2 {
+        Some(arg2) => eval_into_str(proc_name, arg2, context)?,
+        None => " ".to_owned(),
+    };
+
+    let mut parts = Vec::new();
+    for item in list.iter() {
+        match item {
+            Expr::Str(text, _) => parts.push(text.as_str()),
+            _ => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{item}` is not a string."),
+                    arg1.span(),
+                ))
+            }
+        }
+    }
+    Ok(Expr::Str(parts.join(&delimiter), None))
+}
+
+/// `(string-index text char-or-pred)`
+///
+/// Returns the index of the first character that is `char`, or satisfies `pred`,
+/// or `#f` if there is none.
+pub fn string_index(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_exact_2_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let matcher = CharMatcher::eval(proc_name, Some(arg2), context)?;
+
+    for (index, ch) in text.chars().enumerate() {
+        if matcher.matches(ch, context)? {
+            return Ok(Expr::from(index as i64));
+        }
+    }
+    Ok(false.into())
+}
+
+/// `(string-contains text pattern)`
+///
+/// Returns the index where `pattern` first occurs in `text`, or `#f` if it does not.
+pub fn string_contains(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_exact_2_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let pattern = eval_into_str(proc_name, arg2, context)?;
+
+    Ok(text
+        .find(&pattern)
+        .map_or(false.into(), |offset| char_index(&text, offset)))
+}
+
+/// `(string-prefix? prefix text)`
+pub fn is_string_prefix(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_exact_2_args(proc_name, args)?;
+    let prefix = eval_into_str(proc_name, arg1, context)?;
+    let text = eval_into_str(proc_name, arg2, context)?;
+
+    Ok(text.starts_with(&prefix).into())
+}
+
+/// `(string-suffix? suffix text)`
+pub fn is_string_suffix(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2) = get_exact_2_args(proc_name, args)?;
+    let suffix = eval_into_str(proc_name, arg1, context)?;
+    let text = eval_into_str(proc_name, arg2, context)?;
+
+    Ok(text.ends_with(&suffix).into())
+}
+
+pub fn string_upcase(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::Str(text.to_uppercase(), None))
+}
+
+pub fn string_downcase(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::Str(text.to_lowercase(), None))
+}
+
+/// `(string-foldcase text)`
+///
+/// Unlike `string-downcase`, each character is folded on its own, e.g. a final `Σ`
+/// becomes `σ` rather than `ς`, so that folded strings can be compared.
+pub fn string_foldcase(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let text = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::Str(
+        text.chars().flat_map(char::to_lowercase).collect(),
+        None,
+    ))
+}
+
+fn trim(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+    trims_left: bool,
+    trims_right: bool,
+) -> EvalResult {
+    let (arg1, arg2) = get_1_or_2_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let matcher = CharMatcher::eval(proc_name, arg2, context)?;
+
+    let chars: Vec<char> = text.chars().collect();
+    let (mut beg, mut end) = (0, chars.len());
+    while trims_left && beg < end && matcher.matches(chars[beg], context)? {
+        beg += 1;
+    }
+    while trims_right && beg < end && matcher.matches(chars[end - 1], context)? {
+        end -= 1;
+    }
+    Ok(Expr::Str(chars[beg..end].iter().collect(), None))
+}
+
+/// `(string-trim text [char-or-pred])`
+///
+/// Removes the leading characters that are `char`, or satisfy `pred`, or are whitespace
+/// if neither is given.
+pub fn string_trim(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    trim(proc_name, args, context, true, false)
+}
+
+/// `(string-trim-right text [char-or-pred])`
+pub fn string_trim_right(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    trim(proc_name, args, context, false, true)
+}
+
+/// `(string-trim-both text [char-or-pred])`
+pub fn string_trim_both(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    trim(proc_name, args, context, true, true)
+}
+
+/// `(string-replace text pattern replacement)`
+///
+/// Replaces all occurrences of `pattern`, which must not be empty, with `replacement`.
+pub fn string_replace(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (arg1, arg2, arg3) = get_exact_3_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let pattern = eval_into_str(proc_name, arg2, context)?;
+    let replacement = eval_into_str(proc_name, arg3, context)?;
+
+    if pattern.is_empty() {
+        return Err(EvalError::new(
+            format!("{proc_name}: the pattern must not be empty."),
+            arg2.span(),
+        ));
+    }
+    Ok(Expr::Str(text.replace(&pattern, &replacement), None))
+}
+
+fn pad(proc_name: &str, args: &List, context: &EvalContext, pads_left: bool) -> EvalResult {
+    let (arg1, arg2, arg3) = get_2_or_3_args(proc_name, args)?;
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let len = eval_into_int(proc_name, "length", arg2, context)?;
+    let ch = match arg3 {
+        Some(arg3) => eval_into_char(proc_name, arg3, context)?,
+        None => ' ',
+    };
+
+    let Ok(len) = usize::try_from(len) else {
+        return Err(EvalError::new(
+            format!("{proc_name}: length must not be negative, but got {len}."),
+            arg2.span(),
+        ));
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    let padding = std::iter::repeat_n(ch, len.saturating_sub(chars.len()));
+    let padded: String = if pads_left {
+        padding
+            .chain(chars[chars.len().saturating_sub(len)..].iter().copied())
+            .collect()
+    } else {
+        chars[..len.min(chars.len())]
+            .iter()
+            .copied()
+            .chain(padding)
+            .collect()
+    };
+    Ok(Expr::Str(padded, None))
+}
+
+/// `(string-pad text len [char])`
+///
+/// Pads `text` on the left with `char`, which defaults to a space, to `len` characters.
+/// If `text` is longer, keeps its last `len` characters.
+pub fn string_pad(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    pad(proc_name, args, context, true)
+}
+
+/// `(string-pad-right text len [char])`
+///
+/// Pads `text` on the right with `char`, which defaults to a space, to `len` characters.
+/// If `text` is longer, keeps its first `len` characters.
+pub fn string_pad_right(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    pad(proc_name, args, context, false)
+}
+
+/// `(string-copy text [start [end]])`
+///
+/// Unlike `str-slice`, the indices must satisfy `0 <= start <= end <= length`.
+pub fn string_copy(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(arg1), arg2, arg3, None) = (iter.next(), iter.next(), iter.next(), iter.next())
+    else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects 1 to 3 args."
+        )));
+    };
+
+    let text = eval_into_str(proc_name, arg1, context)?;
+    let text_len = text.chars().count() as i32;
+    let beg = match arg2 {
+        Some(arg2) => eval_into_int(proc_name, "start index", arg2, context)?,
+        None => 0,
+    };
+    let end = match arg3 {
+        Some(arg3) => eval_into_int(proc_name, "end index", arg3, context)?,
+        None => text_len,
+    };
+
+    if !(0 <= beg && beg <= end && end <= text_len) {
+        return Err(EvalError::new(
+            format!("{proc_name}: index out-of-bounds {beg} to {end}."),
+            args.span(),
+        ));
+    }
+    Ok(Expr::Str(
+        text.chars()
+            .skip(beg as usize)
+            .take((end - beg) as usize)
+            .collect(),
+        None,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,4 +559,153 @@ mod tests {
         // error: (str-slice "abcdef" 0.5 1)
         assert!(slice(list!("abcdef", 0.5, 1)).is_err());
     }
+
+    #[test]
+    fn test_string_ref() {
+        setup_native_proc_test!(string_ref);
+
+        // (string-ref "abc" 1) => #\b
+        assert_eq!(string_ref(list!("abc", 1)), Ok(Expr::from('b')));
+
+        // (string-ref "λx" 0) => #\λ
+        assert_eq!(string_ref(list!("λx", 0)), Ok(Expr::from('λ')));
+
+        // error: (string-ref "abc" 3)
+        assert!(string_ref(list!("abc", 3)).is_err());
+
+        // error: (string-ref "abc" -1)
+        assert!(string_ref(list!("abc", -1)).is_err());
+    }
+
+    #[test]
+    fn test_string_to_list() {
+        setup_native_proc_test!(string_to_list);
+
+        // (string->list "ab") => (#\a #\b)
+        assert_eq!(string_to_list(list!("ab")), Ok(list!('a', 'b').into()));
+
+        // (string->list "") => ()
+        assert_eq!(string_to_list(list!("")), Ok(list!().into()));
+
+        // error: (string->list 1)
+        assert!(string_to_list(list!(1)).is_err());
+    }
+
+    #[test]
+    fn test_list_to_string() {
+        setup_native_proc_test!(list_to_string);
+
+        let quoted = |list: List| list!(crate::expr::intern("quote"), list);
+
+        // (list->string '(#\a #\b)) => "ab"
+        assert_eq!(
+            list_to_string(list!(quoted(list!('a', 'b')))),
+            Ok(Expr::from("ab"))
+        );
+
+        // (list->string '()) => ""
+        assert_eq!(list_to_string(list!(quoted(list!()))), Ok(Expr::from("")));
+
+        // error: (list->string '(#\a "b"))
+        assert!(list_to_string(list!(quoted(list!('a', "b")))).is_err());
+    }
+
+    #[test]
+    fn test_string_split() {
+        setup_native_proc_test!(string_split);
+
+        // (string-split " a  b ") => ("a" "b")
+        assert_eq!(string_split(list!(" a  b ")), Ok(list!("a", "b").into()));
+
+        // (string-split "a,,b" #\,) => ("a" "" "b")
+        assert_eq!(
+            string_split(list!("a,,b", ',')),
+            Ok(list!("a", "", "b").into())
+        );
+
+        // (string-split "α::β" "::") => ("α" "β")
+        assert_eq!(
+            string_split(list!("α::β", "::")),
+            Ok(list!("α", "β").into())
+        );
+
+        // error: (string-split "abc" "")
+        assert!(string_split(list!("abc", "")).is_err());
+    }
+
+    #[test]
+    fn test_string_contains() {
+        setup_native_proc_test!(string_contains);
+
+        // (string-contains "αβγβ" "β") => 1
+        assert_eq!(string_contains(list!("αβγβ", "β")), Ok(Expr::from(1)));
+
+        // (string-contains "abc" "") => 0
+        assert_eq!(string_contains(list!("abc", "")), Ok(Expr::from(0)));
+
+        // (string-contains "abc" "d") => #f
+        assert_eq!(string_contains(list!("abc", "d")), Ok(Expr::from(false)));
+    }
+
+    #[test]
+    fn test_string_foldcase() {
+        setup_native_proc_test!(string_foldcase);
+
+        // (string-foldcase "ΣΑΣ") => "σασ"
+        assert_eq!(string_foldcase(list!("ΣΑΣ")), Ok(Expr::from("σασ")));
+    }
+
+    #[test]
+    fn test_string_trim() {
+        setup_native_proc_test!(string_trim_both);
+
+        // (string-trim-both "\u{3000} a b\t") => "a b"
+        assert_eq!(
+            string_trim_both(list!("\u{3000} a b\t")),
+            Ok(Expr::from("a b"))
+        );
+
+        // (string-trim-both "--a-b--" #\-) => "a-b"
+        assert_eq!(
+            string_trim_both(list!("--a-b--", '-')),
+            Ok(Expr::from("a-b"))
+        );
+
+        // (string-trim-both "----" #\-) => ""
+        assert_eq!(string_trim_both(list!("----", '-')), Ok(Expr::from("")));
+    }
+
+    #[test]
+    fn test_string_pad() {
+        setup_native_proc_test!(string_pad);
+
+        // (string-pad "42" 5 #\0) => "00042"
+        assert_eq!(string_pad(list!("42", 5, '0')), Ok(Expr::from("00042")));
+
+        // (string-pad "αβγ" 2) => "βγ"
+        assert_eq!(string_pad(list!("αβγ", 2)), Ok(Expr::from("βγ")));
+
+        // error: (string-pad "abc" -1)
+        assert!(string_pad(list!("abc", -1)).is_err());
+    }
+
+    #[test]
+    fn test_string_copy() {
+        setup_native_proc_test!(string_copy);
+
+        // (string-copy "αβγ") => "αβγ"
+        assert_eq!(string_copy(list!("αβγ")), Ok(Expr::from("αβγ")));
+
+        // (string-copy "αβγ" 1) => "βγ"
+        assert_eq!(string_copy(list!("αβγ", 1)), Ok(Expr::from("βγ")));
+
+        // (string-copy "αβγ" 1 2) => "β"
+        assert_eq!(string_copy(list!("αβγ", 1, 2)), Ok(Expr::from("β")));
+
+        // error: (string-copy "αβγ" 2 4)
+        assert!(string_copy(list!("αβγ", 2, 4)).is_err());
+
+        // error: (string-copy "αβγ" 2 1)
+        assert!(string_copy(list!("αβγ", 2, 1)).is_err());
+    }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::Expr,
+    list::List,
+    symbol::Symbol,
+    utils::{eval_into_str, get_exact_1_arg},
+};
+
+pub fn is_symbol(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    Ok(matches!(eval(arg, context)?, Expr::Sym(_, _)).into())
+}
+
+pub fn string_to_symbol(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let name = eval_into_str(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(Expr::Sym(Symbol::new(&name), None))
+}
+
+pub fn symbol_to_string(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    match eval(expr, context)? {
+        Expr::Sym(symbol, _) => Ok(Expr::from(symbol.as_str())),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a symbol."),
+            expr.span(),
+        )),
+    }
+}
+
+/// `(gensym [prefix])`
+///
+/// Returns a fresh symbol that is not `eq?` to any other symbol. The prefix can be
+/// a string or a symbol, and defaults to `g`.
+pub fn gensym(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (prefix_expr, None) = (iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects 0 or 1 argument."
+        )));
+    };
+
+    let prefix = match prefix_expr {
+        Some(expr) => match eval(expr, context)? {
+            Expr::Str(text, _) => text,
+            Expr::Sym(symbol, _) => symbol.to_string(),
+            _ => {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{expr}` does not evaluate to a string or a symbol."),
+                    expr.span(),
+                ))
+            }
+        },
+        None => "g".to_owned(),
+    };
+
+    Ok(Expr::Sym(Symbol::gensym(&prefix), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_is_symbol() {
+        setup_native_proc_test!(is_symbol);
+
+        assert_eq!(
+            is_symbol(list!(list!(intern("quote"), intern("a")))),
+            Ok(true.into())
+        );
+        assert_eq!(is_symbol(list!("a")), Ok(false.into()));
+    }
+
+    #[test]
+    fn test_string_symbol_conversion() {
+        setup_native_proc_test!(string_to_symbol);
+        setup_native_proc_test!(symbol_to_string);
+
+        assert_eq!(string_to_symbol(list!("abc")), Ok(intern("abc")));
+        assert_eq!(
+            symbol_to_string(list!(list!(intern("quote"), intern("abc")))),
+            Ok("abc".into())
+        );
+        assert!(string_to_symbol(list!(1)).is_err());
+        assert!(symbol_to_string(list!("abc")).is_err());
+    }
+
+    #[test]
+    fn test_gensym() {
+        setup_native_proc_test!(gensym);
+
+        let g1 = gensym(list!()).unwrap();
+        let g2 = gensym(list!()).unwrap();
+        assert_ne!(g1, g2);
+
+        let Ok(Expr::Sym(symbol, _)) = gensym(list!("tmp")) else {
+            panic!("gensym should return a symbol");
+        };
+        assert!(symbol.starts_with("tmp"));
+        assert_ne!(Expr::Sym(symbol.clone(), None), intern(symbol.as_str()));
+
+        assert!(gensym(list!(1)).is_err());
+        assert!(gensym(list!("a", "b")).is_err());
+    }
+}
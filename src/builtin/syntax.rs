@@ -0,0 +1,224 @@
+use std::rc::Rc;
+
+use super::primitive::{eval_body, parse_bindings};
+use crate::{
+    eval::{self, eval, EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    list::List,
+    proc::Proc,
+    symbol::Symbol,
+    syntax_rules::SyntaxRules,
+    utils::{get_exact_1_arg, get_exact_2_args},
+};
+
+/// `(syntax-rules (literal ...) (pattern template) ...)`
+///
+/// A custom ellipsis can be given before the literals, as in
+/// `(syntax-rules ::: (literal ...) (pattern template) ...)`.
+pub fn syntax_rules(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let mut expr = iter.next();
+    let ellipsis = match expr {
+        Some(Expr::Sym(ellipsis, _)) => {
+            expr = iter.next();
+            Some(ellipsis.clone())
+        }
+        _ => None,
+    };
+
+    let Some(Expr::List(literal_list, _)) = expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a list of literals."),
+            expr.and_then(|e| e.span()),
+        ));
+    };
+    let mut literals = Vec::new();
+    for literal in literal_list.iter() {
+        let Expr::Sym(literal, _) = literal else {
+            return Err(EvalError::new(
+                format!("{proc_name}: literal `{literal}` is not a symbol."),
+                literal.span(),
+            ));
+        };
+        literals.push(literal.clone());
+    }
+
+    let mut rules = Vec::new();
+    for rule in iter {
+        let Expr::List(rule_list, _) = rule else {
+            return Err(EvalError::new(
+                format!("{proc_name}: `{rule}` is not a rule of (pattern template)."),
+                rule.span(),
+            ));
+        };
+        let (pattern, template) = get_exact_2_args(proc_name, rule_list)?;
+        rules.push((pattern.clone(), template.clone()));
+    }
+
+    let rules = SyntaxRules::new(ellipsis, literals, rules, context.env.clone())
+        .map_err(|message| EvalError::new(format!("{proc_name}: {message}"), args.span()))?;
+    Ok(Expr::Proc(
+        Proc::SyntaxRules {
+            name: None,
+            rules: Rc::new(rules),
+        },
+        None,
+    ))
+}
+
+/// Evaluates `expr` into a `syntax-rules` transformer named `name`.
+fn eval_into_syntax(
+    proc_name: &str,
+    name: &Symbol,
+    expr: &Expr,
+    context: &EvalContext,
+) -> EvalResult {
+    match eval(expr, context)? {
+        Expr::Proc(Proc::SyntaxRules { rules, .. }, span) => Ok(Expr::Proc(
+            Proc::SyntaxRules {
+                name: Some(name.to_string()),
+                rules,
+            },
+            span,
+        )),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` is not a syntax-rules form."),
+            expr.span(),
+        )),
+    }
+}
+
+/// `(define-syntax name (syntax-rules ...))`
+pub fn define_syntax(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (name_expr, spec) = get_exact_2_args(proc_name, args)?;
+    let Expr::Sym(name, _) = name_expr else {
+        return Err(EvalError::new(
+            format!("{proc_name}: expects a symbol for the syntax name."),
+            name_expr.span(),
+        ));
+    };
+
+    context
+        .env
+        .define(name, eval_into_syntax(proc_name, name, spec, context)?);
+    Ok(NIL)
+}
+
+/// `(let-syntax ((name (syntax-rules ...)) ...) body ...)`
+///
+/// The templates refer to the bindings outside of the form.
+pub fn let_syntax(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    bind_syntax(proc_name, args, context, false)
+}
+
+/// `(letrec-syntax ((name (syntax-rules ...)) ...) body ...)`
+///
+/// The templates can refer to the macros defined by the form itself.
+pub fn letrec_syntax(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    bind_syntax(proc_name, args, context, true)
+}
+
+fn bind_syntax(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+    is_recursive: bool,
+) -> EvalResult {
+    let mut iter = args.iter();
+    let bindings = parse_bindings(proc_name, iter.next())?;
+
+    let syntax_context = EvalContext::derive_from(context);
+    let spec_context = if is_recursive {
+        &syntax_context
+    } else {
+        context
+    };
+    for (name, spec) in bindings {
+        let syntax = eval_into_syntax(proc_name, name, spec, spec_context)?;
+        syntax_context.env.define(name, syntax);
+    }
+    eval_body(iter, &syntax_context)
+}
+
+/// `(macroexpand-1 form)`
+///
+/// Returns the expansion of `form` if it is a macro use, or `form` itself otherwise.
+pub fn macroexpand_1(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let form = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    Ok(eval::macroexpand_1(&form, context)?.unwrap_or(form))
+}
+
+/// `(macroexpand form)`
+///
+/// Expands `form` until it is no longer a macro use.
+pub fn macroexpand(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let form = eval(get_exact_1_arg(proc_name, args)?, context)?;
+    eval::macroexpand(&form, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    #[test]
+    fn test_syntax_rules() {
+        setup_native_proc_test!(syntax_rules);
+
+        let rules = syntax_rules(list!(
+            list!(),
+            list!(list!(intern("_"), intern("a")), intern("a"))
+        ));
+        assert!(matches!(rules, Ok(Expr::Proc(Proc::SyntaxRules { .. }, _))));
+
+        // custom ellipsis
+        let rules = syntax_rules(list!(
+            intern(":::"),
+            list!(),
+            list!(list!(intern("_"), intern("a"), intern(":::")), intern("a"))
+        ));
+        assert!(rules.is_ok());
+
+        assert!(syntax_rules(list!()).is_err());
+        assert!(syntax_rules(list!(list!(1))).is_err());
+        assert!(syntax_rules(list!(list!(), list!(intern("_")))).is_err());
+        assert!(syntax_rules(list!(list!(), list!(intern("_"), 1))).is_err());
+        // an ellipsis cannot start a pattern, nor appear twice in a list
+        assert!(syntax_rules(list!(list!(), list!(list!(intern("_"), intern("...")), 1))).is_err());
+        assert!(syntax_rules(list!(
+            list!(),
+            list!(
+                list!(
+                    intern("_"),
+                    intern("a"),
+                    intern("..."),
+                    intern("b"),
+                    intern("...")
+                ),
+                1
+            )
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_define_syntax() {
+        setup_native_proc_test!(define_syntax, env);
+        env.define_native_proc("syntax-rules", syntax_rules);
+
+        let spec = list!(
+            intern("syntax-rules"),
+            list!(),
+            list!(list!(intern("_"), intern("a")), intern("a"))
+        );
+        assert_eq!(define_syntax(list!(intern("id"), spec.clone())), Ok(NIL));
+        let Some(Expr::Proc(Proc::SyntaxRules { name, .. }, _)) = env.lookup("id") else {
+            panic!("id should be defined as a syntax");
+        };
+        assert_eq!(name.as_deref(), Some("id"));
+
+        assert!(define_syntax(list!(1, spec)).is_err());
+        assert!(define_syntax(list!(intern("id"), 1)).is_err());
+    }
+}
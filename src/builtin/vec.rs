@@ -0,0 +1,351 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{
+    eval::{eval, EvalContext, EvalError, EvalResult},
+    expr::{Expr, NIL},
+    list::{List, ListIter},
+    utils::{
+        eval_into_int, eval_into_proc, eval_into_vector, get_exact_1_arg, get_exact_2_args,
+        get_exact_3_args,
+    },
+};
+
+fn make_vector_expr(items: Vec<Expr>) -> Expr {
+    Expr::Vector(Rc::new(RefCell::new(items)), None)
+}
+
+pub(super) fn eval_into_index(
+    proc_name: &str,
+    expr: &Expr,
+    len: usize,
+    context: &EvalContext,
+) -> Result<usize, EvalError> {
+    let index = eval_into_int(proc_name, "index", expr, context)?;
+    if index >= 0 && (index as usize) < len {
+        Ok(index as usize)
+    } else {
+        Err(EvalError::new(
+            format!("{proc_name}: index out-of-bounds {index}."),
+            expr.span(),
+        ))
+    }
+}
+
+/// Evaluates the optional `start` and `end` arguments that follow a vector or bytevector
+/// argument.
+pub(super) fn eval_into_range(
+    proc_name: &str,
+    mut args: ListIter,
+    len: usize,
+    context: &EvalContext,
+) -> Result<(usize, usize), EvalError> {
+    let mut eval_bound = |name: &str, default: usize| -> Result<usize, EvalError> {
+        let Some(expr) = args.next() else {
+            return Ok(default);
+        };
+        let bound = eval_into_int(proc_name, name, expr, context)?;
+        if bound >= 0 && bound as usize <= len {
+            Ok(bound as usize)
+        } else {
+            Err(EvalError::new(
+                format!("{proc_name}: {name} out-of-bounds {bound}."),
+                expr.span(),
+            ))
+        }
+    };
+
+    let start = eval_bound("start index", 0)?;
+    let end = eval_bound("end index", len)?;
+
+    if let Some(expr) = args.next() {
+        return Err(EvalError::new(
+            format!("{proc_name}: too many arguments."),
+            expr.span(),
+        ));
+    }
+    if start > end {
+        return Err(EvalError::from(format!(
+            "{proc_name}: start index {start} is greater than end index {end}."
+        )));
+    }
+
+    Ok((start, end))
+}
+
+pub fn is_vector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let arg = get_exact_1_arg(proc_name, args)?;
+    Ok(matches!(eval(arg, context)?, Expr::Vector(_, _)).into())
+}
+
+pub fn vector(_: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let items = args
+        .iter()
+        .map(|arg| eval(arg, context))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(make_vector_expr(items))
+}
+
+pub fn make_vector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(len_expr), fill_expr, None) = (iter.next(), iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects 1 or 2 arguments."
+        )));
+    };
+
+    let len = eval_into_int(proc_name, "length", len_expr, context)?;
+    if len < 0 {
+        return Err(EvalError::new(
+            format!("{proc_name}: length must be zero or positive integer."),
+            len_expr.span(),
+        ));
+    }
+
+    let fill = match fill_expr {
+        Some(expr) => eval(expr, context)?,
+        None => NIL,
+    };
+
+    Ok(make_vector_expr(vec![fill; len as usize]))
+}
+
+pub fn vector_length(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let vector = eval_into_vector(proc_name, get_exact_1_arg(proc_name, args)?, context)?;
+    let len = vector.borrow().len();
+    Ok(Expr::from(len as i64))
+}
+
+pub fn vector_ref(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (vector_expr, index_expr) = get_exact_2_args(proc_name, args)?;
+    let vector = eval_into_vector(proc_name, vector_expr, context)?;
+    let len = vector.borrow().len();
+    let index = eval_into_index(proc_name, index_expr, len, context)?;
+
+    let item = vector.borrow()[index].clone();
+    Ok(item)
+}
+
+pub fn vector_set(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let (vector_expr, index_expr, value_expr) = get_exact_3_args(proc_name, args)?;
+    let vector = eval_into_vector(proc_name, vector_expr, context)?;
+    let len = vector.borrow().len();
+    let index = eval_into_index(proc_name, index_expr, len, context)?;
+    let value = eval(value_expr, context)?;
+
+    vector.borrow_mut()[index] = value;
+    Ok(NIL)
+}
+
+pub fn vector_to_list(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let Some(vector_expr) = iter.next() else {
+        return Err(EvalError::from(format!("{proc_name} needs an argument.")));
+    };
+    let vector = eval_into_vector(proc_name, vector_expr, context)?;
+    let len = vector.borrow().len();
+    let (start, end) = eval_into_range(proc_name, iter, len, context)?;
+
+    let items = vector.borrow()[start..end].to_vec();
+    Ok(items.into())
+}
+
+pub fn list_to_vector(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let expr = get_exact_1_arg(proc_name, args)?;
+    match eval(expr, context)? {
+        Expr::List(list, _) if list.is_proper() => {
+            Ok(make_vector_expr(list.iter().cloned().collect()))
+        }
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a list."),
+            expr.span(),
+        )),
+    }
+}
+
+pub fn vector_fill(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut iter = args.iter();
+    let (Some(vector_expr), Some(fill_expr)) = (iter.next(), iter.next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects at least 2 arguments."
+        )));
+    };
+    let vector = eval_into_vector(proc_name, vector_expr, context)?;
+    let fill = eval(fill_expr, context)?;
+    let len = vector.borrow().len();
+    let (start, end) = eval_into_range(proc_name, iter, len, context)?;
+
+    vector.borrow_mut()[start..end].fill(fill);
+    Ok(NIL)
+}
+
+/// Calls `proc` with the elements at the same index of the given vectors, stopping at the
+/// end of the shortest vector.
+fn for_each_index<F>(
+    proc_name: &str,
+    args: &List,
+    context: &EvalContext,
+    mut f: F,
+) -> Result<(), EvalError>
+where
+    F: FnMut(Expr) -> Result<(), EvalError>,
+{
+    let mut iter = args.iter();
+    let (Some(proc_expr), Some(_)) = (iter.next(), iter.clone().next()) else {
+        return Err(EvalError::from(format!(
+            "{proc_name}: expects a procedure and at least 1 vector."
+        )));
+    };
+    let proc = eval_into_proc(proc_name, proc_expr, context)?;
+    let vectors = iter
+        .map(|expr| eval_into_vector(proc_name, expr, context))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for index in 0.. {
+        // The procedure may mutate the vectors, so re-check the length every time.
+        let Some(items) = vectors
+            .iter()
+            .map(|vector| vector.borrow().get(index).cloned())
+            .collect::<Option<Vec<_>>>()
+        else {
+            break;
+        };
+        f(proc.apply(items, context)?)?;
+    }
+
+    Ok(())
+}
+
+pub fn vector_map(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    let mut items = Vec::new();
+    for_each_index(proc_name, args, context, |item| {
+        items.push(item);
+        Ok(())
+    })?;
+    Ok(make_vector_expr(items))
+}
+
+pub fn vector_for_each(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+    for_each_index(proc_name, args, context, |_| Ok(()))?;
+    Ok(NIL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+    use crate::expr::intern;
+    use crate::macros::*;
+
+    fn vector_of(items: Vec<i32>) -> Expr {
+        make_vector_expr(items.into_iter().map(Expr::from).collect())
+    }
+
+    #[test]
+    fn test_is_vector() {
+        setup_native_proc_test!(is_vector);
+
+        assert_eq!(is_vector(list!(vector_of(vec![1]))), Ok(true.into()));
+        assert_eq!(is_vector(list!(1)), Ok(false.into()));
+        assert_eq!(
+            is_vector(list!(list!(intern("quote"), list!(1)))),
+            Ok(false.into())
+        );
+    }
+
+    #[test]
+    fn test_vector() {
+        setup_native_proc_test!(vector);
+
+        assert_eq!(vector(list!()), Ok(vector_of(vec![])));
+        assert_eq!(vector(list!(1, 2)), Ok(vector_of(vec![1, 2])));
+    }
+
+    #[test]
+    fn test_make_vector() {
+        setup_native_proc_test!(make_vector);
+
+        assert_eq!(make_vector(list!(2, 0)), Ok(vector_of(vec![0, 0])));
+        assert_eq!(make_vector(list!(0)), Ok(vector_of(vec![])));
+        assert_eq!(
+            make_vector(list!(1)).map(|v| v.to_string()),
+            Ok("#(())".to_owned())
+        );
+        assert!(make_vector(list!(-1)).is_err());
+        assert!(make_vector(list!()).is_err());
+        assert!(make_vector(list!(1, 2, 3)).is_err());
+    }
+
+    #[test]
+    fn test_vector_ref_set() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        context.env.define("v", vector_of(vec![1, 2, 3]));
+        let v = || intern("v");
+
+        assert_eq!(vector_ref("", &list!(v(), 0), context), Ok(1.into()));
+        assert_eq!(vector_ref("", &list!(v(), 2), context), Ok(3.into()));
+        assert!(vector_ref("", &list!(v(), 3), context).is_err());
+        assert!(vector_ref("", &list!(v(), -1), context).is_err());
+        assert!(vector_ref("", &list!(1, 0), context).is_err());
+
+        assert_eq!(vector_set("", &list!(v(), 1, 5), context), Ok(NIL));
+        assert_eq!(context.env.lookup("v"), Some(vector_of(vec![1, 5, 3])));
+        assert!(vector_set("", &list!(v(), 3, 5), context).is_err());
+
+        assert_eq!(vector_length("", &list!(v()), context), Ok(3.into()));
+    }
+
+    #[test]
+    fn test_vector_to_list() {
+        setup_native_proc_test!(vector_to_list);
+
+        let v = vector_of(vec![1, 2, 3]);
+        assert_eq!(vector_to_list(list!(v.clone())), Ok(list!(1, 2, 3).into()));
+        assert_eq!(vector_to_list(list!(v.clone(), 1)), Ok(list!(2, 3).into()));
+        assert_eq!(vector_to_list(list!(v.clone(), 1, 2)), Ok(list!(2).into()));
+        assert!(vector_to_list(list!(v.clone(), 2, 1)).is_err());
+        assert!(vector_to_list(list!(v.clone(), 0, 4)).is_err());
+        assert!(vector_to_list(list!(v, 0, 1, 2)).is_err());
+    }
+
+    #[test]
+    fn test_list_to_vector() {
+        setup_native_proc_test!(list_to_vector);
+
+        let args = list!(list!(intern("quote"), list!(1, 2)));
+        assert_eq!(list_to_vector(args), Ok(vector_of(vec![1, 2])));
+
+        let args = list!(list!(intern("quote"), crate::list::cons(1, 2)));
+        assert!(list_to_vector(args).is_err());
+    }
+
+    #[test]
+    fn test_vector_fill() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        context.env.define("v", vector_of(vec![1, 2, 3]));
+
+        assert_eq!(vector_fill("", &list!(intern("v"), 0, 1), context), Ok(NIL));
+        assert_eq!(context.env.lookup("v"), Some(vector_of(vec![1, 0, 0])));
+
+        assert_eq!(vector_fill("", &list!(intern("v"), 9), context), Ok(NIL));
+        assert_eq!(context.env.lookup("v"), Some(vector_of(vec![9, 9, 9])));
+    }
+
+    #[test]
+    fn test_vector_map() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        let args = list!(
+            intern("num-add"),
+            vector_of(vec![1, 2, 3]),
+            vector_of(vec![10, 20])
+        );
+        assert_eq!(vector_map("", &args, context), Ok(vector_of(vec![11, 22])));
+
+        assert!(vector_map("", &list!(intern("num-add")), context).is_err());
+        assert!(vector_map("", &list!(1, vector_of(vec![1])), context).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use std::cell::Cell;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use crate::eval::{eval, EvalContext, EvalError, EvalResult};
+use crate::expr::{Expr, NIL};
+use crate::list::List;
+
+/// An escaping continuation captured by `call/cc`. See [`Proc::Continuation`].
+///
+/// Invoking a continuation unwinds the Rust call stack back to the `call/cc` that captured
+/// it, which then returns the value passed to the continuation. Therefore, a continuation
+/// can only be invoked while its `call/cc` has not returned yet.
+///
+/// [`Proc::Continuation`]: crate::proc::Proc::Continuation
+#[derive(Clone, Debug)]
+pub struct Continuation {
+    is_active: Rc<Cell<bool>>,
+}
+
+impl Continuation {
+    pub(crate) fn new() -> Self {
+        Self {
+            is_active: Rc::new(Cell::new(true)),
+        }
+    }
+
+    /// Returns `true` if the `call/cc` that captured the continuation has not returned yet.
+    pub fn is_active(&self) -> bool {
+        self.is_active.get()
+    }
+
+    pub(crate) fn deactivate(&self) {
+        self.is_active.set(false);
+    }
+
+    pub(crate) fn invoke(&self, proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+        let mut values = args
+            .iter()
+            .map(|arg| eval(arg, context))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Several arguments are passed to the `call/cc` as multiple values.
+        let value = match values.len() {
+            0 => NIL,
+            1 => values.remove(0),
+            _ => Expr::Values(values),
+        };
+
+        if !self.is_active() {
+            return Err(EvalError::from(format!(
+                "{proc_name}: the continuation cannot be invoked after its `call/cc` has returned."
+            )));
+        }
+
+        *context.shared.escape.borrow_mut() = Some((self.clone(), value));
+        Err(EvalError::from(format!(
+            "{proc_name}: the continuation escaped."
+        )))
+    }
+}
+
+impl PartialEq for Continuation {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.is_active, &other.is_active)
+    }
+}
+
+impl Hash for Continuation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.is_active).hash(state);
+    }
+}
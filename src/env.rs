@@ -1,9 +1,11 @@
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 use crate::expr::Expr;
-use crate::proc::{NativeFunc, Proc};
+use crate::proc::{Arity, NativeFunc, Proc};
+use crate::promise::PromiseState;
+use crate::symbol::Symbol;
 
 /// `Env` object stores variable bindings and manages scope for expression evaluation.
 ///
@@ -13,7 +15,7 @@ use crate::proc::{NativeFunc, Proc};
 #[derive(Debug)]
 pub struct Env {
     base: Option<Rc<Env>>,
-    vars: RefCell<HashMap<String, Expr>>,
+    vars: RefCell<HashMap<Symbol, Expr>>,
     all_envs: Weak<RefCell<Vec<Weak<Env>>>>,
     is_reachable: Cell<bool>,
 }
@@ -50,14 +52,16 @@ impl Env {
     ///
     /// # Type Parameters
     ///
+    /// * `IntoSymbol` - A type that can be converted into a `Symbol`, e.g. `&str`.
     /// * `IntoExpr` - A type that can be converted into an `Expr`.
     ///
     /// # Arguments
     ///
     /// * `name` - The name of the variable to define.
     /// * `expr` - The expression to bind to the variable. This can be any type that implements the `Into<Expr>` trait.
-    pub fn define<IntoExpr>(&self, name: &str, expr: IntoExpr)
+    pub fn define<IntoSymbol, IntoExpr>(&self, name: IntoSymbol, expr: IntoExpr)
     where
+        IntoSymbol: Into<Symbol>,
         IntoExpr: Into<Expr>,
     {
         self.vars.borrow_mut().insert(name.into(), expr.into());
@@ -78,13 +82,15 @@ impl Env {
     /// # Returns
     ///
     /// Returns `true` if the variable was successfully updated, `false` otherwise.
-    pub fn update<IntoExpr>(&self, name: &str, expr: IntoExpr) -> bool
+    pub fn update<IntoSymbol, IntoExpr>(&self, name: IntoSymbol, expr: IntoExpr) -> bool
     where
+        IntoSymbol: Into<Symbol>,
         IntoExpr: Into<Expr>,
     {
+        let name = name.into();
         let mut env = self;
         loop {
-            if let Some(value) = env.vars.borrow_mut().get_mut(name) {
+            if let Some(value) = env.vars.borrow_mut().get_mut(&name) {
                 *value = expr.into();
                 return true;
             }
@@ -107,10 +113,14 @@ impl Env {
     /// # Returns
     ///
     /// Returns an `Option` containing the expression bound to the variable if found, or `None` if not found.
-    pub fn lookup(&self, name: &str) -> Option<Expr> {
+    pub fn lookup<IntoSymbol>(&self, name: IntoSymbol) -> Option<Expr>
+    where
+        IntoSymbol: Into<Symbol>,
+    {
+        let name = name.into();
         let mut env = self;
         loop {
-            if let Some(value) = env.vars.borrow().get(name) {
+            if let Some(value) = env.vars.borrow().get(&name) {
                 return Some(value.clone());
             }
             let Some(base) = &env.base else {
@@ -120,6 +130,42 @@ impl Env {
         }
     }
 
+    /// Looks up the binding for an identifier, which may have been renamed by `syntax-rules`.
+    ///
+    /// Unlike [`Env::lookup`], a renamed identifier that is not bound here, i.e. not bound
+    /// by the expansion, refers to the original identifier in the environment where
+    /// the macro was defined.
+    pub fn lookup_identifier(&self, name: &Symbol) -> Option<Expr> {
+        self.lookup(name).or_else(|| {
+            let (original, env) = name.renamed_from()?;
+            env.upgrade()?.lookup_identifier(original)
+        })
+    }
+
+    /// Same as [`Env::update`], but for an identifier which may have been renamed by
+    /// `syntax-rules`, as in [`Env::lookup_identifier`].
+    pub fn update_identifier(&self, name: &Symbol, expr: Expr) -> bool {
+        match name.renamed_from() {
+            Some((original, env)) if !self.is_bound(name) => env
+                .upgrade()
+                .is_some_and(|env| env.update_identifier(original, expr)),
+            _ => self.update(name, expr),
+        }
+    }
+
+    fn is_bound(&self, name: &Symbol) -> bool {
+        let mut env = self;
+        loop {
+            if env.vars.borrow().contains_key(name) {
+                return true;
+            }
+            let Some(base) = &env.base else {
+                return false;
+            };
+            env = base;
+        }
+    }
+
     /// A convience fucntion to define a native procedure in the current environment.
     /// This is a shorthand for `define(name, Expr::Proc(Proc::Native { ... }))`.
     pub fn define_native_proc(&self, name: &str, func: NativeFunc) {
@@ -129,6 +175,23 @@ impl Env {
                 Proc::Native {
                     name: name.to_owned(),
                     func,
+                    arity: None,
+                },
+                None,
+            ),
+        );
+    }
+
+    /// Same as [`Env::define_native_proc`], but also declares the number of arguments
+    /// the procedure accepts, which is reported by [`Proc::arity`].
+    pub fn define_native_proc_with_arity(&self, name: &str, func: NativeFunc, arity: Arity) {
+        self.define(
+            name,
+            Expr::Proc(
+                Proc::Native {
+                    name: name.to_owned(),
+                    func,
+                    arity: Some(arity),
                 },
                 None,
             ),
@@ -143,17 +206,24 @@ impl Env {
     }
 
     pub(crate) fn gc_mark(&self) {
+        self.gc_mark_env(&mut HashSet::new());
+    }
+
+    fn gc_mark_env(&self, visited: &mut HashSet<*const ()>) {
         if self.is_reachable.get() {
             return;
         }
 
         self.is_reachable.set(true);
 
-        self.vars.borrow().values().for_each(|expr| {
-            if let Expr::Proc(Proc::Closure { outer_context, .. }, _) = expr {
-                outer_context.env.gc_mark();
-            }
-        });
+        if let Some(base) = &self.base {
+            base.gc_mark_env(visited);
+        }
+
+        self.vars
+            .borrow()
+            .values()
+            .for_each(|expr| gc_mark_expr(expr, visited));
     }
 
     pub(crate) fn gc_sweep(&self) {
@@ -165,6 +235,68 @@ impl Env {
     }
 }
 
+/// Marks the environments reachable from `expr`, including the ones captured by closures
+/// stored in lists, multiple values, vectors, hash tables, records and promises. `visited` keeps
+/// track of the containers already traversed, as mutable containers can be cyclic.
+fn gc_mark_expr(expr: &Expr, visited: &mut HashSet<*const ()>) {
+    match expr {
+        Expr::Proc(
+            Proc::Closure { outer_context, .. } | Proc::CaseLambda { outer_context, .. },
+            _,
+        ) => {
+            outer_context.env.gc_mark_env(visited);
+        }
+        Expr::Proc(Proc::SyntaxRules { rules, .. }, _) => {
+            rules.env().gc_mark_env(visited);
+        }
+        Expr::Proc(Proc::Parameter(parameter), _) => {
+            gc_mark_expr(parameter.initial_value(), visited);
+            if let Some(converter) = parameter.converter() {
+                gc_mark_expr(&Expr::Proc(converter.clone(), None), visited);
+            }
+        }
+        Expr::List(list, _) => {
+            let mut iter = list.iter();
+            iter.by_ref().for_each(|expr| gc_mark_expr(expr, visited));
+            if let Some(tail) = iter.tail() {
+                gc_mark_expr(tail, visited);
+            }
+        }
+        Expr::Values(values) => {
+            values.iter().for_each(|expr| gc_mark_expr(expr, visited));
+        }
+        Expr::Vector(vector, _) if visited.insert(Rc::as_ptr(vector) as *const ()) => {
+            vector
+                .borrow()
+                .iter()
+                .for_each(|expr| gc_mark_expr(expr, visited));
+        }
+        Expr::HashTable(table, _) if visited.insert(Rc::as_ptr(table) as *const ()) => {
+            table
+                .borrow()
+                .values()
+                .for_each(|expr| gc_mark_expr(expr, visited));
+        }
+        Expr::Record(record, _) if visited.insert(Rc::as_ptr(record) as *const ()) => {
+            record
+                .values
+                .borrow()
+                .iter()
+                .for_each(|expr| gc_mark_expr(expr, visited));
+        }
+        Expr::Promise(promise, _) if visited.insert(Rc::as_ptr(promise) as *const ()) => {
+            match promise.state() {
+                PromiseState::Done(value) => gc_mark_expr(&value, visited),
+                PromiseState::Delayed { expr, context, .. } => {
+                    gc_mark_expr(&expr, visited);
+                    context.env.gc_mark_env(visited);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,16 +307,16 @@ mod tests {
         let env = Env::root(Weak::new());
         assert_eq!(env.vars.borrow().len(), 0);
         env.define("one", 1);
-        assert_eq!(env.vars.borrow().get("one"), Some(&num(1)));
+        assert_eq!(env.vars.borrow().get(&Symbol::new("one")), Some(&num(1)));
     }
 
     #[test]
     fn test_update() {
         let env = Env::root(Weak::new());
-        assert_eq!(env.update("name", 1), false);
+        assert!(!env.update("name", 1));
 
         env.define("name", 0);
-        assert_eq!(env.update("name", 1), true);
+        assert!(env.update("name", 1));
     }
 
     #[test]
@@ -203,12 +335,18 @@ mod tests {
         base.define("one", 1);
         derived.define("two", 2);
 
-        assert_eq!(derived.update("one", "uno"), true);
-        assert_eq!(derived.update("two", "dos"), true);
+        assert!(derived.update("one", "uno"));
+        assert!(derived.update("two", "dos"));
 
-        assert_eq!(base.vars.borrow().get("one"), Some(&"uno".into()));
-        assert_eq!(derived.vars.borrow().get("one"), None);
-        assert_eq!(derived.vars.borrow().get("two"), Some(&"dos".into()));
+        assert_eq!(
+            base.vars.borrow().get(&Symbol::new("one")),
+            Some(&"uno".into())
+        );
+        assert_eq!(derived.vars.borrow().get(&Symbol::new("one")), None);
+        assert_eq!(
+            derived.vars.borrow().get(&Symbol::new("two")),
+            Some(&"dos".into())
+        );
     }
 
     #[test]
@@ -225,6 +363,34 @@ mod tests {
         assert_eq!(derived.lookup("three"), Some(num(3)));
     }
 
+    #[test]
+    fn test_lookup_identifier() {
+        let macro_env = Env::root(Weak::new());
+        macro_env.define("list", 1);
+        let use_env = Env::root(Weak::new());
+        use_env.define("list", 2);
+
+        let list = Symbol::new("list");
+        let renamed = Symbol::renamed(&list, &macro_env);
+        assert_eq!(use_env.lookup(&renamed), None);
+        assert_eq!(use_env.lookup_identifier(&renamed), Some(num(1)));
+        assert!(use_env.update_identifier(&renamed, num(3)));
+        assert_eq!(macro_env.lookup(&list), Some(num(3)));
+        assert_eq!(use_env.lookup(&list), Some(num(2)));
+
+        // bound by the expansion
+        use_env.define(&renamed, num(4));
+        assert_eq!(use_env.lookup_identifier(&renamed), Some(num(4)));
+        assert!(use_env.update_identifier(&renamed, num(5)));
+        assert_eq!(use_env.lookup(&renamed), Some(num(5)));
+        assert_eq!(macro_env.lookup(&list), Some(num(3)));
+
+        // uninterned symbols do not refer to interned ones
+        let uninterned = Symbol::uninterned("list");
+        assert_eq!(use_env.lookup_identifier(&uninterned), None);
+        assert!(!use_env.update_identifier(&uninterned, num(6)));
+    }
+
     #[test]
     fn test_clone() {
         let original = Env::root(Weak::new());
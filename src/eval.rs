@@ -5,13 +5,8 @@ use std::{
 };
 
 use crate::{
-    builtin::load_builtin,
-    env::Env,
-    expr::Expr,
-    list::{Cons, List},
-    prelude::load_prelude,
-    proc::Proc,
-    span::Span,
+    builtin::load_builtin, continuation::Continuation, env::Env, expr::Expr, list::List,
+    parameter::Parameter, prelude::load_prelude, proc::Proc, span::Span,
 };
 
 /// The object that represents an expression evaluation error.
@@ -19,6 +14,21 @@ use crate::{
 pub struct EvalError {
     pub message: String,
     pub span: Option<Span>,
+
+    /// The object raised by `raise`, `raise-continuable` or `error`, if any.
+    /// Errors from native procedures have no payload.
+    pub payload: Option<Box<Expr>>,
+}
+
+impl EvalError {
+    /// Creates an error with no payload.
+    pub fn new(message: String, span: Option<Span>) -> Self {
+        Self {
+            message,
+            span,
+            payload: None,
+        }
+    }
 }
 
 impl fmt::Display for EvalError {
@@ -33,23 +43,45 @@ impl fmt::Display for EvalError {
 
 impl From<String> for EvalError {
     fn from(message: String) -> Self {
-        Self {
-            message,
-            span: None,
-        }
+        Self::new(message, None)
     }
 }
 
 pub type EvalResult = Result<Expr, EvalError>;
 
+/// An entry of the exception handler stack of [`EvalContext`].
+#[derive(Clone, Debug)]
+pub(crate) enum ExceptionHandler {
+    /// A handler installed by `with-exception-handler`.
+    Proc(Proc),
+
+    /// A `guard` form, which handles exceptions once they have unwound to it.
+    Guard,
+}
+
 /// The evaluation context contains the environment and other necessary state for expression evaluation.
 #[derive(Clone, Debug)]
 pub struct EvalContext {
     pub env: Rc<Env>,
-    call_depth: Rc<Cell<usize>>,
+    pub(crate) shared: Rc<SharedState>,
+}
+
+/// The state shared by all contexts derived from the same [`Evaluator`]. It is kept behind
+/// a single `Rc` so that contexts, and procedures capturing them, stay small.
+#[derive(Debug, Default)]
+pub(crate) struct SharedState {
+    call_depth: Cell<usize>,
+    pub(crate) exception_handlers: RefCell<Vec<ExceptionHandler>>,
+
+    /// The continuation being invoked and the value passed to it, while the evaluation
+    /// unwinds to its `call/cc`.
+    pub(crate) escape: RefCell<Option<(Continuation, Expr)>>,
+
+    /// The values bound to parameter objects by active `parameterize` forms, innermost last.
+    pub(crate) parameter_bindings: RefCell<Vec<(Parameter, Expr)>>,
 
     #[cfg(feature = "callstack_trace")]
-    call_stack: Rc<RefCell<Vec<String>>>,
+    call_stack: RefCell<Vec<String>>,
 }
 
 impl EvalContext {
@@ -58,41 +90,79 @@ impl EvalContext {
     pub fn derive_from(base: &EvalContext) -> Self {
         Self {
             env: Env::derive_from(&base.env),
-            call_depth: base.call_depth.clone(),
-            #[cfg(feature = "callstack_trace")]
-            call_stack: base.call_stack.clone(),
+            shared: base.shared.clone(),
         }
     }
 
+    /// Binds each parameter object to its value while `f` is running, as the `parameterize`
+    /// form does. The values are passed to the converters of the parameter objects first.
+    /// The bindings are removed when `f` returns, whether it succeeds or not.
+    pub fn parameterize<F>(&self, bindings: Vec<(Parameter, Expr)>, f: F) -> EvalResult
+    where
+        F: FnOnce(&EvalContext) -> EvalResult,
+    {
+        let bindings = bindings
+            .into_iter()
+            .map(|(parameter, value)| {
+                let value = parameter.convert(value, self)?;
+                Ok((parameter, value))
+            })
+            .collect::<Result<Vec<_>, EvalError>>()?;
+
+        let depth = self.shared.parameter_bindings.borrow().len();
+        self.shared.parameter_bindings.borrow_mut().extend(bindings);
+        let result = f(self);
+        self.shared.parameter_bindings.borrow_mut().truncate(depth);
+        result
+    }
+
     pub(crate) fn push_call(&self, proc: &Proc) {
         #[cfg(not(feature = "callstack_trace"))]
         let _ = proc;
 
-        let depth = self.call_depth.get();
-        self.call_depth.set(depth + 1);
+        let depth = self.shared.call_depth.get();
+        self.shared.call_depth.set(depth + 1);
 
         #[cfg(feature = "callstack_trace")]
         {
-            self.call_stack.borrow_mut().push(proc.badge());
+            self.shared.call_stack.borrow_mut().push(proc.badge());
             println!("{:03}{} -> {}", depth, " ".repeat(depth), proc.badge());
         }
     }
 
     pub(crate) fn pop_call(&self) {
-        self.call_depth.set(self.call_depth.get() - 1);
+        self.shared.call_depth.set(self.shared.call_depth.get() - 1);
 
         #[cfg(feature = "callstack_trace")]
         {
-            let badge = self.call_stack.borrow_mut().pop();
+            let badge = self.shared.call_stack.borrow_mut().pop();
             if let Some(badge) = badge {
-                let depth = self.call_depth.get();
+                let depth = self.shared.call_depth.get();
                 println!("{:03}{} <- {}", depth, " ".repeat(depth), badge);
             }
         }
     }
 
     pub(crate) fn is_in_proc(&self) -> bool {
-        self.call_depth.get() > 0
+        self.shared.call_depth.get() > 0
+    }
+
+    /// Returns `true` if a continuation has been invoked and the evaluation is unwinding.
+    /// Errors returned while unwinding must be passed through as is.
+    pub(crate) fn is_escaping(&self) -> bool {
+        self.shared.escape.borrow().is_some()
+    }
+
+    /// Takes the value passed to `continuation`, if it is the one being invoked.
+    pub(crate) fn take_escape(&self, continuation: &Continuation) -> Option<Expr> {
+        let mut escape = self.shared.escape.borrow_mut();
+        match escape.take() {
+            Some((target, value)) if target == *continuation => Some(value),
+            other => {
+                *escape = other;
+                None
+            }
+        }
     }
 }
 
@@ -109,8 +179,29 @@ impl EvalContext {
 /// # Returns
 ///
 /// Returns an `EvalResult`, which is typically a `Result` containing either the evaluated expression or an error.
+/// It is an error if the expression returns multiple values. Use [`eval_values`] to accept them.
 pub fn eval(expr: &Expr, context: &EvalContext) -> EvalResult {
-    eval_internal(expr, context, /*is_tail*/ false)
+    let result = eval_internal(expr, context, /*is_tail*/ false);
+    if let Ok(Expr::Values(values)) = &result {
+        return Err(multiple_values_error(expr, values.len()));
+    }
+    result
+}
+
+// Kept out of `eval`, which is on the path of every nested call, to keep its stack frame small.
+#[cold]
+#[inline(never)]
+fn multiple_values_error(expr: &Expr, count: usize) -> EvalError {
+    EvalError::new(
+        format!("`{expr}` returns {count} values where a single value is expected."),
+        expr.span(),
+    )
+}
+
+/// Evaluates an expression in the given context, and returns its values.
+/// An expression returns a single value unless it calls `values`. See [`Expr::into_values`].
+pub fn eval_values(expr: &Expr, context: &EvalContext) -> Result<Vec<Expr>, EvalError> {
+    eval_internal(expr, context, /*is_tail*/ false).map(Expr::into_values)
 }
 
 /// Evaluates an expression in the given context, denoting that the evaluation is in a tail position.
@@ -130,73 +221,86 @@ pub fn eval_tail(expr: &Expr, context: &EvalContext) -> EvalResult {
     eval_internal(expr, context, /*is_tail*/ true)
 }
 
+/// Expands `form` once if it is a macro use, i.e. a list whose first element is a symbol
+/// bound to a macro. Returns `None` if `form` is not a macro use.
+pub(crate) fn macroexpand_1(form: &Expr, context: &EvalContext) -> Result<Option<Expr>, EvalError> {
+    let Expr::List(List::Cons(cons), _) = form else {
+        return Ok(None);
+    };
+    let (Expr::Sym(name, _), Some(args)) = (cons.car.as_ref(), cons.cdr_list()) else {
+        return Ok(None);
+    };
+    match context.env.lookup_identifier(name) {
+        Some(Expr::Proc(proc, _)) => proc.expand(args, context),
+        _ => Ok(None),
+    }
+}
+
+/// Expands `form` repeatedly until it is no longer a macro use. Subforms are not expanded.
+pub(crate) fn macroexpand(form: &Expr, context: &EvalContext) -> EvalResult {
+    let mut form = form.clone();
+    while let Some(expanded) = macroexpand_1(&form, context)? {
+        form = expanded;
+    }
+    Ok(form)
+}
+
 fn eval_internal(expr: &Expr, context: &EvalContext, is_tail: bool) -> EvalResult {
     match expr {
-        Expr::Sym(name, span) => match context.env.lookup(name) {
+        Expr::Sym(name, _) if name.is_keyword() => Ok(expr.clone()),
+        Expr::Sym(name, span) => match context.env.lookup_identifier(name) {
             Some(expr) => Ok(expr.clone()),
-            None => Err(EvalError {
-                message: format!("Undefined symbol: `{}`", name),
-                span: *span,
-            }),
+            None => Err(EvalError::new(
+                format!("Undefined symbol: `{}`", name),
+                *span,
+            )),
         },
         Expr::List(List::Cons(cons), _) => {
             use crate::builtin::quote::{quasiquote, quote, QUASIQUOTE, QUOTE};
 
-            let result = match cons.car.as_ref() {
-                Expr::Sym(text, _) if text == QUOTE => quote(text, &cons.cdr, context),
-                Expr::Sym(text, _) if text == QUASIQUOTE => quasiquote(text, &cons.cdr, context),
-                _ => eval_s_expr(cons, context, is_tail),
+            let Some(args) = cons.cdr_list().filter(|args| args.is_proper()) else {
+                return Err(EvalError::new(
+                    format!("`{expr}` is not a proper list to evaluate."),
+                    expr.span(),
+                ));
             };
 
-            match result {
-                Err(EvalError {
-                    message,
-                    span: None,
-                }) => {
+            let mut result = match cons.car.as_ref() {
+                Expr::Sym(text, _) if text == QUOTE => quote(text, args, context),
+                Expr::Sym(text, _) if text == QUASIQUOTE => quasiquote(text, args, context),
+                _ => eval_s_expr(&cons.car, args, context, is_tail),
+            };
+
+            if let Err(error) = &mut result {
+                if error.span.is_none() {
                     // If the result is an error without a span, let's try to provide a span.
                     // First, let's check if we can get a span from arguments list. If not, we'll
                     // use the span of the expression itself.
-                    let span = if let Some(span) = cons.cdr.as_ref().span() {
-                        Some(span)
-                    } else {
-                        expr.span()
-                    };
-                    Err(EvalError { message, span })
+                    error.span = args.span().or(expr.span());
                 }
-                _ => result,
             }
+            result
         }
         _ => Ok(expr.clone()),
     }
 }
 
-fn eval_s_expr(s_expr: &Cons, context: &EvalContext, is_tail: bool) -> EvalResult {
-    if let Expr::Proc(proc, _) = eval(&s_expr.car, context)? {
-        let args = &s_expr.cdr;
-
+fn eval_s_expr(car: &Expr, args: &List, context: &EvalContext, is_tail: bool) -> EvalResult {
+    if let Expr::Proc(proc, _) = eval(car, context)? {
         if is_tail && context.is_in_proc() {
             Ok(Expr::TailCall {
                 proc: proc.clone(),
-                args: args.as_ref().clone(),
+                args: args.clone(),
                 context: context.clone(),
             })
         } else {
-            let mut res = proc.invoke(args, context)?;
-            while let Expr::TailCall {
-                proc,
-                args,
-                context,
-            } = &res
-            {
-                res = proc.invoke(args, context)?;
-            }
-            Ok(res)
+            proc.call(args, context)
         }
     } else {
-        Err(EvalError {
-            message: format!("`{}` does not evaluate to a callable.", s_expr.car),
-            span: s_expr.car.span(),
-        })
+        Err(EvalError::new(
+            format!("`{}` does not evaluate to a callable.", car),
+            car.span(),
+        ))
     }
 }
 
@@ -227,9 +331,7 @@ impl Evaluator {
             all_envs,
             context: EvalContext {
                 env: root_env,
-                call_depth: Rc::new(Cell::new(0)),
-                #[cfg(feature = "callstack_trace")]
-                call_stack: Rc::new(RefCell::new(Vec::new())),
+                shared: Rc::default(),
             },
         }
     }
@@ -264,6 +366,18 @@ impl Evaluator {
         eval(expr, self.context())
     }
 
+    /// Evaluates an expression in the current context, and returns its values.
+    /// This function is a convenience wrapper around the `eval_values()` function.
+    pub fn eval_values(&self, expr: &Expr) -> Result<Vec<Expr>, EvalError> {
+        eval_values(expr, self.context())
+    }
+
+    /// Expands a macro use in the current context without evaluating the expansion.
+    /// See the `macroexpand` form.
+    pub fn macroexpand(&self, expr: &Expr) -> EvalResult {
+        macroexpand(expr, self.context())
+    }
+
     /// Count the number of unreachable environments in the evaluator.
     /// This function is useful for monitoring memory usage and can be used
     /// to determin when to trigger garbage collection.
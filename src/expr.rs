@@ -1,38 +1,127 @@
-use std::{any::Any, fmt, rc::Rc};
+use std::{any::Any, cell::RefCell, fmt, rc::Rc, thread::LocalKey};
 
 use crate::{
     eval::EvalContext,
+    hash_table::HashTable,
     list::{cons, List, ListIter},
+    number::Number,
     proc::Proc,
+    promise::Promise,
+    record::Record,
     span::Span,
+    symbol::Symbol,
 };
 
 pub type Foreign = Rc<dyn Any>;
 
+pub type Vector = Rc<RefCell<Vec<Expr>>>;
+
+pub type Bytevector = Rc<RefCell<Vec<u8>>>;
+
+thread_local! {
+    /// The containers being displayed, outermost first.
+    static DISPLAYING: RefCell<Vec<*const ()>> = const { RefCell::new(Vec::new()) };
+
+    /// The pairs of containers being compared, outermost first.
+    static COMPARING: RefCell<Vec<(*const (), *const ())>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with `key` pushed onto `stack`, unless `key` is already on it, which means
+/// that a container has been reached again from within itself.
+fn unless_cyclic<K: PartialEq + 'static, R>(
+    stack: &'static LocalKey<RefCell<Vec<K>>>,
+    key: K,
+    f: impl FnOnce() -> R,
+) -> Option<R> {
+    struct Pop<K: 'static>(&'static LocalKey<RefCell<Vec<K>>>);
+
+    impl<K> Drop for Pop<K> {
+        fn drop(&mut self) {
+            self.0.with_borrow_mut(|stack| stack.pop());
+        }
+    }
+
+    if stack.with_borrow(|stack| stack.contains(&key)) {
+        return None;
+    }
+    stack.with_borrow_mut(|stack| stack.push(key));
+    let _pop = Pop(stack);
+    Some(f())
+}
+
+/// Displays a container with `display`, or `#<cycle>` if the container is being
+/// displayed already, so that cyclic structures can be displayed.
+pub(crate) fn display_container<T>(
+    f: &mut fmt::Formatter<'_>,
+    container: *const T,
+    display: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    unless_cyclic(&DISPLAYING, container as *const (), || display(f))
+        .unwrap_or_else(|| write!(f, "#<cycle>"))
+}
+
+/// Compares two containers with `compare`. Containers that are being compared already
+/// are considered equal, so that cyclic structures can be compared.
+fn compare_containers<T>(lhs: &Rc<T>, rhs: &Rc<T>, compare: impl FnOnce() -> bool) -> bool {
+    let key = (Rc::as_ptr(lhs) as *const (), Rc::as_ptr(rhs) as *const ());
+    Rc::ptr_eq(lhs, rhs) || unless_cyclic(&COMPARING, key, compare).unwrap_or(true)
+}
+
 /// The enum that represents all expression variants in the Rusche language.
 #[derive(Clone, Debug)]
 pub enum Expr {
-    /// A 64-bit floating number value.
-    Num(f64, Option<Span>),
+    /// A boolean value, written as `#t` or `#f`.
+    Bool(bool, Option<Span>),
+
+    /// A numeric value, which is either exact or inexact. See [`Number`].
+    Num(Number, Option<Span>),
+
+    /// A character value, written as `#\a`, `#\space` or `#\x41`.
+    Char(char, Option<Span>),
 
     /// A string value.
     Str(String, Option<Span>),
 
-    /// A symbol value.
-    Sym(String, Option<Span>),
+    /// A symbol value. See [`Symbol`].
+    Sym(Symbol, Option<Span>),
 
-    /// A procedure value. There are 3 types of procedures in Rusche:
+    /// A procedure value. There are 8 types of procedures in Rusche:
     /// - [`Proc::Native`]: implemented in Rust
     /// - [`Proc::Closure`]: user-defined via `lambda` form
+    /// - [`Proc::CaseLambda`]: user-defined via `case-lambda` form
     /// - [`Proc::Macro`]: user-defined via `defmacro` form
+    /// - [`Proc::Record`]: generated by `define-record-type` form
+    /// - [`Proc::Continuation`]: captured by `call/cc` form
+    /// - [`Proc::Parameter`]: created by `make-parameter` form
+    /// - [`Proc::SyntaxRules`]: user-defined via `syntax-rules` form
     Proc(Proc, Option<Span>),
 
     /// A list value. It can be either a cons cell or an empty list.
     List(List, Option<Span>),
 
+    /// A mutable, fixed-length vector value, written as `#(1 2 3)`.
+    Vector(Vector, Option<Span>),
+
+    /// A mutable, fixed-length vector of bytes, written as `#u8(1 2 3)`.
+    Bytevector(Bytevector, Option<Span>),
+
+    /// A mutable hash table value, written as `#hash((key . value) ...)`. See [`HashTable`].
+    HashTable(Rc<RefCell<HashTable>>, Option<Span>),
+
+    /// A record value created by a constructor of a `define-record-type` form.
+    Record(Rc<Record>, Option<Span>),
+
+    /// A promise created by `delay`, `delay-force` or `make-promise`. See [`Promise`].
+    Promise(Rc<Promise>, Option<Span>),
+
     /// A foreign object value. This is used to store Rust objects in the interpreter.
     Foreign(Foreign),
 
+    /// Zero or several values returned at once by `values`. A single value is never
+    /// wrapped. Native procedures can return this to produce multiple results, which
+    /// [`eval`](crate::eval::eval) rejects where a single value is expected.
+    Values(Vec<Expr>),
+
     /// A special case for tail-call optimization. _Internal use only._
     TailCall {
         proc: Proc,
@@ -43,6 +132,35 @@ pub enum Expr {
 
 pub const NIL: Expr = Expr::List(List::Nil, None);
 
+/// The characters that are written by name, e.g. `#\space`.
+pub(crate) const CHAR_NAMES: [(&str, char); 9] = [
+    ("alarm", '\x07'),
+    ("backspace", '\x08'),
+    ("delete", '\x7f'),
+    ("escape", '\x1b'),
+    ("newline", '\n'),
+    ("null", '\0'),
+    ("return", '\r'),
+    ("space", ' '),
+    ("tab", '\t'),
+];
+
+/// Formats a character in its literal form, e.g. `#\a`, `#\space` or `#\x3bb`.
+pub(crate) struct CharLiteral(pub char);
+
+impl fmt::Display for CharLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = self.0;
+        if let Some((name, _)) = CHAR_NAMES.iter().find(|(_, named)| *named == ch) {
+            write!(f, "#\\{}", name)
+        } else if ch.is_control() {
+            write!(f, "#\\x{:x}", ch as u32)
+        } else {
+            write!(f, "#\\{}", ch)
+        }
+    }
+}
+
 impl Expr {
     /// Returns `true` if the expression is an atom.
     /// Every expresssion that is not a cons cell list is an atom.
@@ -56,19 +174,102 @@ impl Expr {
     }
 
     /// Returns `true` if the expression can be considered to be truthy.
-    /// In Rusche, only the empty list ([`List::Nil`]) is considered to be falsy.
+    /// In Rusche, only `#f` is considered to be falsy.
+    ///
+    /// With the `legacy_falsy_nil` feature enabled, the empty list ([`List::Nil`])
+    /// is also considered to be falsy, as it used to be before `#f` was introduced.
     pub fn is_truthy(&self) -> bool {
-        !self.is_nil()
+        if matches!(self, Expr::Bool(false, _)) {
+            return false;
+        }
+
+        #[cfg(feature = "legacy_falsy_nil")]
+        if self.is_nil() {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns `true` if the expressions are equivalent in the sense of `eqv?`.
+    ///
+    /// Lists, vectors, bytevectors, hash tables, records, promises and foreign objects are
+    /// compared by identity, and so are procedures, see [`Proc::is_same`]. Numbers are
+    /// equivalent if they have the same exactness and value, and inexact numbers only if
+    /// they have the same representation, e.g. `0.0` is not equivalent to `-0.0`.
+    /// Strings have no identity in Rusche, so they are compared by content.
+    pub fn is_eqv(&self, other: &Expr) -> bool {
+        match (self, other) {
+            (Expr::Num(Number::Real(lhs), _), Expr::Num(Number::Real(rhs), _)) => {
+                lhs.to_bits() == rhs.to_bits()
+            }
+            (Expr::Num(lhs, _), Expr::Num(rhs, _)) => {
+                lhs.is_exact() == rhs.is_exact() && lhs == rhs
+            }
+            (Expr::Proc(lhs, _), Expr::Proc(rhs, _)) => lhs.is_same(rhs),
+            (Expr::List(List::Cons(lhs), _), Expr::List(List::Cons(rhs), _)) => {
+                Rc::ptr_eq(lhs, rhs)
+            }
+            (Expr::Vector(lhs, _), Expr::Vector(rhs, _)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::Bytevector(lhs, _), Expr::Bytevector(rhs, _)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::HashTable(lhs, _), Expr::HashTable(rhs, _)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::Values(_), Expr::Values(_)) => false,
+            _ => self == other,
+        }
+    }
+
+    /// Returns `true` if the expressions are equal in the sense of `equal?`, i.e. lists,
+    /// vectors and bytevectors are compared recursively by content, and everything else
+    /// by [`Expr::is_eqv`].
+    pub fn is_equal(&self, other: &Expr) -> bool {
+        match (self, other) {
+            (Expr::List(lhs, _), Expr::List(rhs, _)) => {
+                let (mut lhs_iter, mut rhs_iter) = (lhs.iter(), rhs.iter());
+                loop {
+                    match (lhs_iter.next(), rhs_iter.next()) {
+                        (Some(lhs), Some(rhs)) if lhs.is_equal(rhs) => {}
+                        (None, None) => break,
+                        _ => return false,
+                    }
+                }
+                match (lhs_iter.tail(), rhs_iter.tail()) {
+                    (Some(lhs), Some(rhs)) => lhs.is_equal(rhs),
+                    (lhs, rhs) => lhs.is_none() && rhs.is_none(),
+                }
+            }
+            (Expr::Vector(lhs, _), Expr::Vector(rhs, _)) => compare_containers(lhs, rhs, || {
+                let (lhs, rhs) = (lhs.borrow(), rhs.borrow());
+                lhs.len() == rhs.len() && lhs.iter().zip(rhs.iter()).all(|(l, r)| l.is_equal(r))
+            }),
+            (Expr::Bytevector(lhs, _), Expr::Bytevector(rhs, _)) => *lhs.borrow() == *rhs.borrow(),
+            _ => self.is_eqv(other),
+        }
+    }
+
+    /// Returns the values of the expression, which is the expression itself unless it is
+    /// an [`Expr::Values`].
+    pub fn into_values(self) -> Vec<Expr> {
+        match self {
+            Expr::Values(values) => values,
+            expr => vec![expr],
+        }
     }
 
     pub fn span(&self) -> Option<Span> {
         match self {
-            Expr::Num(_, span)
+            Expr::Bool(_, span)
+            | Expr::Num(_, span)
+            | Expr::Char(_, span)
             | Expr::Str(_, span)
             | Expr::Sym(_, span)
             | Expr::Proc(_, span)
-            | Expr::List(_, span) => *span,
-            Expr::Foreign(_) => None,
+            | Expr::List(_, span)
+            | Expr::Vector(_, span)
+            | Expr::Bytevector(_, span)
+            | Expr::HashTable(_, span)
+            | Expr::Record(_, span)
+            | Expr::Promise(_, span) => *span,
+            Expr::Foreign(_) | Expr::Values(_) => None,
             Expr::TailCall { .. } => None,
         }
     }
@@ -77,11 +278,26 @@ impl Expr {
 impl PartialEq for Expr {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
+            (Expr::Bool(lhs, _), Expr::Bool(rhs, _)) => lhs == rhs,
             (Expr::Num(lhs, _), Expr::Num(rhs, _)) => lhs == rhs,
+            (Expr::Char(lhs, _), Expr::Char(rhs, _)) => lhs == rhs,
             (Expr::Str(lhs, _), Expr::Str(rhs, _)) => lhs == rhs,
             (Expr::Sym(lhs, _), Expr::Sym(rhs, _)) => lhs == rhs,
             (Expr::Proc(lhs, _), Expr::Proc(rhs, _)) => lhs == rhs,
             (Expr::List(lhs, _), Expr::List(rhs, _)) => lhs == rhs,
+            (Expr::Vector(lhs, _), Expr::Vector(rhs, _)) => {
+                compare_containers(lhs, rhs, || *lhs.borrow() == *rhs.borrow())
+            }
+            (Expr::Bytevector(lhs, _), Expr::Bytevector(rhs, _)) => {
+                Rc::ptr_eq(lhs, rhs) || *lhs.borrow() == *rhs.borrow()
+            }
+            (Expr::HashTable(lhs, _), Expr::HashTable(rhs, _)) => {
+                compare_containers(lhs, rhs, || *lhs.borrow() == *rhs.borrow())
+            }
+            (Expr::Record(lhs, _), Expr::Record(rhs, _)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::Promise(lhs, _), Expr::Promise(rhs, _)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::Foreign(lhs), Expr::Foreign(rhs)) => Rc::ptr_eq(lhs, rhs),
+            (Expr::Values(lhs), Expr::Values(rhs)) => lhs == rhs,
             _ => false,
         }
     }
@@ -90,12 +306,46 @@ impl PartialEq for Expr {
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Expr::Bool(value, _) => write!(f, "{}", if *value { "#t" } else { "#f" }),
             Expr::Num(value, _) => write!(f, "{}", value),
+            Expr::Char(ch, _) => write!(f, "{}", CharLiteral(*ch)),
             Expr::Str(text, _) => write!(f, "\"{}\"", text), // TODO: escape control chars
             Expr::Sym(name, _) => write!(f, "{}", name),
             Expr::Proc(proc, _) => write!(f, "<{}>", proc.fingerprint()),
             Expr::List(list, _) => write!(f, "{}", list),
+            Expr::Vector(vector, _) => display_container(f, Rc::as_ptr(vector), |f| {
+                write!(f, "#(")?;
+                for (index, expr) in vector.borrow().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", expr)?;
+                }
+                write!(f, ")")
+            }),
+            Expr::Bytevector(bytes, _) => {
+                write!(f, "#u8(")?;
+                for (index, byte) in bytes.borrow().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", byte)?;
+                }
+                write!(f, ")")
+            }
+            Expr::HashTable(table, _) => write!(f, "{}", table.borrow()),
+            Expr::Record(record, _) => write!(f, "{}", record),
+            Expr::Promise(_, _) => write!(f, "#<promise>"),
             Expr::Foreign(object) => write!(f, "<foreign: {:p}>", object),
+            Expr::Values(values) => {
+                for (index, expr) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", expr)?;
+                }
+                Ok(())
+            }
 
             // TailCall is a special case and should not be displayed.
             Expr::TailCall { proc, .. } => panic!("Unexpected TailCall: {:?}", proc),
@@ -137,50 +387,66 @@ impl From<&str> for Expr {
     }
 }
 
+impl From<Number> for Expr {
+    fn from(value: Number) -> Self {
+        Expr::Num(value, None)
+    }
+}
+
 impl From<i32> for Expr {
     fn from(value: i32) -> Self {
-        Expr::Num(value as f64, None)
+        Expr::Num(value.into(), None)
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(value: i64) -> Self {
+        Expr::Num(value.into(), None)
     }
 }
 
 impl From<f64> for Expr {
     fn from(value: f64) -> Self {
-        Expr::Num(value, None)
+        Expr::Num(value.into(), None)
+    }
+}
+
+impl From<char> for Expr {
+    fn from(value: char) -> Self {
+        Expr::Char(value, None)
     }
 }
 
 impl From<bool> for Expr {
     fn from(value: bool) -> Self {
-        if value {
-            Expr::Num(1.0, None)
-        } else {
-            NIL
-        }
+        Expr::Bool(value, None)
     }
 }
 
 /// Interns a string into an `Expr::Sym`.
 ///
-/// This function takes a string and converts it into an `Expr::Sym`. The string is
-/// converted into an owned `String` and then wrapped in an `Expr::Sym` variant.
+/// This function takes a string, looks up the [`Symbol`] of the same name in the symbol
+/// table, and then wraps it in an `Expr::Sym` variant.
 ///
 /// # Examples
 ///
 /// ```
 /// use rusche::expr::{intern, Expr};
+/// use rusche::symbol::Symbol;
 ///
 /// let symbol = intern("foo");
-/// assert_eq!(symbol, Expr::Sym(String::from("foo"), None));
+/// assert_eq!(symbol, Expr::Sym(Symbol::new("foo"), None));
 /// ```
-pub fn intern<T: Into<String>>(name: T) -> Expr {
+pub fn intern<T: Into<Symbol>>(name: T) -> Expr {
     Expr::Sym(name.into(), None)
 }
 
 #[cfg(test)]
 pub mod test_utils {
     use super::Expr;
+    use crate::number::Number;
 
-    pub fn num<T: Into<f64>>(value: T) -> Expr {
+    pub fn num<T: Into<Number>>(value: T) -> Expr {
         Expr::Num(value.into(), None)
     }
 }
@@ -196,12 +462,28 @@ mod tests {
         assert_eq!(format!("{}", NIL), "()");
     }
 
+    #[test]
+    fn test_display_bool() {
+        assert_eq!(format!("{}", Expr::from(true)), "#t");
+        assert_eq!(format!("{}", Expr::from(false)), "#f");
+    }
+
     #[test]
     fn test_display_num() {
         assert_eq!(format!("{}", num(0)), "0");
         assert_eq!(format!("{}", num(1)), "1");
         assert_eq!(format!("{}", num(1.2)), "1.2");
-        assert_eq!(format!("{}", num(2.0)), "2");
+        assert_eq!(format!("{}", num(2.0)), "2.0");
+    }
+
+    #[test]
+    fn test_display_char() {
+        assert_eq!(format!("{}", Expr::from('a')), "#\\a");
+        assert_eq!(format!("{}", Expr::from('(')), "#\\(");
+        assert_eq!(format!("{}", Expr::from(' ')), "#\\space");
+        assert_eq!(format!("{}", Expr::from('\n')), "#\\newline");
+        assert_eq!(format!("{}", Expr::from('\u{1}')), "#\\x1");
+        assert_eq!(format!("{}", Expr::from('λ')), "#\\λ");
     }
 
     #[test]
@@ -232,6 +514,116 @@ mod tests {
         assert_eq!(format!("{}", list), r#"(0 "str" sym)"#);
     }
 
+    #[test]
+    fn test_display_vector() {
+        let vector = |items: Vec<Expr>| Expr::Vector(Rc::new(RefCell::new(items)), None);
+        assert_eq!(format!("{}", vector(vec![])), "#()");
+        assert_eq!(
+            format!("{}", vector(vec![num(1), "str".into(), list!(2, 3).into()])),
+            r#"#(1 "str" (2 3))"#
+        );
+    }
+
+    #[test]
+    fn test_display_cyclic_vector() {
+        let vector = Rc::new(RefCell::new(vec![num(1)]));
+        let expr = Expr::Vector(vector.clone(), None);
+        vector.borrow_mut().push(list!(expr.clone()).into());
+        assert_eq!(format!("{}", expr), "#(1 (#<cycle>))");
+
+        // shared but not cyclic
+        let shared = Expr::from(vec![expr.clone()]);
+        assert_eq!(
+            format!("{}", Expr::from(vec![shared.clone(), shared])),
+            "((#(1 (#<cycle>))) (#(1 (#<cycle>))))"
+        );
+        vector.borrow_mut().clear();
+    }
+
+    #[test]
+    fn test_display_bytevector() {
+        let bytevector = |bytes: Vec<u8>| Expr::Bytevector(Rc::new(RefCell::new(bytes)), None);
+        assert_eq!(format!("{}", bytevector(vec![])), "#u8()");
+        assert_eq!(format!("{}", bytevector(vec![0, 1, 255])), "#u8(0 1 255)");
+    }
+
+    #[test]
+    fn test_display_values() {
+        assert_eq!(format!("{}", Expr::Values(vec![])), "");
+        assert_eq!(
+            format!("{}", Expr::Values(vec![num(1), "str".into()])),
+            r#"1 "str""#
+        );
+    }
+
+    #[test]
+    fn test_is_eqv() {
+        let list: Expr = list!(1, 2).into();
+        assert!(list.is_eqv(&list.clone()));
+        assert!(!list.is_eqv(&list!(1, 2).into()));
+        assert!(NIL.is_eqv(&NIL));
+        assert!(num(1).is_eqv(&num(1)));
+        assert!(!num(1).is_eqv(&num(1.0)));
+        assert!(num(0.5).is_eqv(&num(0.5)));
+        assert!(!num(0.0).is_eqv(&num(-0.0)));
+        assert!(Expr::from("str").is_eqv(&"str".into()));
+
+        let vector = Expr::Vector(Rc::new(RefCell::new(vec![num(1)])), None);
+        assert!(vector.is_eqv(&vector.clone()));
+        assert!(!vector.is_eqv(&Expr::Vector(Rc::new(RefCell::new(vec![num(1)])), None)));
+
+        let foreign: Foreign = Rc::new(1);
+        assert!(Expr::Foreign(foreign.clone()).is_eqv(&Expr::Foreign(foreign)));
+        assert!(!Expr::Foreign(Rc::new(1)).is_eqv(&Expr::Foreign(Rc::new(1))));
+    }
+
+    #[test]
+    fn test_is_equal() {
+        let list: Expr = list!(1, list!(2, "str")).into();
+        assert!(list.is_equal(&list!(1, list!(2, "str")).into()));
+        assert!(!list.is_equal(&list!(1, list!(2)).into()));
+        assert!(!list.is_equal(&list!(1, list!(2.0, "str")).into()));
+        assert!(Expr::from(cons(1, 2)).is_equal(&cons(1, 2).into()));
+        assert!(!Expr::from(cons(1, 2)).is_equal(&list!(1, 2).into()));
+
+        let vector = |items: Vec<Expr>| Expr::Vector(Rc::new(RefCell::new(items)), None);
+        assert!(
+            vector(vec![num(1), list!(2).into()]).is_equal(&vector(vec![num(1), list!(2).into()]))
+        );
+        assert!(!vector(vec![num(1)]).is_equal(&vector(vec![num(1), num(2)])));
+    }
+
+    #[test]
+    fn test_cyclic_vector_equality() {
+        let cyclic = |value: i32| {
+            let vector = Rc::new(RefCell::new(vec![num(value)]));
+            vector.borrow_mut().push(Expr::Vector(vector.clone(), None));
+            vector
+        };
+        let (lhs, rhs, other) = (cyclic(1), cyclic(1), cyclic(2));
+        let expr = |vector: &Vector| Expr::Vector(vector.clone(), None);
+
+        assert!(expr(&lhs).is_equal(&expr(&rhs)));
+        assert!(!expr(&lhs).is_equal(&expr(&other)));
+        assert_eq!(expr(&lhs), expr(&rhs));
+        assert_ne!(expr(&lhs), expr(&other));
+
+        // break the cycles so that the vectors are dropped
+        for vector in [lhs, rhs, other] {
+            vector.borrow_mut().clear();
+        }
+    }
+
+    #[test]
+    fn test_into_values() {
+        assert_eq!(num(1).into_values(), vec![num(1)]);
+        assert_eq!(Expr::Values(vec![]).into_values(), vec![]);
+        assert_eq!(
+            Expr::Values(vec![num(1), num(2)]).into_values(),
+            vec![num(1), num(2)]
+        );
+    }
+
     #[test]
     fn test_expr_from_list() {
         assert_eq!(
@@ -248,7 +640,22 @@ mod tests {
 
     #[test]
     fn test_expr_from_bool() {
-        assert_eq!(Expr::from(true), num(1));
-        assert_eq!(Expr::from(false), NIL);
+        assert_eq!(Expr::from(true), Expr::Bool(true, None));
+        assert_eq!(Expr::from(false), Expr::Bool(false, None));
+        assert_ne!(Expr::from(false), NIL);
+    }
+
+    #[test]
+    fn test_is_truthy() {
+        assert!(Expr::from(true).is_truthy());
+        assert!(!Expr::from(false).is_truthy());
+        assert!(num(0).is_truthy());
+        assert!(Expr::from("").is_truthy());
+
+        #[cfg(not(feature = "legacy_falsy_nil"))]
+        assert!(NIL.is_truthy());
+
+        #[cfg(feature = "legacy_falsy_nil")]
+        assert!(!NIL.is_truthy());
     }
 }
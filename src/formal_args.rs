@@ -0,0 +1,290 @@
+use std::fmt;
+
+use crate::eval::{eval, EvalContext, EvalError};
+use crate::expr::Expr;
+use crate::proc::Arity;
+use crate::symbol::Symbol;
+
+/// The marker that starts the optional arguments in a list of formal arguments.
+pub const OPTIONAL: &str = "#!optional";
+
+/// The marker that starts the keyword arguments in a list of formal arguments.
+pub const KEY: &str = "#:key";
+
+/// The formal arguments of a [`Proc::Closure`] or a [`Proc::Macro`], e.g.
+/// `(a b #!optional (c 0) #:key (d 1) . rest)`.
+///
+/// - `a` and `b` are required.
+/// - `c` is optional, and defaults to `0` if not given.
+/// - `d` is a keyword argument, given as `#:d 2`, and defaults to `1`.
+/// - `rest` is bound to the list of the remaining arguments.
+///
+/// An optional or keyword argument without a default expression defaults to `#f`.
+/// Default expressions are evaluated after the preceding arguments are bound, so they
+/// can refer to them.
+///
+/// [`Proc::Closure`]: crate::proc::Proc::Closure
+/// [`Proc::Macro`]: crate::proc::Proc::Macro
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormalArgs {
+    pub required: Vec<Symbol>,
+    pub optional: Vec<(Symbol, Option<Expr>)>,
+    pub keys: Vec<(Symbol, Option<Expr>)>,
+    pub rest: Option<Symbol>,
+}
+
+impl FormalArgs {
+    /// Creates formal arguments that are all required.
+    pub fn new(required: Vec<Symbol>) -> Self {
+        Self {
+            required,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether `name` is one of the arguments.
+    pub(crate) fn contains(&self, name: &Symbol) -> bool {
+        self.required.contains(name)
+            || self
+                .optional
+                .iter()
+                .chain(&self.keys)
+                .any(|(arg, _)| arg == name)
+            || self.rest.as_ref() == Some(name)
+    }
+
+    /// Returns the number of arguments accepted, where each keyword argument counts as two.
+    pub fn arity(&self) -> Arity {
+        let min = self.required.len();
+        match self.rest {
+            Some(_) => Arity::at_least(min),
+            None => Arity::between(min, min + self.optional.len() + self.keys.len() * 2),
+        }
+    }
+
+    /// Binds the arguments in `context`. `args` are already evaluated, or are passed as is
+    /// for a macro.
+    pub(crate) fn bind(
+        &self,
+        proc_name: &str,
+        args: Vec<Expr>,
+        context: &EvalContext,
+    ) -> Result<(), EvalError> {
+        if !self.optional.is_empty() || !self.keys.is_empty() {
+            return self.bind_optional_and_keys(proc_name, args, context);
+        }
+
+        // Only required and rest arguments, which is the common case of every call.
+        if !self.arity().accepts(args.len()) {
+            return Err(self.arity_error(proc_name, args.len()));
+        }
+        let mut args = args.into_iter();
+        for (name, arg) in self.required.iter().zip(args.by_ref()) {
+            context.env.define(name, arg);
+        }
+        if let Some(name) = &self.rest {
+            context.env.define(name, args.collect::<Vec<_>>());
+        }
+        Ok(())
+    }
+
+    /// Binds the arguments when there are optional or keyword arguments. This is kept out
+    /// of `bind` so that the common case needs a small stack frame.
+    #[inline(never)]
+    fn bind_optional_and_keys(
+        &self,
+        proc_name: &str,
+        args: Vec<Expr>,
+        context: &EvalContext,
+    ) -> Result<(), EvalError> {
+        let arg_count = args.len();
+        let arity_error = || self.arity_error(proc_name, arg_count);
+
+        let mut args = args.into_iter().peekable();
+        for name in &self.required {
+            let arg = args.next().ok_or_else(arity_error)?;
+            context.env.define(name, arg);
+        }
+
+        for (name, default) in &self.optional {
+            // With keyword arguments, a keyword ends the optional arguments.
+            let arg = args.next_if(|arg| self.keys.is_empty() || as_keyword(arg).is_none());
+            let value = match (arg, default) {
+                (Some(arg), _) => arg,
+                (None, Some(default)) => eval(default, context)?,
+                (None, None) => false.into(),
+            };
+            context.env.define(name, value);
+        }
+
+        let rest: Vec<Expr> = args.collect();
+        if !self.keys.is_empty() {
+            let mut values = vec![None; self.keys.len()];
+            let mut iter = rest.iter();
+            while let Some(arg) = iter.next() {
+                let index = as_keyword(arg)
+                    .and_then(|key| self.keys.iter().position(|(name, _)| name.as_str() == key));
+                match index {
+                    Some(index) => {
+                        let Some(value) = iter.next() else {
+                            return Err(EvalError::from(format!(
+                                "{proc_name}: keyword `{arg}` expects a value."
+                            )));
+                        };
+                        values[index] = Some(value.clone());
+                    }
+                    None if self.rest.is_some() => {}
+                    None if as_keyword(arg).is_some() => {
+                        return Err(EvalError::from(format!(
+                            "{proc_name}: unknown keyword `{arg}`."
+                        )));
+                    }
+                    None => return Err(arity_error()),
+                }
+            }
+
+            for ((name, default), value) in self.keys.iter().zip(values) {
+                let value = match (value, default) {
+                    (Some(value), _) => value,
+                    (None, Some(default)) => eval(default, context)?,
+                    (None, None) => false.into(),
+                };
+                context.env.define(name, value);
+            }
+        }
+
+        match &self.rest {
+            Some(name) => context.env.define(name, rest),
+            None if self.keys.is_empty() && !rest.is_empty() => return Err(arity_error()),
+            None => {}
+        }
+        Ok(())
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn arity_error(&self, proc_name: &str, arg_count: usize) -> EvalError {
+        EvalError::from(format!(
+            "{proc_name}: expects {}, but got {arg_count}.",
+            self.arity()
+        ))
+    }
+}
+
+impl fmt::Display for FormalArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arg_to_string = |(name, default): &(Symbol, Option<Expr>)| match default {
+            Some(default) => format!("({name} {default})"),
+            None => name.to_string(),
+        };
+
+        let mut items: Vec<String> = self.required.iter().map(|name| name.to_string()).collect();
+        if !self.optional.is_empty() {
+            items.push(OPTIONAL.to_owned());
+            items.extend(self.optional.iter().map(arg_to_string));
+        }
+        if !self.keys.is_empty() {
+            items.push(KEY.to_owned());
+            items.extend(self.keys.iter().map(arg_to_string));
+        }
+
+        match &self.rest {
+            Some(rest) if items.is_empty() => write!(f, "{rest}"),
+            Some(rest) => write!(f, "({} . {rest})", items.join(" ")),
+            None => write!(f, "({})", items.join(" ")),
+        }
+    }
+}
+
+/// Returns the name of a keyword argument such as `#:name`, without the `#:` prefix.
+fn as_keyword(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Sym(symbol, _) if symbol.is_keyword() => Some(&symbol[2..]),
+        _ => None,
+    }
+}
+
+/// Extracts the name of variadic arguments from the given name.
+///
+/// If the name starts with `*` and has more than one character,
+/// returns the rest of the name. Otherwise, returns `None`.
+///
+pub(crate) fn get_variadic_args_name(name: &str) -> Option<&str> {
+    if name.starts_with("*") && name.len() > 1 {
+        Some(&name[1..])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+
+    fn sym(name: &str) -> Symbol {
+        Symbol::new(name)
+    }
+
+    #[test]
+    fn test_get_variadic_args_name() {
+        assert_eq!(get_variadic_args_name("args"), None);
+        assert_eq!(get_variadic_args_name("*args"), Some("args"));
+        assert_eq!(get_variadic_args_name("*a"), Some("a"));
+        assert_eq!(get_variadic_args_name("*"), None);
+    }
+
+    #[test]
+    fn test_arity() {
+        assert_eq!(FormalArgs::new(vec![sym("a")]).arity(), Arity::exact(1));
+
+        let formal_args = FormalArgs {
+            required: vec![sym("a")],
+            optional: vec![(sym("b"), None)],
+            keys: vec![(sym("c"), None)],
+            rest: None,
+        };
+        assert_eq!(formal_args.arity(), Arity::between(1, 4));
+        assert_eq!(formal_args.to_string(), "(a #!optional b #:key c)");
+
+        let formal_args = FormalArgs {
+            rest: Some(sym("rest")),
+            ..FormalArgs::default()
+        };
+        assert_eq!(formal_args.arity(), Arity::at_least(0));
+        assert_eq!(formal_args.to_string(), "rest");
+    }
+
+    #[test]
+    fn test_bind() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+        let keyword = |name: &str| Expr::Sym(sym(name), None);
+
+        let formal_args = FormalArgs {
+            required: vec![sym("a")],
+            optional: vec![(sym("b"), Some(2.into()))],
+            keys: vec![(sym("c"), None), (sym("d"), Some(4.into()))],
+            rest: None,
+        };
+        formal_args
+            .bind("f", vec![1.into(), keyword("#:d"), 5.into()], context)
+            .unwrap();
+        assert_eq!(context.env.lookup("a"), Some(1.into()));
+        assert_eq!(context.env.lookup("b"), Some(2.into()));
+        assert_eq!(context.env.lookup("c"), Some(false.into()));
+        assert_eq!(context.env.lookup("d"), Some(5.into()));
+
+        let error = formal_args.bind("f", vec![], context).unwrap_err();
+        assert_eq!(error.message, "f: expects 1 to 6 args, but got 0.");
+        assert!(formal_args
+            .bind("f", vec![1.into(), keyword("#:e"), 5.into()], context)
+            .is_err());
+        assert!(formal_args
+            .bind("f", vec![1.into(), keyword("#:c")], context)
+            .is_err());
+        assert!(formal_args
+            .bind("f", vec![1.into(), 2.into(), 3.into()], context)
+            .is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::expr::{display_container, Expr};
+use crate::list::cons;
+use crate::number::{BigInt, Number, Rational};
+use crate::symbol::Symbol;
+
+/// The hashable form of a hash table key.
+///
+/// Two keys are the same if they are `equal?`: numbers must agree in both value and
+/// exactness, so `1` and `1.0` are different keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum HashKey {
+    Bool(bool),
+    Int(i64),
+    BigInt(BigInt),
+    Rational(Rational),
+    Real(u64),
+    Char(char),
+    Str(String),
+    Sym(Symbol),
+}
+
+impl HashKey {
+    fn new(expr: &Expr) -> Option<Self> {
+        match expr {
+            Expr::Bool(value, _) => Some(HashKey::Bool(*value)),
+            Expr::Num(Number::Int(value), _) => Some(HashKey::Int(*value)),
+            Expr::Num(Number::BigInt(value), _) => Some(HashKey::BigInt(BigInt::clone(value))),
+            Expr::Num(Number::Rational(value), _) => {
+                Some(HashKey::Rational(Rational::clone(value)))
+            }
+            Expr::Num(Number::Real(value), _) => Some(HashKey::Real(value.to_bits())),
+            Expr::Char(ch, _) => Some(HashKey::Char(*ch)),
+            Expr::Str(text, _) => Some(HashKey::Str(text.clone())),
+            Expr::Sym(name, _) => Some(HashKey::Sym(name.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// The error returned when an expression that cannot be hashed is used as a key.
+#[derive(Debug, PartialEq)]
+pub struct InvalidKeyError;
+
+/// A mutable hash table mapping numbers, characters, strings, symbols and booleans to
+/// arbitrary values.
+///
+/// Entries are kept in insertion order, which is the order used for iteration and printing.
+#[derive(Clone, Debug, Default)]
+pub struct HashTable {
+    /// The entries in insertion order, with `None` in place of removed entries until
+    /// the table is compacted.
+    entries: Vec<Option<(Expr, Expr)>>,
+    indices: HashMap<HashKey, usize>,
+}
+
+impl HashTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if `expr` can be used as a key.
+    pub fn is_valid_key(expr: &Expr) -> bool {
+        HashKey::new(expr).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the value associated with `key`, if any.
+    pub fn get(&self, key: &Expr) -> Option<&Expr> {
+        let index = self.indices.get(&HashKey::new(key)?)?;
+        self.entries[*index].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &Expr) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Associates `value` with `key`, returning the value previously associated with it.
+    pub fn insert(&mut self, key: Expr, value: Expr) -> Result<Option<Expr>, InvalidKeyError> {
+        let hash_key = HashKey::new(&key).ok_or(InvalidKeyError)?;
+        if let Some((_, old_value)) = self
+            .indices
+            .get(&hash_key)
+            .and_then(|index| self.entries[*index].as_mut())
+        {
+            return Ok(Some(std::mem::replace(old_value, value)));
+        }
+        self.indices.insert(hash_key, self.entries.len());
+        self.entries.push(Some((key, value)));
+        Ok(None)
+    }
+
+    /// Removes `key` from the table, returning the value associated with it.
+    pub fn remove(&mut self, key: &Expr) -> Option<Expr> {
+        let index = self.indices.remove(&HashKey::new(key)?)?;
+        let (_, value) = self.entries[index].take()?;
+
+        // Compact once more than half of the entries are removed ones, so that removal
+        // takes amortized constant time.
+        if self.entries.len() > 2 * self.indices.len() {
+            self.compact();
+        }
+        Some(value)
+    }
+
+    fn compact(&mut self) {
+        self.entries.retain(Option::is_some);
+        self.indices = self
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (key, _))| Some((HashKey::new(key)?, index)))
+            .collect();
+    }
+
+    /// Iterates over the key-value pairs in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Expr, &Expr)> {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key, value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Expr> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Expr> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl PartialEq for HashTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl fmt::Display for HashTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_container(f, self, |f| {
+            write!(f, "#hash(")?;
+            for (index, (key, value)) in self.iter().enumerate() {
+                if index > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", cons(key.clone(), value.clone()))?;
+            }
+            write!(f, ")")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{intern, test_utils::num};
+    use crate::macros::list;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut table = HashTable::new();
+        assert_eq!(table.insert(num(1), "one".into()), Ok(None));
+        assert_eq!(table.insert("two".into(), num(2)), Ok(None));
+        assert_eq!(table.insert(intern("three"), num(3)), Ok(None));
+        assert_eq!(table.insert(true.into(), num(4)), Ok(None));
+        assert_eq!(table.len(), 4);
+
+        assert_eq!(table.get(&num(1)), Some(&"one".into()));
+        assert_eq!(table.get(&"two".into()), Some(&num(2)));
+        assert_eq!(table.get(&intern("three")), Some(&num(3)));
+        assert_eq!(table.get(&true.into()), Some(&num(4)));
+
+        // `equal?` semantics: exactness matters and strings are not symbols
+        assert_eq!(table.get(&num(1.0)), None);
+        assert_eq!(table.get(&intern("two")), None);
+        assert_eq!(table.get(&false.into()), None);
+
+        assert_eq!(table.insert(num(1), "uno".into()), Ok(Some("one".into())));
+        assert_eq!(table.get(&num(1)), Some(&"uno".into()));
+        assert_eq!(table.len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        let mut table = HashTable::new();
+        assert!(!HashTable::is_valid_key(&list!(1).into()));
+        assert_eq!(table.insert(list!(1).into(), num(1)), Err(InvalidKeyError));
+        assert_eq!(table.get(&list!(1).into()), None);
+        assert_eq!(table.remove(&list!(1).into()), None);
+    }
+
+    #[test]
+    fn test_big_and_rational_keys() {
+        let mut table = HashTable::new();
+        let big = Expr::from(Number::from(
+            "123456789012345678901234567890".parse::<BigInt>().unwrap(),
+        ));
+        let ratio = Expr::from(Number::from("1/3".parse::<Rational>().unwrap()));
+        table.insert(big.clone(), num(1)).unwrap();
+        table.insert(ratio.clone(), num(2)).unwrap();
+        assert_eq!(table.get(&big), Some(&num(1)));
+        assert_eq!(table.get(&ratio), Some(&num(2)));
+    }
+
+    #[test]
+    fn test_remove_keeps_order() {
+        let mut table = HashTable::new();
+        for n in 1..=4 {
+            table.insert(num(n), num(n * 10)).unwrap();
+        }
+        assert_eq!(table.remove(&num(2)), Some(num(20)));
+        assert_eq!(table.remove(&num(2)), None);
+        assert_eq!(
+            table.keys().cloned().collect::<Vec<_>>(),
+            [num(1), num(3), num(4)]
+        );
+        assert_eq!(table.get(&num(4)), Some(&num(40)));
+
+        table.insert(num(2), num(0)).unwrap();
+        assert_eq!(
+            table.to_string(),
+            "#hash((1 . 10) (3 . 30) (4 . 40) (2 . 0))"
+        );
+    }
+
+    #[test]
+    fn test_remove_compacts() {
+        let mut table = HashTable::new();
+        for n in 0..100 {
+            table.insert(num(n), num(n)).unwrap();
+        }
+        for n in 0..90 {
+            assert_eq!(table.remove(&num(n)), Some(num(n)));
+        }
+        assert_eq!(table.len(), 10);
+        assert!(table.entries.len() <= 20);
+        assert_eq!(
+            table.keys().cloned().collect::<Vec<_>>(),
+            (90..100).map(num).collect::<Vec<_>>()
+        );
+        assert!((90..100).all(|n| table.get(&num(n)) == Some(&num(n))));
+
+        for n in 90..100 {
+            table.remove(&num(n));
+        }
+        assert!(table.is_empty());
+        assert!(table.entries.is_empty());
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut lhs = HashTable::new();
+        let mut rhs = HashTable::new();
+        lhs.insert(num(1), num(1)).unwrap();
+        lhs.insert(num(2), num(2)).unwrap();
+        rhs.insert(num(2), num(2)).unwrap();
+        assert_ne!(lhs, rhs);
+        rhs.insert(num(1), num(1)).unwrap();
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut table = HashTable::new();
+        assert_eq!(table.to_string(), "#hash()");
+        table.insert(intern("a"), num(1)).unwrap();
+        table.insert("b".into(), list!(1, 2).into()).unwrap();
+        assert_eq!(table.to_string(), r#"#hash((a . 1) ("b" 1 2))"#);
+    }
+}
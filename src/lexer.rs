@@ -1,3 +1,5 @@
+use crate::expr::CHAR_NAMES;
+use crate::number::Number;
 use crate::span::{Loc, Span};
 use crate::token::Token;
 use std::iter::{Iterator, Peekable};
@@ -8,6 +10,7 @@ const TOKEN_DELIMITERS: &str = " \t\r\n()';\"";
 pub enum LexError {
     IncompleteString(Span),
     InvalidNumber(Span),
+    InvalidChar(Span),
 }
 
 type LexResult = Result<Option<Token>, LexError>;
@@ -60,9 +63,18 @@ where
             // string
             Some('"') => self.read_string(begin_loc),
 
+            // vector, bytevector, hash table, boolean, character or symbol
+            Some('#') => self.read_hash(begin_loc),
+
             // number
             Some(ch) if ch.is_ascii_digit() => self.read_number(ch, begin_loc),
 
+            // dot or symbol
+            Some('.') => match self.read_symbol('.', begin_loc)? {
+                Some(Token::Sym(name, _)) if name == "." => Ok(Some(Token::Dot(begin_loc))),
+                token => Ok(token),
+            },
+
             // number or symbol
             Some(ch) if ch == '+' || ch == '-' => match self.iter.peek() {
                 Some(&next_ch) if next_ch.is_ascii_digit() => self.read_number(ch, begin_loc),
@@ -114,24 +126,75 @@ where
 
     fn read_number(&mut self, first_char: char, begin_loc: Loc) -> LexResult {
         let mut digits = String::new();
-
-        if first_char.is_ascii_digit() {
-            digits.push(first_char);
-        }
+        digits.push(first_char);
 
         while let Some(ch) = self.next_char_if(|ch| !TOKEN_DELIMITERS.contains(*ch)) {
             digits.push(ch);
         }
 
-        let sign = if first_char == '-' { -1.0 } else { 1.0 };
         let span = begin_loc.span_to(self.loc);
 
         digits
-            .parse::<f64>()
-            .map(|value| Some(Token::Num(value * sign, span)))
+            .parse::<Number>()
+            .map(|value| Some(Token::Num(value, span)))
             .map_err(|_| LexError::InvalidNumber(span))
     }
 
+    fn read_hash(&mut self, begin_loc: Loc) -> LexResult {
+        if self.next_char_if(|ch| *ch == '(').is_some() {
+            return Ok(Some(Token::OpenVector(begin_loc)));
+        }
+        if self.next_char_if(|ch| *ch == '\\').is_some() {
+            return self.read_char(begin_loc);
+        }
+
+        let Some(Token::Sym(name, span)) = self.read_symbol('#', begin_loc)? else {
+            unreachable!("read_symbol() always returns a symbol");
+        };
+
+        match name.as_str() {
+            "#u8" if self.next_char_if(|ch| *ch == '(').is_some() => {
+                Ok(Some(Token::OpenBytevector(begin_loc)))
+            }
+            "#hash" if self.next_char_if(|ch| *ch == '(').is_some() => {
+                Ok(Some(Token::OpenHashTable(begin_loc)))
+            }
+            "#t" | "#true" => Ok(Some(Token::Bool(true, span))),
+            "#f" | "#false" => Ok(Some(Token::Bool(false, span))),
+            _ => Ok(Some(Token::Sym(name, span))),
+        }
+    }
+
+    fn read_char(&mut self, begin_loc: Loc) -> LexResult {
+        // The first character is taken as is, even if it is a delimiter, e.g. `#\(`.
+        let Some(first_char) = self.next_char() else {
+            return Err(LexError::InvalidChar(begin_loc.span_to(self.loc)));
+        };
+
+        let mut name = String::new();
+        name.push(first_char);
+        while let Some(ch) = self.next_char_if(|ch| !TOKEN_DELIMITERS.contains(*ch)) {
+            name.push(ch);
+        }
+
+        let span = begin_loc.span_to(self.loc);
+
+        let mut chars = name.chars();
+        let ch = match (chars.next(), chars.next()) {
+            (Some(ch), None) => Some(ch),
+            (Some('x'), Some(_)) => u32::from_str_radix(&name[1..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            _ => CHAR_NAMES
+                .iter()
+                .find(|(char_name, _)| *char_name == name)
+                .map(|(_, ch)| *ch),
+        };
+
+        ch.map(|ch| Some(Token::Char(ch, span)))
+            .ok_or(LexError::InvalidChar(span))
+    }
+
     fn read_symbol(&mut self, first_char: char, begin_loc: Loc) -> LexResult {
         let mut name = String::with_capacity(16);
         name.push(first_char);
@@ -188,6 +251,7 @@ pub fn tokenize(text: &str, loc: Option<Loc>) -> Result<Vec<Token>, LexError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::macros::tok;
 
     #[test]
     fn test_read_string() {
@@ -237,10 +301,181 @@ mod tests {
         assert_parsed_number!("1", 1);
         assert_parsed_number!("1.1", 1.1);
         assert_parsed_number!("-1", -1);
+        assert_parsed_number!("+1", 1);
+        assert_parsed_number!("-1.5", -1.5);
+        assert_parsed_number!("1e3", 1000.0);
+
+        macro_rules! assert_parsed_exactness {
+            ($source:literal, $is_exact:literal) => {
+                let token = Lexer::new($source.chars(), Loc::default())
+                    .get_token()
+                    .unwrap()
+                    .unwrap();
+                let Token::Num(value, _) = token else {
+                    panic!("{} is not lexed as a number", $source);
+                };
+                assert_eq!(value.is_exact(), $is_exact);
+            };
+        }
+
+        assert_parsed_exactness!("1", true);
+        assert_parsed_exactness!("-1", true);
+        assert_parsed_exactness!("12345678901234567890123", true);
+        assert_parsed_exactness!("1.0", false);
+        assert_parsed_exactness!("1e3", false);
+        assert_parsed_exactness!("1/3", true);
+        assert_parsed_exactness!("-2/4", true);
 
         assert!(Lexer::new("123xya".chars(), Loc::default())
             .get_token()
             .is_err());
+        assert!(Lexer::new("1/0".chars(), Loc::default())
+            .get_token()
+            .is_err());
+        assert!(Lexer::new("1/2/3".chars(), Loc::default())
+            .get_token()
+            .is_err());
+    }
+
+    #[test]
+    fn test_read_bool() {
+        let get_token = |src: &str| Lexer::new(src.chars(), Loc::default()).get_token();
+
+        assert_eq!(get_token("#t").unwrap().unwrap(), tok!(Bool(true)));
+        assert_eq!(get_token("#true").unwrap().unwrap(), tok!(Bool(true)));
+        assert_eq!(get_token("#f").unwrap().unwrap(), tok!(Bool(false)));
+        assert_eq!(get_token("#false").unwrap().unwrap(), tok!(Bool(false)));
+
+        // not a boolean literal
+        assert_eq!(get_token("#tf").unwrap().unwrap(), tok!(Sym("#tf")));
+
+        // a boolean literal followed by a delimiter
+        let mut lexer = Lexer::new("(#t)".chars(), Loc::default());
+        assert_eq!(lexer.get_token().unwrap().unwrap(), tok!(OpenParen));
+        let token = lexer.get_token().unwrap().unwrap();
+        assert_eq!(token, tok!(Bool(true)));
+        assert_eq!(token.span(), Span::new(Loc::new(0, 1), Loc::new(0, 3)));
+        assert_eq!(lexer.get_token().unwrap().unwrap(), tok!(CloseParen));
+    }
+
+    #[test]
+    fn test_read_dot() {
+        let get_tokens = |src: &str| tokenize(src, None).unwrap();
+
+        assert_eq!(
+            get_tokens("(1 . 2)"),
+            vec![
+                tok!(OpenParen),
+                tok!(Num(1)),
+                tok!(Dot),
+                tok!(Num(2)),
+                tok!(CloseParen)
+            ]
+        );
+        assert_eq!(get_tokens("(a.b)")[1], tok!(Sym("a.b")));
+        assert_eq!(get_tokens("...")[0], tok!(Sym("...")));
+        assert_eq!(get_tokens(".a")[0], tok!(Sym(".a")));
+    }
+
+    #[test]
+    fn test_read_vector() {
+        let get_tokens = |src: &str| tokenize(src, None).unwrap();
+
+        assert_eq!(
+            get_tokens("#(1 #t)"),
+            vec![
+                tok!(OpenVector),
+                tok!(Num(1)),
+                tok!(Bool(true)),
+                tok!(CloseParen)
+            ]
+        );
+
+        let tokens = get_tokens("'#()");
+        assert_eq!(
+            tokens,
+            vec![tok!(Quote), tok!(OpenVector), tok!(CloseParen)]
+        );
+        assert_eq!(tokens[1].span(), Span::new(Loc::new(0, 1), Loc::new(0, 3)));
+    }
+
+    #[test]
+    fn test_read_char() {
+        let get_tokens = |src: &str| tokenize(src, None).unwrap();
+
+        assert_eq!(get_tokens(r"#\a"), vec![tok!(Char('a'))]);
+        assert_eq!(get_tokens(r"#\A"), vec![tok!(Char('A'))]);
+        assert_eq!(get_tokens(r"#\space"), vec![tok!(Char(' '))]);
+        assert_eq!(get_tokens(r"#\newline"), vec![tok!(Char('\n'))]);
+        assert_eq!(get_tokens(r"#\x41"), vec![tok!(Char('A'))]);
+        assert_eq!(get_tokens(r"#\x3bb"), vec![tok!(Char('λ'))]);
+        assert_eq!(get_tokens(r"#\x"), vec![tok!(Char('x'))]);
+        assert_eq!(get_tokens(r"#\ "), vec![tok!(Char(' '))]);
+        assert_eq!(
+            get_tokens(r"(#\( #\))"),
+            vec![
+                tok!(OpenParen),
+                tok!(Char('(')),
+                tok!(Char(')')),
+                tok!(CloseParen)
+            ]
+        );
+
+        let tokens = get_tokens(r"#\space)");
+        assert_eq!(tokens[0].span(), Span::new(Loc::new(0, 0), Loc::new(0, 7)));
+
+        assert_eq!(
+            tokenize(r"#\spaces", None),
+            Err(LexError::InvalidChar(Span::new(
+                Loc::new(0, 0),
+                Loc::new(0, 8)
+            )))
+        );
+        assert!(tokenize(r"#\xZZ", None).is_err());
+        assert!(tokenize(r"#\xD800", None).is_err());
+        assert!(tokenize(r"#\", None).is_err());
+    }
+
+    #[test]
+    fn test_read_bytevector() {
+        let get_tokens = |src: &str| tokenize(src, None).unwrap();
+
+        let tokens = get_tokens("#u8(1 255)");
+        assert_eq!(
+            tokens,
+            vec![
+                tok!(OpenBytevector),
+                tok!(Num(1)),
+                tok!(Num(255)),
+                tok!(CloseParen)
+            ]
+        );
+        assert_eq!(tokens[0].span(), Span::new(Loc::new(0, 0), Loc::new(0, 4)));
+
+        assert_eq!(get_tokens("#u8")[0], tok!(Sym("#u8")));
+    }
+
+    #[test]
+    fn test_read_hash_table() {
+        let get_tokens = |src: &str| tokenize(src, None).unwrap();
+
+        let tokens = get_tokens("#hash((a . 1))");
+        assert_eq!(
+            tokens,
+            vec![
+                tok!(OpenHashTable),
+                tok!(OpenParen),
+                tok!(Sym("a")),
+                tok!(Dot),
+                tok!(Num(1)),
+                tok!(CloseParen),
+                tok!(CloseParen)
+            ]
+        );
+        assert_eq!(tokens[0].span(), Span::new(Loc::new(0, 0), Loc::new(0, 6)));
+
+        assert_eq!(get_tokens("#hash")[0], tok!(Sym("#hash")));
+        assert_eq!(get_tokens("#hash ()")[0], tok!(Sym("#hash")));
     }
 
     #[test]
@@ -315,8 +550,8 @@ mod tests {
 
         match_next_token!(Some(OpenParen));
         match_next_token!(Some(Sym("add".into())));
-        match_next_token!(Some(Num(1.0)));
-        match_next_token!(Some(Num(2.34)));
+        match_next_token!(Some(Num(1.into())));
+        match_next_token!(Some(Num(2.34.into())));
         match_next_token!(Some(OpenParen));
         match_next_token!(Some(Sym("x".into())));
         match_next_token!(Some(Sym("y".into())));
@@ -324,9 +559,9 @@ mod tests {
         match_next_token!(Some(Str("test".into())));
         match_next_token!(Some(Quote));
         match_next_token!(Some(OpenParen));
-        match_next_token!(Some(Num(100.0)));
-        match_next_token!(Some(Num(200.0)));
-        match_next_token!(Some(Num(300.0)));
+        match_next_token!(Some(Num(100.into())));
+        match_next_token!(Some(Num(200.into())));
+        match_next_token!(Some(Num(300.into())));
         match_next_token!(Some(CloseParen));
         match_next_token!(Some(CloseParen));
         match_next_token!(None);
@@ -14,25 +14,46 @@ mod prelude;
 
 mod macros;
 
+pub mod continuation;
 pub mod env;
 pub mod eval;
 pub mod expr;
+pub mod formal_args;
+pub mod hash_table;
 pub mod lexer;
 pub mod list;
+pub mod number;
+pub mod parameter;
 pub mod parser;
 pub mod proc;
+pub mod promise;
+pub mod record;
 pub mod span;
+pub mod symbol;
+pub mod syntax_rules;
 pub mod token;
 pub mod utils;
 
 // Re-export public APIs
+pub use continuation::Continuation;
 pub use env::Env;
-pub use eval::{eval, eval_tail, EvalContext, EvalError, EvalResult, Evaluator};
+pub use eval::{eval, eval_tail, eval_values, EvalContext, EvalError, EvalResult, Evaluator};
 pub use expr::{intern, Expr, Foreign, NIL};
+pub use formal_args::FormalArgs;
+pub use hash_table::HashTable;
 pub use lexer::{tokenize, LexError, Lexer};
 pub use list::{cons, Cons, List, ListIter};
+pub use number::{BigInt, Number, Rational};
+pub use parameter::Parameter;
 pub use parser::{ParseError, Parser};
-pub use proc::{NativeFunc, Proc};
+pub use proc::{Arity, NativeFunc, Proc};
+pub use promise::Promise;
+pub use record::{Record, RecordType};
 pub use span::{Loc, Span};
+pub use symbol::Symbol;
+pub use syntax_rules::SyntaxRules;
 pub use token::Token;
-pub use utils::{eval_into_foreign, eval_into_int, get_exact_1_arg, get_exact_2_args};
+pub use utils::{
+    eval_into_bytes, eval_into_foreign, eval_into_int, eval_into_num, get_exact_1_arg,
+    get_exact_2_args, Bytes,
+};
@@ -2,46 +2,64 @@ use crate::expr::Expr;
 use crate::span::Span;
 use std::fmt;
 use std::iter::Iterator;
+use std::rc::Rc;
 
 /// The struct that represents a [cons cell](https://en.wikipedia.org/wiki/Cons) that contains a value and a reference to the next cons cell.
+///
+/// The cdr is usually another list, but it can be any expression, in which case the cons cell
+/// is a dotted pair, e.g. `(1 . 2)`.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Cons {
     pub car: Box<Expr>,
-    pub cdr: Box<List>,
+    pub cdr: Box<Expr>,
 }
 
 impl Cons {
-    pub fn new<T>(car: T, cdr: List) -> Self
+    pub fn new<T, U>(car: T, cdr: U) -> Self
     where
         T: Into<Expr>,
+        U: Into<Expr>,
     {
         Self {
             car: Box::new(car.into()),
-            cdr: Box::new(cdr),
+            cdr: Box::new(cdr.into()),
         }
     }
 
     pub fn cdar(&self) -> Option<&Expr> {
-        if let List::Cons(cons) = self.cdr.as_ref() {
+        if let Expr::List(List::Cons(cons), _) = self.cdr.as_ref() {
             Some(&cons.car)
         } else {
             None
         }
     }
+
+    /// Returns the cdr as a list, or `None` if the cdr is not a list.
+    pub fn cdr_list(&self) -> Option<&List> {
+        if let Expr::List(list, _) = self.cdr.as_ref() {
+            Some(list)
+        } else {
+            None
+        }
+    }
 }
 
 /// The enum that represents a list which is either a cons cell or the empty list.
+///
+/// Cons cells are shared when a list is cloned, which gives lists identity for `eq?`.
+/// `PartialEq` compares lists structurally.
 #[derive(Clone, Debug, PartialEq)]
 pub enum List {
-    Cons(Cons),
+    Cons(Rc<Cons>),
     Nil,
 }
 
 impl List {
-    pub fn iter(&self) -> ListIter {
+    pub fn iter(&self) -> ListIter<'_> {
         ListIter::new(self)
     }
 
+    /// Returns the number of elements in the list, not counting the tail of an improper list.
     pub fn len(&self) -> usize {
         self.iter().count()
     }
@@ -54,6 +72,14 @@ impl List {
         self.is_empty()
     }
 
+    /// Returns `true` if the list is terminated by the empty list, i.e. it is not a dotted list
+    /// such as `(1 2 . 3)`.
+    pub fn is_proper(&self) -> bool {
+        let mut iter = self.iter();
+        iter.by_ref().for_each(drop);
+        iter.tail().is_none()
+    }
+
     pub fn span(&self) -> Option<Span> {
         let mut iter = self.iter();
 
@@ -78,37 +104,44 @@ impl<'a> From<ListIter<'a>> for List {
 
 impl fmt::Display for List {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_list(f, self, true)
-    }
-}
-
-fn write_list(f: &mut fmt::Formatter<'_>, list: &List, is_top_level: bool) -> fmt::Result {
-    if is_top_level {
         write!(f, "(")?;
-    }
-    if let List::Cons(cons) = list {
-        if is_top_level {
-            write!(f, "{}", cons.car)?;
-        } else {
-            write!(f, " {}", cons.car)?;
+
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            write!(f, "{}", first)?;
+        }
+        for expr in iter.by_ref() {
+            write!(f, " {}", expr)?;
+        }
+        if let Some(tail) = iter.tail() {
+            write!(f, " . {}", tail)?;
         }
 
-        write_list(f, &cons.cdr, false)?
-    }
-    if is_top_level {
-        write!(f, ")")?;
+        write!(f, ")")
     }
-    Ok(())
 }
 
 /// An iterator that iterates over the elements of [`List`].
+///
+/// If the list is improper, the iteration stops before the tail, which can then be
+/// retrieved with [`ListIter::tail`].
+#[derive(Clone)]
 pub struct ListIter<'a> {
     list: &'a List,
+    tail: Option<&'a Expr>,
 }
 
 impl<'a> ListIter<'a> {
     pub fn new(list: &'a List) -> Self {
-        Self { list }
+        Self { list, tail: None }
+    }
+
+    /// Returns the tail of an improper list, once the iteration has reached it.
+    ///
+    /// For `(1 2 . 3)`, this returns `Some(3)` after `1` and `2` have been iterated over.
+    /// It always returns `None` for proper lists.
+    pub fn tail(&self) -> Option<&'a Expr> {
+        self.tail
     }
 }
 
@@ -117,9 +150,14 @@ impl<'a> Iterator for ListIter<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if let List::Cons(cons) = self.list {
-            let car = &cons.car;
-            self.list = &cons.cdr;
-            Some(car)
+            match cons.cdr.as_ref() {
+                Expr::List(list, _) => self.list = list,
+                tail => {
+                    self.list = &List::Nil;
+                    self.tail = Some(tail);
+                }
+            }
+            Some(&cons.car)
         } else {
             None
         }
@@ -127,12 +165,14 @@ impl<'a> Iterator for ListIter<'a> {
 }
 
 /// Create a new cons cell with the given value and the next cons cell.
+///
+/// If `cdr` is not a list, the result is a dotted pair.
 pub fn cons<T, U>(car: T, cdr: U) -> List
 where
     T: Into<Expr>,
-    U: Into<List>,
+    U: Into<Expr>,
 {
-    List::Cons(Cons::new(car, cdr.into()))
+    List::Cons(Rc::new(Cons::new(car, cdr)))
 }
 
 #[cfg(test)]
@@ -148,6 +188,9 @@ mod tests {
         // (1 nil).cdar => None
         assert_eq!(Cons::new(Expr::from(1), List::Nil).cdar(), None);
 
+        // (1 . 2).cdar => None
+        assert_eq!(Cons::new(1, 2).cdar(), None);
+
         // (1 '(1 2)).cdar => Some(1)
         assert_eq!(
             Cons::new(Expr::from(1), list!(1, 2)).cdar(),
@@ -167,39 +210,51 @@ mod tests {
     fn test_display() {
         let list = list!(1, 2, list!(3, "str", intern("sym")));
         assert_eq!(format!("{}", list), "(1 2 (3 \"str\" sym))");
+
+        assert_eq!(format!("{}", cons(1, 2)), "(1 . 2)");
+        assert_eq!(format!("{}", cons(1, cons(2, 3))), "(1 2 . 3)");
+        assert_eq!(format!("{}", cons(list!(1), list!(2))), "((1) 2)");
+    }
+
+    #[test]
+    fn test_is_proper() {
+        assert!(list!().is_proper());
+        assert!(list!(1, 2).is_proper());
+        assert!(!cons(1, 2).is_proper());
+        assert!(!cons(1, cons(2, 3)).is_proper());
     }
 
     #[test]
     fn test_list_span() {
         // (1 2 3)
         let args = list!(
-            Expr::Num(1.0, Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
-            Expr::Num(2.0, Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
-            Expr::Num(3.0, Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
+            Expr::Num(1.into(), Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
+            Expr::Num(2.into(), Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
+            Expr::Num(3.into(), Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
         );
         assert_eq!(args.span(), Some(Span::new(Loc::new(1, 1), Loc::new(1, 6))));
 
         // (1 2 3)
         let args = list!(
-            Expr::Num(1.0, None),
-            Expr::Num(2.0, Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
-            Expr::Num(3.0, Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
+            Expr::Num(1.into(), None),
+            Expr::Num(2.into(), Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
+            Expr::Num(3.into(), Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
         );
         assert_eq!(args.span(), None);
 
         // (1 2 3)
         let args = list!(
-            Expr::Num(1.0, Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
-            Expr::Num(2.0, Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
-            Expr::Num(3.0, None)
+            Expr::Num(1.into(), Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
+            Expr::Num(2.into(), Some(Span::new(Loc::new(1, 3), Loc::new(1, 4)))),
+            Expr::Num(3.into(), None)
         );
         assert_eq!(args.span(), None);
 
         // (1 2 3)
         let args = list!(
-            Expr::Num(1.0, Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
-            Expr::Num(2.0, None),
-            Expr::Num(3.0, Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
+            Expr::Num(1.into(), Some(Span::new(Loc::new(1, 1), Loc::new(1, 2)))),
+            Expr::Num(2.into(), None),
+            Expr::Num(3.into(), Some(Span::new(Loc::new(1, 5), Loc::new(1, 6))))
         );
         assert_eq!(args.span(), Some(Span::new(Loc::new(1, 1), Loc::new(1, 6))));
     }
@@ -212,6 +267,17 @@ mod tests {
         assert_eq!(iter.next(), Some(&num(2)));
         assert_eq!(iter.next(), Some(&num(3)));
         assert_eq!(iter.next(), None);
+        assert_eq!(iter.tail(), None);
+
+        // (1 2 . 3)
+        let list = cons(1, cons(2, 3));
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&num(1)));
+        assert_eq!(iter.tail(), None);
+        assert_eq!(iter.next(), Some(&num(2)));
+        assert_eq!(iter.tail(), Some(&num(3)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(list.len(), 2);
     }
 
     #[test]
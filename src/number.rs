@@ -0,0 +1,689 @@
+mod bigint;
+mod rational;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+pub use bigint::{BigInt, ParseBigIntError};
+pub use rational::{ParseRationalError, Rational};
+
+/// The enum that represents all numeric values in the Rusche language.
+///
+/// Exact integers are stored as `i64` and automatically promoted to [`BigInt`] when
+/// an operation overflows. Exact non-integers are stored as [`Rational`]. Inexact numbers
+/// are stored as `f64`. An operation between exact numbers yields an exact number, while
+/// any inexact operand makes the result inexact.
+#[derive(Clone, Debug)]
+pub enum Number {
+    /// An exact integer that fits in 64 bits.
+    Int(i64),
+
+    /// An exact integer that does not fit in 64 bits. It is boxed, as are rationals, to
+    /// keep numbers and expressions small.
+    BigInt(Box<BigInt>),
+
+    /// An exact ratio of two integers, which is never an integer itself.
+    Rational(Box<Rational>),
+
+    /// An inexact real number.
+    Real(f64),
+}
+
+impl Number {
+    /// Returns `true` if the number is exact.
+    pub fn is_exact(&self) -> bool {
+        !matches!(self, Number::Real(_))
+    }
+
+    /// Returns `true` if the number is an integer, regardless of its exactness.
+    pub fn is_integer(&self) -> bool {
+        match self {
+            Number::Int(_) | Number::BigInt(_) => true,
+            Number::Rational(_) => false,
+            Number::Real(value) => value.is_finite() && value.fract() == 0.0,
+        }
+    }
+
+    /// Returns `true` if the number is zero.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Number::Int(value) => *value == 0,
+            Number::BigInt(value) => value.is_zero(),
+            Number::Rational(_) => false,
+            Number::Real(value) => *value == 0.0,
+        }
+    }
+
+    /// Converts the number into an inexact `f64` value.
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Number::Int(value) => *value as f64,
+            Number::BigInt(value) => value.to_f64(),
+            Number::Rational(value) => value.to_f64(),
+            Number::Real(value) => *value,
+        }
+    }
+
+    /// Returns the number as an `i64` if it is an exact integer that fits in 64 bits.
+    pub fn to_i64(&self) -> Option<i64> {
+        match self {
+            Number::Int(value) => Some(*value),
+            Number::BigInt(value) => value.to_i64(),
+            Number::Rational(_) | Number::Real(_) => None,
+        }
+    }
+
+    /// Converts the number into an inexact number.
+    pub fn to_inexact(&self) -> Number {
+        Number::Real(self.to_f64())
+    }
+
+    /// Converts the number into an exact number of exactly the same value.
+    ///
+    /// Returns `None` if the number is an infinity or NaN.
+    pub fn to_exact(&self) -> Option<Number> {
+        match self {
+            Number::Real(value) => Rational::from_f64(*value).map(Number::from),
+            exact => Some(exact.clone()),
+        }
+    }
+
+    fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Number::Int(value) => Some(BigInt::from(*value)),
+            Number::BigInt(value) => Some(BigInt::clone(value)),
+            Number::Rational(_) | Number::Real(_) => None,
+        }
+    }
+
+    fn to_rational(&self) -> Option<Rational> {
+        match self {
+            Number::Rational(value) => Some(Rational::clone(value)),
+            _ => self.to_bigint().map(Rational::from),
+        }
+    }
+
+    fn to_exact_rational(&self) -> Option<Rational> {
+        match self {
+            Number::Real(value) => Rational::from_f64(*value),
+            _ => self.to_rational(),
+        }
+    }
+
+    /// Returns the numerator of the number in lowest terms. The result is inexact if
+    /// the number is inexact.
+    ///
+    /// Returns `None` if the number is an infinity or NaN.
+    pub fn numerator(&self) -> Option<Number> {
+        match self {
+            Number::Int(_) | Number::BigInt(_) => Some(self.clone()),
+            Number::Rational(value) => Some(value.numer().clone().into()),
+            Number::Real(_) => self.to_exact()?.numerator().map(|num| num.to_inexact()),
+        }
+    }
+
+    /// Returns the positive denominator of the number in lowest terms. The result is
+    /// inexact if the number is inexact.
+    ///
+    /// Returns `None` if the number is an infinity or NaN.
+    pub fn denominator(&self) -> Option<Number> {
+        match self {
+            Number::Int(_) | Number::BigInt(_) => Some(Number::Int(1)),
+            Number::Rational(value) => Some(value.denom().clone().into()),
+            Number::Real(_) => self.to_exact()?.denominator().map(|num| num.to_inexact()),
+        }
+    }
+
+    /// Returns the largest integer not greater than the number.
+    pub fn floor(&self) -> Number {
+        match self {
+            Number::Rational(value) => value.floor().into(),
+            Number::Real(value) => Number::Real(value.floor()),
+            integer => integer.clone(),
+        }
+    }
+
+    /// Returns the smallest integer not less than the number.
+    pub fn ceiling(&self) -> Number {
+        match self {
+            Number::Rational(value) => value.ceiling().into(),
+            Number::Real(value) => Number::Real(value.ceil()),
+            integer => integer.clone(),
+        }
+    }
+
+    /// Returns the integer closest to the number whose absolute value is not larger than
+    /// the absolute value of the number.
+    pub fn truncate(&self) -> Number {
+        match self {
+            Number::Rational(value) => value.truncate().into(),
+            Number::Real(value) => Number::Real(value.trunc()),
+            integer => integer.clone(),
+        }
+    }
+
+    /// Returns the closest integer to the number, rounding to even when the number is
+    /// halfway between two integers.
+    pub fn round(&self) -> Number {
+        match self {
+            Number::Rational(value) => value.round().into(),
+            Number::Real(value) => Number::Real(value.round_ties_even()),
+            integer => integer.clone(),
+        }
+    }
+
+    /// Divides the number by `rhs`.
+    ///
+    /// The quotient of two exact numbers is exact. Returns `None` on an exact division
+    /// by zero.
+    pub fn checked_div(self, rhs: Number) -> Option<Number> {
+        match (self.to_rational(), rhs.to_rational()) {
+            (Some(lhs), Some(rhs)) => lhs.checked_div(&rhs).map(Number::from),
+            _ => Some(Number::Real(self.to_f64() / rhs.to_f64())),
+        }
+    }
+
+    /// Computes the remainder of a truncating division of the number by `rhs`.
+    /// The result has the same sign as `self`.
+    ///
+    /// Returns `None` on an exact division by zero.
+    pub fn checked_rem(self, rhs: Number) -> Option<Number> {
+        match (&self, &rhs) {
+            (Number::Int(lhs), Number::Int(rhs)) => match lhs.checked_rem(*rhs) {
+                Some(remainder) => Some(Number::Int(remainder)),
+                None if *rhs == 0 => None,
+                None => Some(Number::Int(0)), // i64::MIN % -1
+            },
+            _ => match (self.to_rational(), rhs.to_rational()) {
+                (Some(lhs), Some(rhs)) => {
+                    let quotient = Rational::from(lhs.checked_div(&rhs)?.truncate());
+                    Some((&lhs - &(&rhs * &quotient)).into())
+                }
+                _ => Some(Number::Real(self.to_f64() % rhs.to_f64())),
+            },
+        }
+    }
+
+    /// Formats the number in the given radix, which must be between 2 and 36.
+    ///
+    /// Returns `None` for an inexact number in a radix other than 10.
+    pub fn to_str_radix(&self, radix: u32) -> Option<String> {
+        match self {
+            _ if radix == 10 => Some(self.to_string()),
+            Number::Int(value) => Some(BigInt::from(*value).to_str_radix(radix)),
+            Number::BigInt(value) => Some(value.to_str_radix(radix)),
+            Number::Rational(value) => Some(format!(
+                "{}/{}",
+                value.numer().to_str_radix(radix),
+                value.denom().to_str_radix(radix)
+            )),
+            Number::Real(_) => None,
+        }
+    }
+
+    /// Parses a number in the given radix, which must be between 2 and 36.
+    ///
+    /// Only exact integers and fractions can be parsed in a radix other than 10.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseNumberError> {
+        if radix == 10 {
+            return text.parse();
+        }
+
+        let parse_int =
+            |text: &str| BigInt::from_str_radix(text, radix).map_err(|_| ParseNumberError);
+        match text.split_once('/') {
+            Some((_, denom)) if denom.starts_with(['+', '-']) => Err(ParseNumberError),
+            Some((numer, denom)) => Rational::new(parse_int(numer)?, parse_int(denom)?)
+                .map(Number::from)
+                .ok_or(ParseNumberError),
+            None => parse_int(text).map(Number::from),
+        }
+    }
+}
+
+fn exact_operation(
+    lhs: Number,
+    rhs: Number,
+    int_op: fn(i64, i64) -> Option<i64>,
+    bigint_op: fn(&BigInt, &BigInt) -> BigInt,
+    rational_op: fn(&Rational, &Rational) -> Rational,
+    real_op: fn(f64, f64) -> f64,
+) -> Number {
+    if let (Number::Int(lhs), Number::Int(rhs)) = (&lhs, &rhs) {
+        if let Some(result) = int_op(*lhs, *rhs) {
+            return Number::Int(result);
+        }
+    }
+    if let (Some(lhs), Some(rhs)) = (lhs.to_bigint(), rhs.to_bigint()) {
+        return bigint_op(&lhs, &rhs).into();
+    }
+    match (lhs.to_rational(), rhs.to_rational()) {
+        (Some(lhs), Some(rhs)) => rational_op(&lhs, &rhs).into(),
+        _ => Number::Real(real_op(lhs.to_f64(), rhs.to_f64())),
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, rhs: Number) -> Number {
+        exact_operation(
+            self,
+            rhs,
+            i64::checked_add,
+            |a, b| a + b,
+            |a, b| a + b,
+            |a, b| a + b,
+        )
+    }
+}
+
+impl Sub for Number {
+    type Output = Number;
+
+    fn sub(self, rhs: Number) -> Number {
+        exact_operation(
+            self,
+            rhs,
+            i64::checked_sub,
+            |a, b| a - b,
+            |a, b| a - b,
+            |a, b| a - b,
+        )
+    }
+}
+
+impl Mul for Number {
+    type Output = Number;
+
+    fn mul(self, rhs: Number) -> Number {
+        exact_operation(
+            self,
+            rhs,
+            i64::checked_mul,
+            |a, b| a * b,
+            |a, b| a * b,
+            |a, b| a * b,
+        )
+    }
+}
+
+impl Neg for Number {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        Number::Int(0) - self
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Returns `true` if `value` converts to `f64` without rounding.
+fn is_exact_in_f64(value: i64) -> bool {
+    value.unsigned_abs() <= 1 << f64::MANTISSA_DIGITS
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(lhs), Number::Int(rhs)) => lhs.partial_cmp(rhs),
+            (Number::Real(lhs), Number::Real(rhs)) => lhs.partial_cmp(rhs),
+            (Number::Real(lhs), Number::Int(rhs)) if is_exact_in_f64(*rhs) => {
+                lhs.partial_cmp(&(*rhs as f64))
+            }
+            (Number::Int(lhs), Number::Real(rhs)) if is_exact_in_f64(*lhs) => {
+                (*lhs as f64).partial_cmp(rhs)
+            }
+            // An infinity is beyond any exact number, and NaN is not comparable.
+            (Number::Real(lhs), _) if !lhs.is_finite() => lhs.partial_cmp(&0.0),
+            (_, Number::Real(rhs)) if !rhs.is_finite() => 0.0.partial_cmp(rhs),
+            // Otherwise, compare exactly, so that distinct large integers are not rounded
+            // to the same `f64`.
+            _ => self
+                .to_exact_rational()
+                .partial_cmp(&other.to_exact_rational()),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Int(value) => write!(f, "{}", value),
+            Number::BigInt(value) => write!(f, "{}", value),
+            Number::Rational(value) => write!(f, "{}", value),
+            Number::Real(value) if value.is_nan() => write!(f, "+nan.0"),
+            Number::Real(value) if value.is_infinite() => {
+                write!(f, "{}inf.0", if *value > 0.0 { "+" } else { "-" })
+            }
+            Number::Real(value) if value.fract() == 0.0 => write!(f, "{}.0", value),
+            Number::Real(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The error returned when parsing a [`Number`] from a string fails.
+#[derive(Debug, PartialEq)]
+pub struct ParseNumberError;
+
+impl FromStr for Number {
+    type Err = ParseNumberError;
+
+    /// Parses a number. Integer and fraction literals such as `1/3` produce exact numbers,
+    /// while literals with a decimal point or an exponent produce inexact numbers, as do
+    /// `+inf.0`, `-inf.0` and `+nan.0`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = text.parse::<i64>() {
+            Ok(Number::Int(value))
+        } else if let Ok(value) = text.parse::<BigInt>() {
+            Ok(Number::from(value))
+        } else if let Ok(value) = text.parse::<Rational>() {
+            Ok(Number::from(value))
+        } else {
+            match text {
+                "+inf.0" => Ok(Number::Real(f64::INFINITY)),
+                "-inf.0" => Ok(Number::Real(f64::NEG_INFINITY)),
+                "+nan.0" => Ok(Number::Real(f64::NAN)),
+                // Rust also parses spellings such as `inf` and `NaN`, which are symbols in Scheme.
+                _ if text
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E')) =>
+                {
+                    text.parse::<f64>()
+                        .map(Number::Real)
+                        .map_err(|_| ParseNumberError)
+                }
+                _ => Err(ParseNumberError),
+            }
+        }
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Number::Int(value as i64)
+    }
+}
+
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        Number::Int(value)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Number::Real(value)
+    }
+}
+
+impl From<BigInt> for Number {
+    fn from(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(value) => Number::Int(value),
+            None => Number::BigInt(Box::new(value)),
+        }
+    }
+}
+
+impl From<Rational> for Number {
+    fn from(value: Rational) -> Self {
+        if value.is_integer() {
+            Number::from(value.numer().clone())
+        } else {
+            Number::Rational(Box::new(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Number {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(parse("42"), Number::Int(42)));
+        assert!(matches!(parse("-42"), Number::Int(-42)));
+        assert!(matches!(parse("4.2"), Number::Real(_)));
+        assert!(matches!(parse("1e3"), Number::Real(_)));
+        assert!(matches!(
+            parse("123456789012345678901234567890"),
+            Number::BigInt(_)
+        ));
+        assert!("abc".parse::<Number>().is_err());
+
+        assert_eq!(parse("+inf.0").to_f64(), f64::INFINITY);
+        assert_eq!(parse("-inf.0").to_f64(), f64::NEG_INFINITY);
+        assert!(parse("+nan.0").to_f64().is_nan());
+        for text in [
+            "inf",
+            "-inf",
+            "infinity",
+            "+Infinity",
+            "nan",
+            "NaN",
+            "inf.0",
+            "1e",
+        ] {
+            assert!(text.parse::<Number>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_radix() {
+        assert_eq!(Number::from(255).to_str_radix(16), Some("ff".to_owned()));
+        assert_eq!(Number::from(-5).to_str_radix(2), Some("-101".to_owned()));
+        assert_eq!(parse("-1/3").to_str_radix(3), Some("-1/10".to_owned()));
+        assert_eq!(parse("1.5").to_str_radix(10), Some("1.5".to_owned()));
+        assert_eq!(parse("1.5").to_str_radix(2), None);
+        assert_eq!(
+            parse("123456789012345678901234567890").to_str_radix(16),
+            Some("18ee90ff6c373e0ee4e3f0ad2".to_owned())
+        );
+
+        assert_eq!(Number::from_str_radix("ff", 16), Ok(Number::from(255)));
+        assert_eq!(Number::from_str_radix("-101", 2), Ok(Number::from(-5)));
+        assert_eq!(Number::from_str_radix("1/10", 3), Ok(parse("1/3")));
+        assert_eq!(
+            Number::from_str_radix("18EE90FF6C373E0EE4E3F0AD2", 16),
+            Ok(parse("123456789012345678901234567890"))
+        );
+        assert_eq!(Number::from_str_radix("1.5", 10), Ok(parse("1.5")));
+        assert!(Number::from_str_radix("12", 2).is_err());
+        assert!(Number::from_str_radix("", 16).is_err());
+        assert!(Number::from_str_radix("1/-1", 16).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Number::from(42).to_string(), "42");
+        assert_eq!(Number::from(-1.5).to_string(), "-1.5");
+        assert_eq!(Number::from(2.0).to_string(), "2.0");
+        assert_eq!(Number::from(f64::INFINITY).to_string(), "+inf.0");
+        assert_eq!(Number::from(f64::NEG_INFINITY).to_string(), "-inf.0");
+        assert_eq!(Number::from(f64::NAN).to_string(), "+nan.0");
+        assert_eq!(
+            parse("123456789012345678901234567890").to_string(),
+            "123456789012345678901234567890"
+        );
+    }
+
+    #[test]
+    fn test_exactness() {
+        assert!(Number::from(1).is_exact());
+        assert!(parse("123456789012345678901234567890").is_exact());
+        assert!(!Number::from(1.0).is_exact());
+
+        assert!(Number::from(1).is_integer());
+        assert!(Number::from(1.0).is_integer());
+        assert!(!Number::from(1.5).is_integer());
+
+        assert!(!Number::from(1).to_inexact().is_exact());
+        assert!(Number::from(1.0).to_exact().unwrap().is_exact());
+        assert_eq!(
+            Number::from(1e20).to_exact().unwrap().to_string(),
+            "100000000000000000000"
+        );
+        assert_eq!(Number::from(1.5).to_exact().unwrap().to_string(), "3/2");
+        assert!(Number::from(f64::INFINITY).to_exact().is_none());
+
+        assert!(parse("1/3").is_exact());
+        assert!(!parse("1/3").is_integer());
+        assert!(matches!(parse("4/2"), Number::Int(2)));
+    }
+
+    #[test]
+    fn test_promotion() {
+        let max = Number::from(i64::MAX);
+        let sum = max.clone() + Number::from(1);
+        assert!(matches!(sum, Number::BigInt(_)));
+        assert_eq!(sum.to_string(), "9223372036854775808");
+
+        // demoted back to i64 when it fits again
+        assert!(matches!(sum - Number::from(1), Number::Int(i64::MAX)));
+
+        let product = max.clone() * max;
+        assert_eq!(
+            product.to_string(),
+            "85070591730234615847396907784232501249"
+        );
+
+        assert_eq!((-Number::from(i64::MIN)).to_string(), "9223372036854775808");
+    }
+
+    #[test]
+    fn test_mixed_exactness() {
+        let sum = Number::from(1) + Number::from(0.5);
+        assert!(!sum.is_exact());
+        assert_eq!(sum, Number::from(1.5));
+
+        let product = Number::from(2) * Number::from(2.0);
+        assert!(!product.is_exact());
+        assert_eq!(product.to_string(), "4.0");
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let quotient = Number::from(6).checked_div(Number::from(3)).unwrap();
+        assert!(quotient.is_exact());
+        assert_eq!(quotient, Number::from(2));
+
+        let quotient = Number::from(1).checked_div(Number::from(3)).unwrap();
+        assert!(quotient.is_exact());
+        assert_eq!(quotient.to_string(), "1/3");
+
+        let quotient = Number::from(1).checked_div(Number::from(2.0)).unwrap();
+        assert!(!quotient.is_exact());
+        assert_eq!(quotient, Number::from(0.5));
+
+        assert!(Number::from(1).checked_div(Number::from(0)).is_none());
+        assert_eq!(
+            Number::from(1.0).checked_div(Number::from(0)),
+            Some(Number::from(f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_checked_rem() {
+        assert_eq!(
+            Number::from(11).checked_rem(Number::from(4)),
+            Some(Number::from(3))
+        );
+        assert_eq!(
+            Number::from(-11).checked_rem(Number::from(4)),
+            Some(Number::from(-3))
+        );
+        assert_eq!(
+            Number::from(i64::MIN).checked_rem(Number::from(-1)),
+            Some(Number::from(0))
+        );
+        assert_eq!(
+            parse("100000000000000000001").checked_rem(Number::from(10)),
+            Some(Number::from(1))
+        );
+        assert_eq!(
+            parse("7/2").checked_rem(Number::from(2)),
+            Some(parse("3/2"))
+        );
+        assert!(Number::from(1).checked_rem(Number::from(0)).is_none());
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert_eq!(Number::from(1), Number::from(1.0));
+        assert!(Number::from(1) < Number::from(1.5));
+        assert!(parse("100000000000000000000") > Number::from(i64::MAX));
+        assert!(parse("-100000000000000000000") < Number::from(i64::MIN));
+        assert_ne!(Number::from(f64::NAN), Number::from(f64::NAN));
+        assert!(parse("1/3") < parse("1/2"));
+        assert!(parse("1/3") < Number::from(0.5));
+        assert!(parse("-1/3") > Number::from(-1));
+        assert_eq!(parse("1/2"), Number::from(0.5));
+
+        // large integers are compared exactly
+        assert_ne!(parse("9007199254740992.0"), parse("9007199254740993"));
+        assert!(parse("9007199254740992.0") < parse("9007199254740993"));
+        assert_eq!(parse("9007199254740992.0"), parse("9007199254740992"));
+        assert!(parse("1e20") < parse("100000000000000000001"));
+        assert!(Number::from(f64::INFINITY) > parse("1000000000000000000000"));
+        assert!(Number::from(f64::NEG_INFINITY) < Number::from(i64::MIN));
+        assert!(Number::from(f64::NAN)
+            .partial_cmp(&parse("1000000000000000000000"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let sum = parse("1/3") + parse("2/3");
+        assert!(matches!(sum, Number::Int(1)));
+
+        let sum = parse("1/10") + parse("2/10");
+        assert_eq!(sum.to_string(), "3/10");
+
+        let product = parse("2/3") * Number::from(3);
+        assert!(matches!(product, Number::Int(2)));
+
+        let mixed = parse("1/2") + Number::from(0.25);
+        assert!(!mixed.is_exact());
+        assert_eq!(mixed.to_string(), "0.75");
+
+        assert_eq!((-parse("1/3")).to_string(), "-1/3");
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(parse("7/2").floor().to_string(), "3");
+        assert_eq!(parse("-7/2").floor().to_string(), "-4");
+        assert_eq!(parse("-7/2").ceiling().to_string(), "-3");
+        assert_eq!(parse("-7/2").truncate().to_string(), "-3");
+        assert_eq!(parse("7/2").round().to_string(), "4");
+        assert_eq!(parse("5/2").round().to_string(), "2");
+        assert_eq!(Number::from(2.5).round().to_string(), "2.0");
+        assert_eq!(Number::from(-2.7).floor().to_string(), "-3.0");
+        assert_eq!(Number::from(5).round().to_string(), "5");
+    }
+
+    #[test]
+    fn test_numerator_denominator() {
+        assert_eq!(parse("6/4").numerator(), Some(Number::from(3)));
+        assert_eq!(parse("6/4").denominator(), Some(Number::from(2)));
+        assert_eq!(parse("-6/4").numerator(), Some(Number::from(-3)));
+        assert_eq!(Number::from(5).denominator(), Some(Number::from(1)));
+
+        let denominator = Number::from(0.5).denominator().unwrap();
+        assert!(!denominator.is_exact());
+        assert_eq!(denominator, Number::from(2));
+
+        assert!(Number::from(f64::NAN).numerator().is_none());
+    }
+}
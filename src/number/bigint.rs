@@ -0,0 +1,607 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+const LIMB_BITS: u32 = 32;
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+/// An arbitrary-precision signed integer.
+///
+/// The magnitude is stored as little-endian base 2^32 limbs without trailing zero limbs,
+/// so that every value has exactly one representation. Zero is never negative.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_parts(false, Vec::new())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    /// Returns the value as `i64` if it fits, `None` otherwise.
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let value = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0_u64, |acc, limb| (acc << LIMB_BITS) | *limb as u64);
+        if self.negative {
+            if value <= i64::MAX as u64 {
+                Some(-(value as i64))
+            } else if value == i64::MIN.unsigned_abs() {
+                Some(i64::MIN)
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(value).ok()
+        }
+    }
+
+    /// Returns the nearest `f64` value.
+    pub fn to_f64(&self) -> f64 {
+        let value = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0_f64, |acc, limb| acc * 4294967296.0 + *limb as f64);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Returns `2^exponent`.
+    pub fn power_of_two(exponent: u32) -> Self {
+        let mut magnitude = vec![0_u32; (exponent / LIMB_BITS) as usize];
+        magnitude.push(1 << (exponent % LIMB_BITS));
+        Self::from_parts(false, magnitude)
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    /// Returns the greatest common divisor of the two values, which is never negative.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while let Some((_, remainder)) = a.div_rem(&b) {
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    /// Performs a truncating division, returning the quotient and the remainder.
+    /// The remainder has the same sign as the dividend.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> Option<(BigInt, BigInt)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = mag_div_rem(&self.magnitude, &rhs.magnitude);
+        Some((
+            Self::from_parts(self.negative != rhs.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        ))
+    }
+
+    /// Formats the integer in the given radix, which must be between 2 and 36.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        if self.is_zero() {
+            return "0".to_owned();
+        }
+
+        let mut digits = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = mag_div_rem_small(&magnitude, radix);
+            digits.extend(char::from_digit(remainder, radix));
+            magnitude = quotient;
+        }
+        if self.negative {
+            digits.push('-');
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// Parses an integer in the given radix, which must be between 2 and 36.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() {
+            return Err(ParseBigIntError);
+        }
+
+        let mut magnitude = Vec::new();
+        for ch in digits.chars() {
+            let digit = ch.to_digit(radix).ok_or(ParseBigIntError)?;
+            mag_mul_small_add(&mut magnitude, radix, digit);
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        Self::from_parts(
+            value < 0,
+            vec![magnitude as u32, (magnitude >> LIMB_BITS) as u32],
+        )
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => mag_cmp(&self.magnitude, &other.magnitude),
+            (true, true) => mag_cmp(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, mag_add(&self.magnitude, &rhs.magnitude));
+        }
+        match mag_cmp(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, mag_sub(&rhs.magnitude, &self.magnitude))
+            }
+            _ => BigInt::from_parts(self.negative, mag_sub(&self.magnitude, &rhs.magnitude)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &(-rhs.clone())
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mag_mul(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = mag_div_rem_small(&magnitude, DECIMAL_CHUNK);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        if let Some(first) = chunks.next() {
+            write!(f, "{}", first)?;
+        }
+        for chunk in chunks {
+            write!(f, "{:0width$}", chunk, width = DECIMAL_CHUNK_DIGITS)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing a [`BigInt`] from a string fails.
+#[derive(Debug, PartialEq)]
+pub struct ParseBigIntError;
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|ch| ch.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut magnitude = Vec::new();
+        let head_len = match digits.len() % DECIMAL_CHUNK_DIGITS {
+            0 => DECIMAL_CHUNK_DIGITS,
+            len => len,
+        };
+        let (head, mut tail) = digits.split_at(head_len.min(digits.len()));
+        mag_mul_small_add(
+            &mut magnitude,
+            1,
+            head.parse().map_err(|_| ParseBigIntError)?,
+        );
+        while !tail.is_empty() {
+            let (chunk, rest) = tail.split_at(DECIMAL_CHUNK_DIGITS);
+            let chunk = chunk.parse().map_err(|_| ParseBigIntError)?;
+            mag_mul_small_add(&mut magnitude, DECIMAL_CHUNK, chunk);
+            tail = rest;
+        }
+
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+fn mag_cmp(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn mag_add(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (index, limb) in long.iter().enumerate() {
+        let sum = *limb as u64 + *short.get(index).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> LIMB_BITS;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Subtracts `rhs` from `lhs`. `lhs` must not be less than `rhs`.
+fn mag_sub(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    debug_assert!(mag_cmp(lhs, rhs) != Ordering::Less);
+
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0_i64;
+    for (index, limb) in lhs.iter().enumerate() {
+        let mut diff = *limb as i64 - *rhs.get(index).unwrap_or(&0) as i64 - borrow;
+        if diff < 0 {
+            diff += 1 << LIMB_BITS;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        result.push(diff as u32);
+    }
+    result
+}
+
+fn mag_mul(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = vec![0_u32; lhs.len() + rhs.len()];
+    for (i, a) in lhs.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, b) in rhs.iter().enumerate() {
+            let product = *a as u64 * *b as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> LIMB_BITS;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    result
+}
+
+/// Computes `magnitude * factor + addend` in place.
+fn mag_mul_small_add(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in magnitude.iter_mut() {
+        let value = *limb as u64 * factor as u64 + carry;
+        *limb = value as u32;
+        carry = value >> LIMB_BITS;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+fn mag_div_rem_small(lhs: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0_u32; lhs.len()];
+    let mut remainder = 0_u64;
+    for (index, limb) in lhs.iter().enumerate().rev() {
+        let value = (remainder << LIMB_BITS) | *limb as u64;
+        quotient[index] = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    (quotient, remainder as u32)
+}
+
+/// Divides `lhs` by `rhs` (which must not be zero) using Knuth's Algorithm D.
+fn mag_div_rem(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    debug_assert!(!rhs.is_empty());
+
+    if mag_cmp(lhs, rhs) == Ordering::Less {
+        return (Vec::new(), lhs.to_vec());
+    }
+    if rhs.len() == 1 {
+        let (quotient, remainder) = mag_div_rem_small(lhs, rhs[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    // Normalize so that the most significant limb of the divisor has its top bit set.
+    let shift = rhs[rhs.len() - 1].leading_zeros();
+    let divisor = shl_bits(rhs, shift);
+    let mut dividend = shl_bits(lhs, shift);
+    dividend.resize(lhs.len() + 1, 0);
+
+    let n = divisor.len();
+    let m = lhs.len() - n;
+    let base = 1_u128 << LIMB_BITS;
+    let mut quotient = vec![0_u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let numerator = ((dividend[j + n] as u128) << LIMB_BITS) | dividend[j + n - 1] as u128;
+        let mut qhat = numerator / divisor[n - 1] as u128;
+        let mut rhat = numerator % divisor[n - 1] as u128;
+        while qhat >= base
+            || qhat * divisor[n - 2] as u128 > ((rhat << LIMB_BITS) | dividend[j + n - 2] as u128)
+        {
+            qhat -= 1;
+            rhat += divisor[n - 1] as u128;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        // Multiply and subtract.
+        let mut borrow = 0_i128;
+        for i in 0..n {
+            let product = qhat * divisor[i] as u128;
+            let diff = dividend[i + j] as i128 - borrow - (product & 0xFFFF_FFFF) as i128;
+            dividend[i + j] = diff as u32;
+            borrow = (product >> LIMB_BITS) as i128 - (diff >> LIMB_BITS);
+        }
+        let diff = dividend[j + n] as i128 - borrow;
+        dividend[j + n] = diff as u32;
+
+        quotient[j] = qhat as u32;
+        if diff < 0 {
+            // We subtracted too much; add the divisor back.
+            quotient[j] = quotient[j].wrapping_sub(1);
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let sum = dividend[i + j] as u64 + divisor[i] as u64 + carry;
+                dividend[i + j] = sum as u32;
+                carry = sum >> LIMB_BITS;
+            }
+            dividend[j + n] = dividend[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    let mut remainder = shr_bits(&dividend[..n], shift);
+    while remainder.last() == Some(&0) {
+        remainder.pop();
+    }
+    (quotient, remainder)
+}
+
+fn shl_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0_u32;
+    for limb in limbs {
+        result.push((limb << shift) | carry);
+        carry = limb >> (LIMB_BITS - shift);
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+
+fn shr_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = vec![0_u32; limbs.len()];
+    for index in 0..limbs.len() {
+        let high = limbs
+            .get(index + 1)
+            .map_or(0, |limb| limb << (LIMB_BITS - shift));
+        result[index] = (limbs[index] >> shift) | high;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_power_of_two() {
+        assert_eq!(BigInt::power_of_two(0), big("1"));
+        assert_eq!(BigInt::power_of_two(31), big("2147483648"));
+        assert_eq!(BigInt::power_of_two(64), big("18446744073709551616"));
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(big("12").gcd(&big("18")), big("6"));
+        assert_eq!(big("-12").gcd(&big("18")), big("6"));
+        assert_eq!(big("0").gcd(&big("-5")), big("5"));
+        assert_eq!(
+            big("123456789012345678901234567890").gcd(&big("987654321098765432109876543210")),
+            big("9000000000900000000090")
+        );
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(big("0").to_string(), "0");
+        assert_eq!(big("-0").to_string(), "0");
+        assert_eq!(big("+42").to_string(), "42");
+        assert_eq!(big("-42").to_string(), "-42");
+        assert_eq!(big("1000000000").to_string(), "1000000000");
+        assert_eq!(
+            big("123456789012345678901234567890").to_string(),
+            "123456789012345678901234567890"
+        );
+        assert_eq!(big("-000000000000000000001").to_string(), "-1");
+
+        assert!("".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("1.5".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn test_to_i64() {
+        assert_eq!(BigInt::from(i64::MAX).to_i64(), Some(i64::MAX));
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(BigInt::from(-1).to_i64(), Some(-1));
+        assert_eq!(big("9223372036854775808").to_i64(), None);
+        assert_eq!(big("-9223372036854775809").to_i64(), None);
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(BigInt::from(-12345).to_f64(), -12345.0);
+        assert_eq!(big("18446744073709551616").to_f64(), 18446744073709551616.0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = big("99999999999999999999");
+        let b = big("-12345678901234567890");
+
+        assert_eq!((&a + &b).to_string(), "87654321098765432109");
+        assert_eq!((&b + &a).to_string(), "87654321098765432109");
+        assert_eq!((&a - &b).to_string(), "112345678901234567889");
+        assert_eq!((&b - &a).to_string(), "-112345678901234567889");
+        assert_eq!(
+            (&a * &b).to_string(),
+            "-1234567890123456788987654321098765432110"
+        );
+        assert_eq!((&a - &a), BigInt::zero());
+        assert!(!(&a - &a).is_negative());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let check = |lhs: &str, rhs: &str, quotient: &str, remainder: &str| {
+            let (q, r) = big(lhs).div_rem(&big(rhs)).unwrap();
+            assert_eq!(q.to_string(), quotient);
+            assert_eq!(r.to_string(), remainder);
+        };
+
+        check("7", "2", "3", "1");
+        check("-7", "2", "-3", "-1");
+        check("7", "-2", "-3", "1");
+        check("1", "12345678901234567890", "0", "1");
+        check(
+            "1234567890123456788987654321098765432110",
+            "99999999999999999999",
+            "12345678901234567890",
+            "0",
+        );
+        check(
+            "340282366920938463463374607431768211455",
+            "18446744073709551617",
+            "18446744073709551615",
+            "0",
+        );
+        check(
+            "987654321987654321987654321987654321",
+            "123456789123456789",
+            "8000000072900000671",
+            "48148902048148902",
+        );
+
+        assert_eq!(big("1").div_rem(&BigInt::zero()), None);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(big("-100000000000000000000") < big("-1"));
+        assert!(big("-1") < big("0"));
+        assert!(big("100000000000000000000") > big("99999999999999999999"));
+        assert_eq!(big("5").cmp(&BigInt::from(5)), Ordering::Equal);
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use super::bigint::BigInt;
+
+/// An exact ratio of two integers.
+///
+/// The value is always kept in lowest terms with a positive denominator, so that every
+/// value has exactly one representation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl Rational {
+    /// Creates `numer/denom` reduced to lowest terms.
+    ///
+    /// Returns `None` if `denom` is zero.
+    pub fn new(numer: BigInt, denom: BigInt) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+
+        let gcd = numer.gcd(&denom);
+        let (numer, _) = numer.div_rem(&gcd)?;
+        let (denom, _) = denom.div_rem(&gcd)?;
+
+        if denom.is_negative() {
+            Some(Self {
+                numer: -numer,
+                denom: -denom,
+            })
+        } else {
+            Some(Self { numer, denom })
+        }
+    }
+
+    /// Converts a finite `f64` into the rational number of exactly the same value.
+    ///
+    /// Returns `None` for infinities and NaN.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i64;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074) // subnormal
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        let mantissa = BigInt::from(if value < 0.0 { -mantissa } else { mantissa });
+        if exponent >= 0 {
+            let numer = &mantissa * &BigInt::power_of_two(exponent as u32);
+            Some(Self::from(numer))
+        } else {
+            Self::new(mantissa, BigInt::power_of_two(exponent.unsigned_abs()))
+        }
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &BigInt {
+        &self.denom
+    }
+
+    /// Returns `true` if the denominator is one.
+    pub fn is_integer(&self) -> bool {
+        self.denom == BigInt::from(1)
+    }
+
+    /// Returns the nearest `f64` value.
+    pub fn to_f64(&self) -> f64 {
+        self.numer.to_f64() / self.denom.to_f64()
+    }
+
+    /// Divides the number by `rhs`.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Rational) -> Option<Rational> {
+        Self::new(&self.numer * &rhs.denom, &self.denom * &rhs.numer)
+    }
+
+    /// Returns the largest integer not greater than the number.
+    pub fn floor(&self) -> BigInt {
+        let (quotient, remainder) = self.div_rem();
+        if remainder.is_negative() {
+            &quotient - &BigInt::from(1)
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the smallest integer not less than the number.
+    pub fn ceiling(&self) -> BigInt {
+        let (quotient, remainder) = self.div_rem();
+        if !remainder.is_negative() && !remainder.is_zero() {
+            &quotient + &BigInt::from(1)
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the integer closest to the number, discarding the fractional part.
+    pub fn truncate(&self) -> BigInt {
+        self.div_rem().0
+    }
+
+    /// Returns the closest integer to the number, rounding to even when the number is
+    /// halfway between two integers.
+    pub fn round(&self) -> BigInt {
+        let floor = self.floor();
+        // 2 * (self - floor) compared with 1, scaled by the denominator
+        let twice_fraction = &(&self.numer - &(&floor * &self.denom)) * &BigInt::from(2);
+        match twice_fraction.cmp(&self.denom) {
+            Ordering::Less => floor,
+            Ordering::Equal if floor.is_even() => floor,
+            _ => &floor + &BigInt::from(1),
+        }
+    }
+
+    fn div_rem(&self) -> (BigInt, BigInt) {
+        self.numer
+            .div_rem(&self.denom)
+            .expect("denominator is never zero")
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Self {
+            numer: value,
+            denom: BigInt::from(1),
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are always positive, so cross-multiplying preserves the order.
+        (&self.numer * &other.denom).cmp(&(&other.numer * &self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Rational {
+        let numer = &(&self.numer * &rhs.denom) + &(&rhs.numer * &self.denom);
+        Rational::new(numer, &self.denom * &rhs.denom).expect("denominator is never zero")
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Rational {
+        let numer = &(&self.numer * &rhs.denom) - &(&rhs.numer * &self.denom);
+        Rational::new(numer, &self.denom * &rhs.denom).expect("denominator is never zero")
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Rational {
+        Rational::new(&self.numer * &rhs.numer, &self.denom * &rhs.denom)
+            .expect("denominator is never zero")
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// The error returned when parsing a [`Rational`] from a string fails.
+#[derive(Debug, PartialEq)]
+pub struct ParseRationalError;
+
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    /// Parses a rational number in the form of `numer/denom`, e.g. `-1/3`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (numer, denom) = text.split_once('/').ok_or(ParseRationalError)?;
+        if denom.starts_with(['+', '-']) {
+            return Err(ParseRationalError);
+        }
+
+        let numer = numer.parse::<BigInt>().map_err(|_| ParseRationalError)?;
+        let denom = denom.parse::<BigInt>().map_err(|_| ParseRationalError)?;
+
+        Self::new(numer, denom).ok_or(ParseRationalError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(text: &str) -> Rational {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(ratio("1/3").to_string(), "1/3");
+        assert_eq!(ratio("-2/6").to_string(), "-1/3");
+        assert_eq!(ratio("+4/2").to_string(), "2");
+        assert_eq!(ratio("0/5").to_string(), "0");
+        assert!("1/0".parse::<Rational>().is_err());
+        assert!("1/-3".parse::<Rational>().is_err());
+        assert!("1/3/4".parse::<Rational>().is_err());
+        assert!("1.5/3".parse::<Rational>().is_err());
+        assert!("13".parse::<Rational>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(&ratio("1/3") + &ratio("1/6"), ratio("1/2"));
+        assert_eq!(&ratio("1/3") - &ratio("1/2"), ratio("-1/6"));
+        assert_eq!(&ratio("2/3") * &ratio("3/4"), ratio("1/2"));
+        assert_eq!(
+            ratio("2/3").checked_div(&ratio("-4/3")),
+            Some(ratio("-1/2"))
+        );
+        assert_eq!(ratio("2/3").checked_div(&ratio("0/1")), None);
+        assert_eq!(-ratio("2/3"), ratio("-2/3"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(ratio("1/3") < ratio("1/2"));
+        assert!(ratio("-1/2") < ratio("-1/3"));
+        assert_eq!(ratio("2/4").cmp(&ratio("1/2")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let cases = [
+            // value, floor, ceiling, truncate, round
+            ("7/2", "3", "4", "3", "4"),
+            ("5/2", "2", "3", "2", "2"),
+            ("-7/2", "-4", "-3", "-3", "-4"),
+            ("-5/2", "-3", "-2", "-2", "-2"),
+            ("1/3", "0", "1", "0", "0"),
+            ("-5/3", "-2", "-1", "-1", "-2"),
+            ("4/1", "4", "4", "4", "4"),
+        ];
+        for (value, floor, ceiling, truncate, round) in cases {
+            let value = ratio(value);
+            assert_eq!(value.floor().to_string(), floor, "floor {value}");
+            assert_eq!(value.ceiling().to_string(), ceiling, "ceiling {value}");
+            assert_eq!(value.truncate().to_string(), truncate, "truncate {value}");
+            assert_eq!(value.round().to_string(), round, "round {value}");
+        }
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(Rational::from_f64(0.5), Some(ratio("1/2")));
+        assert_eq!(Rational::from_f64(-0.75), Some(ratio("-3/4")));
+        assert_eq!(Rational::from_f64(3.0), Some(ratio("3/1")));
+        assert_eq!(Rational::from_f64(0.0), Some(ratio("0/1")));
+        assert_eq!(
+            Rational::from_f64(0.1).map(|value| value.to_string()),
+            Some("3602879701896397/36028797018963968".to_owned())
+        );
+        assert_eq!(Rational::from_f64(f64::INFINITY), None);
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+    }
+}
@@ -0,0 +1,135 @@
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use crate::eval::{EvalContext, EvalError, EvalResult};
+use crate::expr::Expr;
+use crate::list::List;
+use crate::proc::Proc;
+
+/// A parameter object created by `make-parameter`. See [`Proc::Parameter`].
+///
+/// Calling a parameter object returns its current value, which is the value bound by
+/// the innermost active `parameterize`, or its initial value if there is none.
+/// Bindings are kept in the [`EvalContext`], so they are visible to all procedures called
+/// while they are active, regardless of where the procedures were defined.
+///
+/// [`Proc::Parameter`]: crate::proc::Proc::Parameter
+#[derive(Clone, Debug)]
+pub struct Parameter {
+    inner: Rc<ParameterInner>,
+}
+
+#[derive(Debug)]
+struct ParameterInner {
+    value: Expr,
+    converter: Option<Proc>,
+}
+
+impl Parameter {
+    /// Creates a parameter object with the given initial value and no converter.
+    pub fn new(value: Expr) -> Self {
+        Self {
+            inner: Rc::new(ParameterInner {
+                value,
+                converter: None,
+            }),
+        }
+    }
+
+    /// Creates a parameter object with a converter, which is applied to the initial value
+    /// and to every value bound by `parameterize`.
+    pub fn with_converter(
+        value: Expr,
+        converter: Proc,
+        context: &EvalContext,
+    ) -> Result<Self, EvalError> {
+        let value = converter.apply([value], context)?;
+        Ok(Self {
+            inner: Rc::new(ParameterInner {
+                value,
+                converter: Some(converter),
+            }),
+        })
+    }
+
+    /// Returns the initial value of the parameter object.
+    pub fn initial_value(&self) -> &Expr {
+        &self.inner.value
+    }
+
+    pub fn converter(&self) -> Option<&Proc> {
+        self.inner.converter.as_ref()
+    }
+
+    /// Returns the current value of the parameter object in `context`.
+    pub fn value(&self, context: &EvalContext) -> Expr {
+        context
+            .shared
+            .parameter_bindings
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(parameter, _)| parameter == self)
+            .map_or_else(|| self.inner.value.clone(), |(_, value)| value.clone())
+    }
+
+    /// Applies the converter, if any, to a value to be bound to the parameter object.
+    pub(crate) fn convert(&self, value: Expr, context: &EvalContext) -> EvalResult {
+        match &self.inner.converter {
+            Some(converter) => converter.apply([value], context),
+            None => Ok(value),
+        }
+    }
+
+    pub(crate) fn invoke(&self, proc_name: &str, args: &List, context: &EvalContext) -> EvalResult {
+        if !args.is_nil() {
+            return Err(EvalError::from(format!("{proc_name}: expects 0 args.")));
+        }
+        Ok(self.value(context))
+    }
+}
+
+impl PartialEq for Parameter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Hash for Parameter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.inner).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+
+    #[test]
+    fn test_value() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let parameter = Parameter::new(1.into());
+        let other = Parameter::new(2.into());
+        assert_eq!(parameter.value(context), 1.into());
+
+        let result = context.parameterize(vec![(parameter.clone(), 10.into())], |context| {
+            assert_eq!(parameter.value(context), 10.into());
+            assert_eq!(other.value(context), 2.into());
+            context.parameterize(vec![(parameter.clone(), 20.into())], |context| {
+                Ok(parameter.value(context))
+            })
+        });
+        assert_eq!(result, Ok(20.into()));
+        assert_eq!(parameter.value(context), 1.into());
+
+        // bindings are removed on errors too
+        let result = context.parameterize(vec![(parameter.clone(), 10.into())], |_| {
+            Err(EvalError::from("error".to_owned()))
+        });
+        assert!(result.is_err());
+        assert_eq!(parameter.value(context), 1.into());
+    }
+}
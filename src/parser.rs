@@ -1,9 +1,12 @@
 use crate::expr::{intern, Expr};
+use crate::hash_table::HashTable;
 use crate::list::{cons, List};
 use crate::macros::list;
 use crate::span::Span;
 use crate::token::Token;
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
@@ -67,7 +70,14 @@ impl Parser {
             };
 
             let mut expr = match token {
+                Token::Dot(_) if self.contexts.is_empty() => {
+                    return Err(ParseError::UnexpectedToken(token));
+                }
                 Token::OpenParen(_)
+                | Token::OpenVector(_)
+                | Token::OpenBytevector(_)
+                | Token::OpenHashTable(_)
+                | Token::Dot(_)
                 | Token::Quote(_)
                 | Token::Quasiquote(_)
                 | Token::Unquote(_)
@@ -76,9 +86,11 @@ impl Parser {
                     continue;
                 }
                 Token::CloseParen(_) => self.end_list(token)?,
-                Token::Sym(name, span) => Expr::Sym(name, Some(span)),
+                Token::Bool(value, span) => Expr::Bool(value, Some(span)),
+                Token::Sym(name, span) => Expr::Sym(name.into(), Some(span)),
                 Token::Str(text, span) => Expr::Str(text, Some(span)),
                 Token::Num(value, span) => Expr::Num(value, Some(span)),
+                Token::Char(ch, span) => Expr::Char(ch, Some(span)),
             };
 
             loop {
@@ -117,18 +129,60 @@ impl Parser {
 
     fn end_list(&mut self, token: Token) -> Result<Expr, ParseError> {
         let mut list = List::Nil;
+        let mut dotted_tail = None;
         while let Some(context) = self.contexts.pop() {
             if get_quote_name(context.token.as_ref()).is_some() {
                 break;
             }
+            if let Some(dot_token @ Token::Dot(_)) = context.token {
+                // `.` must be followed by exactly one expression and preceded by at least one.
+                match context.car {
+                    Some(tail) if list.is_nil() && dotted_tail.is_none() => {
+                        dotted_tail = Some((dot_token, tail));
+                        continue;
+                    }
+                    Some(_) => return Err(ParseError::UnexpectedToken(dot_token)),
+                    None => match dotted_tail {
+                        Some((dot_token, _)) => return Err(ParseError::UnexpectedToken(dot_token)),
+                        None => return Err(ParseError::UnexpectedToken(token)),
+                    },
+                }
+            }
             if let Some(car) = context.car {
-                list = cons(car, list);
+                list = match dotted_tail.take() {
+                    Some((_, tail)) => cons(car, tail),
+                    None => cons(car, list),
+                };
+            }
+            if let Some((dot_token, _)) = dotted_tail {
+                return Err(ParseError::UnexpectedToken(dot_token)); // `(. 1)`
             }
             if let Some(begin_token) = context.token {
                 let expr_span = Span {
                     begin: begin_token.span().begin,
                     end: token.span().end,
                 };
+                if let Token::OpenVector(_) = begin_token {
+                    if !list.is_proper() {
+                        return Err(ParseError::UnexpectedToken(token));
+                    }
+                    let items = list.iter().cloned().collect();
+                    return Ok(Expr::Vector(Rc::new(RefCell::new(items)), Some(expr_span)));
+                }
+                if let Token::OpenBytevector(_) = begin_token {
+                    let bytes = make_bytes(&list).ok_or(ParseError::UnexpectedToken(token))?;
+                    return Ok(Expr::Bytevector(
+                        Rc::new(RefCell::new(bytes)),
+                        Some(expr_span),
+                    ));
+                }
+                if let Token::OpenHashTable(_) = begin_token {
+                    let table = make_hash_table(&list).ok_or(ParseError::UnexpectedToken(token))?;
+                    return Ok(Expr::HashTable(
+                        Rc::new(RefCell::new(table)),
+                        Some(expr_span),
+                    ));
+                }
                 return Ok(Expr::List(list, Some(expr_span)));
             }
         }
@@ -140,8 +194,9 @@ impl Parser {
 
         // Find the first token that started the current expression
         for context in self.contexts.iter().rev() {
-            if let Some(token) = context.token.as_ref() {
-                return token.clone();
+            match context.token.as_ref() {
+                Some(Token::Dot(_)) | None => continue,
+                Some(token) => return token.clone(),
             }
         }
         panic!("No token found for the current expression");
@@ -154,6 +209,39 @@ impl Default for Parser {
     }
 }
 
+/// Collects the elements of a `#u8(...)` literal, each of which must be an exact integer
+/// between 0 and 255.
+fn make_bytes(items: &List) -> Option<Vec<u8>> {
+    if !items.is_proper() {
+        return None;
+    }
+    items
+        .iter()
+        .map(|item| match item {
+            Expr::Num(value, _) => value.to_i64().and_then(|value| u8::try_from(value).ok()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Builds a hash table from the entries of a `#hash(...)` literal, each of which must be a
+/// pair whose car is a valid key.
+fn make_hash_table(entries: &List) -> Option<HashTable> {
+    if !entries.is_proper() {
+        return None;
+    }
+    let mut table = HashTable::new();
+    for entry in entries.iter() {
+        let Expr::List(List::Cons(cons), _) = entry else {
+            return None;
+        };
+        table
+            .insert(cons.car.as_ref().clone(), cons.cdr.as_ref().clone())
+            .ok()?;
+    }
+    Some(table)
+}
+
 fn get_quote_name(token: Option<&Token>) -> Option<&'static str> {
     use crate::builtin::quote::{QUASIQUOTE, QUOTE, UNQUOTE, UNQUOTE_SPLICING};
     match token {
@@ -168,6 +256,7 @@ fn get_quote_name(token: Option<&Token>) -> Option<&'static str> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lexer::tokenize;
     use crate::macros::tok;
 
     #[test]
@@ -186,6 +275,125 @@ mod tests {
         assert_eq!(parsed_expr, expected_expr);
     }
 
+    #[test]
+    fn test_parser_dotted_pair() {
+        let parse = |src: &str| Parser::with_tokens(tokenize(src, None).unwrap()).parse();
+
+        // (1 . 2)
+        let parsed_expr = parse("(1 . 2)").unwrap().unwrap();
+        assert_eq!(parsed_expr, cons(1, 2).into());
+
+        // (1 2 . 3)
+        let parsed_expr = parse("(1 2 . 3)").unwrap().unwrap();
+        assert_eq!(parsed_expr, cons(1, cons(2, 3)).into());
+
+        // (1 . (2 3)) => (1 2 3)
+        let parsed_expr = parse("(1 . (2 3))").unwrap().unwrap();
+        assert_eq!(parsed_expr, list!(1, 2, 3).into());
+
+        // '(1 . 2)
+        let parsed_expr = parse("'(1 . 2)").unwrap().unwrap();
+        assert_eq!(parsed_expr, list!(intern("quote"), cons(1, 2)).into());
+
+        // (1 . '2)
+        let parsed_expr = parse("(1 . '2)").unwrap().unwrap();
+        assert_eq!(parsed_expr, cons(1, list!(intern("quote"), 2)).into());
+
+        assert_eq!(
+            parse("(1 . 2 3)"),
+            Err(ParseError::UnexpectedToken(tok!(Dot)))
+        );
+        assert_eq!(parse("(. 2)"), Err(ParseError::UnexpectedToken(tok!(Dot))));
+        assert_eq!(
+            parse("(1 . . 2)"),
+            Err(ParseError::UnexpectedToken(tok!(Dot)))
+        );
+        assert_eq!(
+            parse("(1 .)"),
+            Err(ParseError::UnexpectedToken(tok!(CloseParen)))
+        );
+        assert_eq!(parse("."), Err(ParseError::UnexpectedToken(tok!(Dot))));
+        assert_eq!(
+            parse("(1 . 2"),
+            Err(ParseError::IncompleteExpr(tok!(OpenParen)))
+        );
+    }
+
+    #[test]
+    fn test_parser_vector() {
+        let parse = |src: &str| Parser::with_tokens(tokenize(src, None).unwrap()).parse();
+        let vector = |items: Vec<Expr>| Expr::Vector(Rc::new(RefCell::new(items)), None);
+
+        // #(1 (2 3) #())
+        let parsed_expr = parse("#(1 (2 3) #())").unwrap().unwrap();
+        let expected_expr = vector(vec![1.into(), list!(2, 3).into(), vector(vec![])]);
+        assert_eq!(parsed_expr, expected_expr);
+
+        // '#(a)
+        let parsed_expr = parse("'#(a)").unwrap().unwrap();
+        let expected_expr = list!(intern("quote"), vector(vec![intern("a")])).into();
+        assert_eq!(parsed_expr, expected_expr);
+
+        assert_eq!(
+            parse("#(1 . 2)"),
+            Err(ParseError::UnexpectedToken(tok!(CloseParen)))
+        );
+        assert_eq!(
+            parse("#(1 2"),
+            Err(ParseError::IncompleteExpr(tok!(OpenVector)))
+        );
+    }
+
+    #[test]
+    fn test_parser_bytevector() {
+        let parse = |src: &str| Parser::with_tokens(tokenize(src, None).unwrap()).parse();
+        let bytevector = |bytes: Vec<u8>| Expr::Bytevector(Rc::new(RefCell::new(bytes)), None);
+
+        assert_eq!(parse("#u8(0 1 255)"), Ok(Some(bytevector(vec![0, 1, 255]))));
+        assert_eq!(parse("#u8()"), Ok(Some(bytevector(vec![]))));
+
+        for src in ["#u8(256)", "#u8(-1)", "#u8(1.0)", "#u8(a)", "#u8(1 . 2)"] {
+            assert_eq!(
+                parse(src),
+                Err(ParseError::UnexpectedToken(tok!(CloseParen))),
+                "{src}"
+            );
+        }
+        assert_eq!(
+            parse("#u8(1"),
+            Err(ParseError::IncompleteExpr(tok!(OpenBytevector)))
+        );
+    }
+
+    #[test]
+    fn test_parser_hash_table() {
+        let parse = |src: &str| Parser::with_tokens(tokenize(src, None).unwrap()).parse();
+
+        let parsed_expr = parse(r#"#hash((a . 1) ("b" 2 3) (a . 4))"#)
+            .unwrap()
+            .unwrap();
+        let Expr::HashTable(table, _) = &parsed_expr else {
+            panic!("expected a hash table: {parsed_expr}");
+        };
+        assert_eq!(table.borrow().len(), 2);
+        assert_eq!(table.borrow().get(&intern("a")), Some(&4.into()));
+        assert_eq!(table.borrow().get(&"b".into()), Some(&list!(2, 3).into()));
+
+        assert_eq!(parse("#hash()").unwrap().unwrap().to_string(), "#hash()");
+        assert_eq!(
+            parse("#hash(1)"),
+            Err(ParseError::UnexpectedToken(tok!(CloseParen)))
+        );
+        assert_eq!(
+            parse("#hash(((1) . 1))"),
+            Err(ParseError::UnexpectedToken(tok!(CloseParen)))
+        );
+        assert_eq!(
+            parse("#hash((1 . 2)"),
+            Err(ParseError::IncompleteExpr(tok!(OpenHashTable)))
+        );
+    }
+
     #[test]
     fn test_parser_reset() {
         let mut parser = Parser::default();
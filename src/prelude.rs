@@ -4,11 +4,7 @@ use crate::{
     parser::{ParseError, Parser},
 };
 
-const PRELUDE_SYMBOLS: [&str; 4] = [
-    // #t
-    "(define #t 1)",
-    // #f
-    "(define #f '())",
+const PRELUDE_SYMBOLS: [&str; 1] = [
     // numeric operation aliases
     r#"
     (define + num-add)
@@ -16,60 +12,21 @@ const PRELUDE_SYMBOLS: [&str; 4] = [
     (define * num-multiply)
     (define / num-divide)
     (define % num-modulo)
+    (define = num-equal)
     (define < num-less)
     (define > num-greater)
     "#,
-    // = (eq? alias)
-    "(define = eq?)",
 ];
 
-const PRELUDE_MACROS: [&str; 6] = [
-    // begin
-    r#"
-    (defmacro begin (*exprs)
-        `(let () ,@exprs))
-    "#,
-    // cond
-    r#"
-    (defmacro (cond *clauses)
-        (if (null? clauses)
-            #f                                          ; No more clauses, return #f by default
-            (let ((clause (car clauses)))
-                (if (eq? (car clause) 'else)            ; If the first clause is 'else'
-                    `(,@(cdr clause))                   ; Expand to the else expression(s)
-                    `(if ,(car clause)                  ; Otherwise, expand to an if expression
-                        (begin ,@(cdr clause))          ; If condition is true, evaluate the body
-                        (cond ,@(cdr clauses)))))))     ; Else, recursively process remaining clauses
-    "#,
+const PRELUDE_MACROS: [&str; 1] = [
     // defun
     r#"
     (defmacro defun (name args *body)
         `(define ,name (lambda ,args ,@body)))
     "#,
-    // let
-    r#"
-    (defmacro let (bindings *body)
-        `((lambda ,(map car bindings) ; Get the list of variable names
-             ,@body)                  ; The body of the let becomes the lambda's body
-          ,@(map cdar bindings)))     ; Apply the values to the lambda
-    "#,
-    // list
-    r#"
-    (defmacro list (*args)
-        (if (null? args)
-            '()
-            `(cons ,(car args) (list ,@(cdr args)))))
-    "#,
-    // while
-    r#"
-    (defmacro while (condition *body)
-        `(define (loop)
-            (if ,condition (begin ,@body (loop))))
-        (loop))
-    "#,
 ];
 
-const PRELUDE_FUNCS: [&str; 11] = [
+const PRELUDE_FUNCS: [&str; 12] = [
     // caar, cadr, cdar, cdar
     r#"
     (define (caar lst) (car (car lst)))
@@ -77,11 +34,9 @@ const PRELUDE_FUNCS: [&str; 11] = [
     (define (cdar lst) (car (cdr lst)))
     (define (cddr lst) (cdr (cdr lst)))
     "#,
-    // and, or, not
+    // not
     r#"
-    (define (and x y) (if x (if y #t #f) #f))
-    (define (or  x y) (if x #t (if y #t #f)))
-    (define (not x  ) (if x #f #t))
+    (define (not x) (if x #f #t))
     "#,
     // null?
     r#"
@@ -112,15 +67,28 @@ const PRELUDE_FUNCS: [&str; 11] = [
         (cond ((and (null? lst1) (null? lst2)) '())
               ((and (not (atom? lst1)) (not (atom? lst2)))
                (cons (cons (car lst1) (cons (car lst2) '()))
-                     (pair (cdr lst1) (cdr lst2))))))
+                     (pair (cdr lst1) (cdr lst2))))
+              (#t '())))
+    "#,
+    // member, memv, memq
+    r#"
+    (define (member obj lst #!optional (compare equal?))
+        (cond
+            ((not (pair? lst)) #f)                    ; If the list is empty, return #f
+            ((compare obj (car lst)) lst)             ; If the first element matches, return the sublist
+            (#t (member obj (cdr lst) compare))))     ; Otherwise, recursively search the rest of the list
+    (define (memv obj lst) (member obj lst eqv?))
+    (define (memq obj lst) (member obj lst eq?))
     "#,
-    // assoc
+    // assoc, assv, assq
     r#"
-    (define (assoc key lst)
+    (define (assoc key lst #!optional (compare equal?))
         (cond
-            ((null? lst) #f)                       ; If the list is empty, return #f
-            ((eq? (car (car lst)) key) (car lst))  ; If the car of the first element matches the key, return the pair
-            (#t (assoc key (cdr lst)))))           ; Otherwise, recursively search the rest of the list
+            ((not (pair? lst)) #f)                    ; If the list is empty, return #f
+            ((compare key (car (car lst))) (car lst)) ; If the car of the first element matches the key, return the pair
+            (#t (assoc key (cdr lst) compare))))      ; Otherwise, recursively search the rest of the list
+    (define (assv key lst) (assoc key lst eqv?))
+    (define (assq key lst) (assoc key lst eq?))
     "#,
     // subst
     r#"
@@ -144,6 +112,94 @@ const PRELUDE_FUNCS: [&str; 11] = [
     "#,
 ];
 
+const PRELUDE_STREAMS: [&str; 4] = [
+    // stream primitives, where a stream is a promise of either '() or a pair of
+    // a promise of the first element and the rest of the stream
+    r#"
+    (define stream-null (delay '()))
+    (define (stream? obj) (promise? obj))
+    (define (stream-null? obj) (and (promise? obj) (null? (force obj))))
+    (define (stream-pair? obj) (and (promise? obj) (pair? (force obj))))
+    (defmacro stream-cons (obj strm)
+        `(delay (cons (delay ,obj) (delay-force ,strm))))
+    (define (stream-car strm)
+        (if (stream-pair? strm)
+            (force (car (force strm)))
+            (error "stream-car: not a stream pair" strm)))
+    (define (stream-cdr strm)
+        (if (stream-pair? strm)
+            (cdr (force strm))
+            (error "stream-cdr: not a stream pair" strm)))
+    "#,
+    // stream-lambda, define-stream and stream
+    r#"
+    (defmacro stream-lambda (formals *body)
+        `(lambda ,formals (delay-force (begin ,@body))))
+    (defmacro define-stream (spec *body)
+        `(define ,(car spec) (stream-lambda ,(cdr spec) ,@body)))
+    (defmacro stream (*objs)
+        (if (null? objs)
+            'stream-null
+            `(stream-cons ,(car objs) (stream ,@(cdr objs)))))
+    "#,
+    // conversions
+    r#"
+    (define (list->stream lst)
+        (if (null? lst)
+            stream-null
+            (stream-cons (car lst) (list->stream (cdr lst)))))
+    (define (stream->list strm #!optional n)   ; n is #f for the whole stream
+        (let collect ((strm strm) (n n) (acc '()))
+            (if (and (stream-pair? strm) (not (eq? n 0)))
+                (collect (stream-cdr strm) (and n (- n 1)) (cons (stream-car strm) acc))
+                (let unwind ((acc acc) (lst '()))
+                    (if (null? acc) lst (unwind (cdr acc) (cons (car acc) lst)))))))
+    "#,
+    // stream operations
+    r#"
+    (define-stream (stream-from first #!optional (step 1))
+        (stream-cons first (stream-from (+ first step) step)))
+    (define-stream (stream-range first past #!optional (step 1))
+        (if (if (< step 0) (> first past) (< first past))
+            (stream-cons first (stream-range (+ first step) past step))
+            stream-null))
+    (define-stream (stream-iterate proc base)
+        (stream-cons base (stream-iterate proc (proc base))))
+    (define-stream (stream-map proc strm)
+        (if (stream-pair? strm)
+            (stream-cons (proc (stream-car strm)) (stream-map proc (stream-cdr strm)))
+            stream-null))
+    (define-stream (stream-filter pred? strm)
+        (cond ((not (stream-pair? strm)) stream-null)
+              ((pred? (stream-car strm))
+               (stream-cons (stream-car strm) (stream-filter pred? (stream-cdr strm))))
+              (else (stream-filter pred? (stream-cdr strm)))))
+    (define-stream (stream-take n strm)
+        (if (and (> n 0) (stream-pair? strm))
+            (stream-cons (stream-car strm) (stream-take (- n 1) (stream-cdr strm)))
+            stream-null))
+    (define-stream (stream-drop n strm)
+        (if (and (> n 0) (stream-pair? strm))
+            (stream-drop (- n 1) (stream-cdr strm))
+            strm))
+    (define-stream (stream-append strm1 strm2)
+        (if (stream-pair? strm1)
+            (stream-cons (stream-car strm1) (stream-append (stream-cdr strm1) strm2))
+            strm2))
+    (define (stream-ref strm n)
+        (if (= n 0) (stream-car strm) (stream-ref (stream-cdr strm) (- n 1))))
+    (define (stream-fold proc base strm)
+        (if (stream-pair? strm)
+            (stream-fold proc (proc base (stream-car strm)) (stream-cdr strm))
+            base))
+    (define (stream-for-each proc strm)
+        (if (stream-pair? strm)
+            (begin
+                (proc (stream-car strm))
+                (stream-for-each proc (stream-cdr strm)))))
+    "#,
+];
+
 pub fn load_prelude(context: &EvalContext) {
     for src in PRELUDE_SYMBOLS {
         eval_src(src, context);
@@ -154,6 +210,9 @@ pub fn load_prelude(context: &EvalContext) {
     for src in PRELUDE_FUNCS {
         eval_src(src, context);
     }
+    for src in PRELUDE_STREAMS {
+        eval_src(src, context);
+    }
 }
 
 fn eval_src(src: &str, context: &EvalContext) {
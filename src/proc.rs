@@ -1,13 +1,62 @@
+use std::fmt;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::rc::Rc;
 
-use crate::eval::{eval, eval_tail, EvalContext, EvalError, EvalResult};
-use crate::expr::NIL;
-use crate::list::List;
+use crate::builtin::quote::QUOTE;
+use crate::continuation::Continuation;
+use crate::eval::{eval, eval_tail, eval_values, EvalContext, EvalError, EvalResult};
+use crate::expr::{intern, Expr, NIL};
+use crate::formal_args::FormalArgs;
+use crate::list::{cons, List};
+use crate::macros::list;
+use crate::parameter::Parameter;
+use crate::record::{RecordProc, RecordType};
+use crate::syntax_rules::SyntaxRules;
 
 /// The function signature for native procedures -- [`Proc::Native`].
 pub type NativeFunc = fn(proc_name: &str, args: &List, context: &EvalContext) -> EvalResult;
 
+/// The number of arguments that a procedure accepts. See [`Proc::arity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arity {
+    pub min: usize,
+    /// The maximum number of arguments, or `None` if there is no limit.
+    pub max: Option<usize>,
+}
+
+impl Arity {
+    pub fn exact(count: usize) -> Self {
+        Self::between(count, count)
+    }
+
+    pub fn at_least(min: usize) -> Self {
+        Self { min, max: None }
+    }
+
+    pub fn between(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max: Some(max),
+        }
+    }
+
+    /// Returns `true` if `count` arguments are accepted.
+    pub fn accepts(&self, count: usize) -> bool {
+        self.min <= count && self.max.is_none_or(|max| count <= max)
+    }
+}
+
+impl fmt::Display for Arity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args = |count: usize| if count == 1 { "arg" } else { "args" };
+        match self.max {
+            None => write!(f, "at least {} {}", self.min, args(self.min)),
+            Some(max) if max == self.min => write!(f, "{max} {}", args(max)),
+            Some(max) => write!(f, "{} to {max} {}", self.min, args(max)),
+        }
+    }
+}
+
 /// The enum that represents all procedure variants in the Rusche language.
 #[derive(Clone, Debug)]
 pub enum Proc {
@@ -15,8 +64,8 @@ pub enum Proc {
     /// Closures can be created by the `lambda` form.
     Closure {
         name: Option<String>,
-        formal_args: Vec<String>,
-        body: Box<List>,
+        formal_args: Rc<FormalArgs>,
+        body: Rc<List>,
         outer_context: EvalContext,
     },
 
@@ -25,12 +74,49 @@ pub enum Proc {
     /// Macros can be created by the `defmacro` form.
     Macro {
         name: Option<String>,
-        formal_args: Vec<String>,
-        body: Box<List>,
+        formal_args: Rc<FormalArgs>,
+        body: Rc<List>,
+    },
+
+    /// A procedure with several clauses of formal arguments and bodies, which calls
+    /// the first clause that accepts the number of arguments.
+    /// Case-lambdas can be created by the `case-lambda` form.
+    CaseLambda {
+        name: Option<String>,
+        clauses: Rc<[(FormalArgs, List)]>,
+        outer_context: EvalContext,
+    },
+
+    /// A native procedure that is implemented in Rust. The arity is checked before the
+    /// procedure is called. It is `None` for special forms, and for procedures registered
+    /// without declaring it.
+    Native {
+        name: String,
+        func: NativeFunc,
+        arity: Option<Arity>,
     },
 
-    /// A native procedure that is implemented in Rust.
-    Native { name: String, func: NativeFunc },
+    /// A procedure that constructs, tests or accesses records of a record type.
+    /// Record procedures are created by the `define-record-type` form.
+    Record {
+        name: String,
+        record_type: Rc<RecordType>,
+        op: RecordProc,
+    },
+
+    /// An escaping continuation captured by the `call/cc` form.
+    Continuation(Continuation),
+
+    /// A parameter object created by the `make-parameter` form, which returns its current
+    /// value when called.
+    Parameter(Parameter),
+
+    /// A hygienic macro created by the `syntax-rules` form, and usually bound to a name
+    /// by the `define-syntax` or `let-syntax` form.
+    SyntaxRules {
+        name: Option<String>,
+        rules: Rc<SyntaxRules>,
+    },
 }
 
 impl Proc {
@@ -42,10 +128,22 @@ impl Proc {
                 formal_args,
                 body,
                 outer_context,
-            } => eval_closure(
-                name.as_deref(),
-                formal_args,
-                body,
+            } => eval_args(args, context).and_then(|args| {
+                eval_closure(
+                    name.as_deref().unwrap_or("unnamed-closure"),
+                    formal_args,
+                    body,
+                    outer_context,
+                    args,
+                )
+            }),
+            Proc::CaseLambda {
+                name,
+                clauses,
+                outer_context,
+            } => eval_case_lambda(
+                name.as_deref().unwrap_or("unnamed-case-lambda"),
+                clauses,
                 outer_context,
                 args,
                 context,
@@ -55,12 +153,132 @@ impl Proc {
                 formal_args,
                 body,
             } => eval_macro(name.as_deref(), formal_args, body, args, context),
-            Proc::Native { name, func } => func(name, args, context),
+            Proc::Native {
+                name,
+                arity: Some(arity),
+                ..
+            } if !arity.accepts(args.len()) => Err(native_arity_error(name, arity, args.len())),
+            Proc::Native { name, func, .. } => func(name, args, context),
+            Proc::Record {
+                name,
+                record_type,
+                op,
+            } => op.invoke(name, record_type, args, context),
+            Proc::Continuation(continuation) => continuation.invoke("continuation", args, context),
+            Proc::Parameter(parameter) => parameter.invoke("parameter", args, context),
+            Proc::SyntaxRules { name, rules } => eval_syntax_rules(
+                name.as_deref().unwrap_or("unnamed-syntax"),
+                rules,
+                args,
+                context,
+            ),
         };
         context.pop_call();
         result
     }
 
+    /// Returns the number of arguments accepted by the procedure, or `None` if unknown.
+    pub fn arity(&self) -> Option<Arity> {
+        match self {
+            Proc::Closure { formal_args, .. } | Proc::Macro { formal_args, .. } => {
+                Some(formal_args.arity())
+            }
+            Proc::CaseLambda { clauses, .. } => clauses
+                .iter()
+                .map(|(formal_args, _)| formal_args.arity())
+                .reduce(|lhs, rhs| Arity {
+                    min: lhs.min.min(rhs.min),
+                    max: lhs.max.zip(rhs.max).map(|(lhs, rhs)| lhs.max(rhs)),
+                }),
+            Proc::Native { arity, .. } => *arity,
+            Proc::Record { op, .. } => Some(op.arity()),
+            Proc::Continuation(_) => Some(Arity::at_least(0)),
+            Proc::Parameter(_) => Some(Arity::exact(0)),
+            Proc::SyntaxRules { .. } => None,
+        }
+    }
+
+    /// Returns the expansion of a macro use with the given arguments, without evaluating
+    /// it, or `None` if the procedure is not a macro.
+    pub(crate) fn expand(
+        &self,
+        args: &List,
+        context: &EvalContext,
+    ) -> Result<Option<Expr>, EvalError> {
+        let expansion = match self {
+            Proc::Macro {
+                name,
+                formal_args,
+                body,
+            } => expand_macro(name.as_deref(), formal_args, body, args, context)?,
+            Proc::SyntaxRules { name, rules } => {
+                rules.expand(name.as_deref().unwrap_or("unnamed-syntax"), args)?
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(expansion))
+    }
+
+    /// Invokes the procedure, and then keeps invoking the tail calls it returns until
+    /// a value is produced.
+    pub(crate) fn call(&self, args: &List, context: &EvalContext) -> EvalResult {
+        let mut res = self.invoke(args, context)?;
+        while let Expr::TailCall {
+            proc,
+            args,
+            context,
+        } = &res
+        {
+            res = proc.invoke(args, context)?;
+        }
+        Ok(res)
+    }
+
+    /// Applies the procedure to already evaluated arguments.
+    ///
+    /// This is useful for native procedures that take a procedure as an argument, such as
+    /// `vector-map`. Each argument is quoted so that it is passed as is, without being
+    /// evaluated again.
+    pub fn apply<Iter>(&self, args: Iter, context: &EvalContext) -> EvalResult
+    where
+        Iter: IntoIterator<Item = Expr>,
+    {
+        let quoted_args: Vec<Expr> = args
+            .into_iter()
+            .map(|arg| list!(intern(QUOTE), arg).into())
+            .collect();
+        self.call(
+            &quoted_args
+                .into_iter()
+                .rev()
+                .fold(List::Nil, |cdr, car| cons(car, cdr)),
+            context,
+        )
+    }
+
+    /// Returns `true` if both are the same procedure in the sense of `eqv?`.
+    ///
+    /// Unlike `==`, which compares user-defined procedures by their definitions, two
+    /// procedures are only the same if they are created by the same evaluation of a
+    /// `lambda`, `case-lambda` or `defmacro` form.
+    pub fn is_same(&self, other: &Proc) -> bool {
+        match (self, other) {
+            (Proc::Closure { body: body1, .. }, Proc::Closure { body: body2, .. })
+            | (Proc::Macro { body: body1, .. }, Proc::Macro { body: body2, .. }) => {
+                Rc::ptr_eq(body1, body2)
+            }
+            (
+                Proc::CaseLambda {
+                    clauses: clauses1, ..
+                },
+                Proc::CaseLambda {
+                    clauses: clauses2, ..
+                },
+            ) => Rc::ptr_eq(clauses1, clauses2),
+            _ => self == other,
+        }
+    }
+
     pub(crate) fn badge(&self) -> String {
         match self {
             Proc::Closure { name, .. } => {
@@ -69,9 +287,20 @@ impl Proc {
             Proc::Macro { name, .. } => {
                 format!("proc/macro:{}", name.as_deref().unwrap_or("unnamed"),)
             }
+            Proc::CaseLambda { name, .. } => {
+                format!("proc/case-lambda:{}", name.as_deref().unwrap_or("unnamed"))
+            }
             Proc::Native { name, .. } => {
                 format!("proc/native:{}", name)
             }
+            Proc::Record { name, .. } => {
+                format!("proc/record:{}", name)
+            }
+            Proc::Continuation(_) => "proc/continuation".to_owned(),
+            Proc::Parameter(_) => "proc/parameter".to_owned(),
+            Proc::SyntaxRules { name, .. } => {
+                format!("proc/syntax:{}", name.as_deref().unwrap_or("unnamed"))
+            }
         }
     }
 
@@ -84,19 +313,45 @@ impl Proc {
                 outer_context,
                 ..
             } => {
-                formal_args.hash(&mut hasher);
+                formal_args.to_string().hash(&mut hasher);
                 body.to_string().hash(&mut hasher);
                 Rc::as_ptr(&outer_context.env).hash(&mut hasher);
             }
             Proc::Macro {
                 formal_args, body, ..
             } => {
-                formal_args.hash(&mut hasher);
+                formal_args.to_string().hash(&mut hasher);
                 body.to_string().hash(&mut hasher);
             }
+            Proc::CaseLambda {
+                clauses,
+                outer_context,
+                ..
+            } => {
+                for (formal_args, body) in clauses.iter() {
+                    formal_args.to_string().hash(&mut hasher);
+                    body.to_string().hash(&mut hasher);
+                }
+                Rc::as_ptr(&outer_context.env).hash(&mut hasher);
+            }
             Proc::Native { func, .. } => {
                 func.hash(&mut hasher);
             }
+            Proc::Record {
+                record_type, op, ..
+            } => {
+                Rc::as_ptr(record_type).hash(&mut hasher);
+                op.hash(&mut hasher);
+            }
+            Proc::Continuation(continuation) => {
+                continuation.hash(&mut hasher);
+            }
+            Proc::Parameter(parameter) => {
+                parameter.hash(&mut hasher);
+            }
+            Proc::SyntaxRules { rules, .. } => {
+                Rc::as_ptr(rules).hash(&mut hasher);
+            }
         }
 
         format!("{}:{:x}", self.badge(), hasher.finish())
@@ -125,123 +380,251 @@ impl PartialEq for Proc {
                     && body1 == body2
                     && Rc::ptr_eq(&outer_context1.env, &outer_context2.env)
             }
-            (lhs, rhs) => lhs == rhs,
+            (
+                Proc::Macro {
+                    name: name1,
+                    formal_args: formal_args1,
+                    body: body1,
+                },
+                Proc::Macro {
+                    name: name2,
+                    formal_args: formal_args2,
+                    body: body2,
+                },
+            ) => name1 == name2 && formal_args1 == formal_args2 && body1 == body2,
+            (
+                Proc::CaseLambda {
+                    name: name1,
+                    clauses: clauses1,
+                    outer_context: outer_context1,
+                },
+                Proc::CaseLambda {
+                    name: name2,
+                    clauses: clauses2,
+                    outer_context: outer_context2,
+                },
+            ) => {
+                name1 == name2
+                    && clauses1 == clauses2
+                    && Rc::ptr_eq(&outer_context1.env, &outer_context2.env)
+            }
+            (
+                Proc::Native {
+                    name: name1,
+                    func: func1,
+                    ..
+                },
+                Proc::Native {
+                    name: name2,
+                    func: func2,
+                    ..
+                },
+            ) => name1 == name2 && std::ptr::fn_addr_eq(*func1, *func2),
+            (
+                Proc::Record {
+                    name: name1,
+                    record_type: record_type1,
+                    op: op1,
+                },
+                Proc::Record {
+                    name: name2,
+                    record_type: record_type2,
+                    op: op2,
+                },
+            ) => name1 == name2 && Rc::ptr_eq(record_type1, record_type2) && op1 == op2,
+            (Proc::Continuation(continuation1), Proc::Continuation(continuation2)) => {
+                continuation1 == continuation2
+            }
+            (Proc::Parameter(parameter1), Proc::Parameter(parameter2)) => parameter1 == parameter2,
+            (
+                Proc::SyntaxRules {
+                    name: name1,
+                    rules: rules1,
+                },
+                Proc::SyntaxRules {
+                    name: name2,
+                    rules: rules2,
+                },
+            ) => name1 == name2 && Rc::ptr_eq(rules1, rules2),
+            _ => false,
         }
     }
 }
 
+// The functions called by `Proc::invoke` are kept out of it, as it is on the path of
+// every nested call and its stack frame would be as large as the largest of them.
+#[inline(never)]
+fn eval_args(args: &List, context: &EvalContext) -> Result<Vec<Expr>, EvalError> {
+    args.iter().map(|expr| eval(expr, context)).collect()
+}
+
+#[inline(never)]
 fn eval_closure(
-    closure_name: Option<&str>,
-    formal_args: &[String],
+    closure_name: &str,
+    formal_args: &FormalArgs,
     body: &List,
     outer_context: &EvalContext,
-    actual_args: &List,
-    context: &EvalContext,
+    args: Vec<Expr>,
 ) -> EvalResult {
-    let closure_name = closure_name.unwrap_or("unnamed-closure");
     let closure_context = EvalContext::derive_from(outer_context);
-    let mut formal_args = formal_args.iter();
-    let mut actual_args = actual_args.iter();
-
-    loop {
-        if let Some(formal_arg) = formal_args.next() {
-            if let Some(name) = get_variadic_args_name(formal_arg) {
-                closure_context.env.define(name, actual_args);
-                break;
-            }
-
-            let expr = actual_args
-                .next()
-                .ok_or(EvalError::from(format!("{}: too few args", closure_name)))?;
-
-            closure_context.env.define(formal_arg, eval(expr, context)?);
-        } else {
-            if actual_args.next().is_none() {
-                break;
-            }
-            return Err(EvalError::from(format!("{}: too many args", closure_name)));
-        }
-    }
+    formal_args.bind(closure_name, args, &closure_context)?;
 
     let mut iter = body.iter().peekable();
     while let Some(expr) = iter.next() {
         if iter.peek().is_none() {
             return eval_tail(expr, &closure_context);
         } else {
-            eval(expr, &closure_context)?;
+            eval_values(expr, &closure_context)?;
         }
     }
     Ok(NIL)
 }
 
+#[inline(never)]
+fn eval_case_lambda(
+    name: &str,
+    clauses: &[(FormalArgs, List)],
+    outer_context: &EvalContext,
+    args: &List,
+    context: &EvalContext,
+) -> EvalResult {
+    let args = eval_args(args, context)?;
+    let Some((formal_args, body)) = clauses
+        .iter()
+        .find(|(formal_args, _)| formal_args.arity().accepts(args.len()))
+    else {
+        return Err(EvalError::from(format!(
+            "{name}: no clause accepts {} args.",
+            args.len()
+        )));
+    };
+    eval_closure(name, formal_args, body, outer_context, args)
+}
+
+#[inline(never)]
 fn eval_macro(
     macro_name: Option<&str>,
-    formal_args: &[String],
+    formal_args: &FormalArgs,
     body: &List,
     actual_args: &List,
     context: &EvalContext,
 ) -> EvalResult {
-    let macro_name = macro_name.unwrap_or("unnamed-macro");
-    let macro_context = EvalContext::derive_from(context);
-    let mut formal_args = formal_args.iter();
-    let mut actual_args = actual_args.iter();
-
-    loop {
-        if let Some(formal_arg) = formal_args.next() {
-            if let Some(name) = get_variadic_args_name(formal_arg) {
-                macro_context.env.define(name, actual_args);
-                break;
-            }
+    let expanded_expr = expand_macro(macro_name, formal_args, body, actual_args, context)?;
+    eval_tail(&expanded_expr, context)
+}
 
-            let expr = actual_args
-                .next()
-                .ok_or(EvalError::from(format!("{}: too few args", macro_name)))?;
+#[cold]
+#[inline(never)]
+fn native_arity_error(name: &str, arity: &Arity, arg_count: usize) -> EvalError {
+    EvalError::from(format!("{name}: expects {arity}, but got {arg_count}."))
+}
 
-            macro_context.env.define(formal_arg, expr.clone());
-        } else {
-            if actual_args.next().is_none() {
-                break;
-            }
-            return Err(EvalError::from(format!("{}: too many args", macro_name)));
-        }
-    }
+#[inline(never)]
+fn eval_syntax_rules(
+    name: &str,
+    rules: &SyntaxRules,
+    args: &List,
+    context: &EvalContext,
+) -> EvalResult {
+    eval_tail(&rules.expand(name, args)?, context)
+}
 
-    let mut iter = body.iter().peekable();
-    while let Some(expr) = iter.next() {
-        let expanded_expr = eval(expr, &macro_context)?;
-        if iter.peek().is_none() {
-            return eval_tail(&expanded_expr, context);
-        } else {
-            eval(&expanded_expr, context)?;
-        }
-    }
-    Ok(NIL)
+/// Returns the expansion of a macro without evaluating it. The body is evaluated like that
+/// of a procedure, and the value of its last expression is the expansion.
+fn expand_macro(
+    macro_name: Option<&str>,
+    formal_args: &FormalArgs,
+    body: &List,
+    actual_args: &List,
+    context: &EvalContext,
+) -> EvalResult {
+    let macro_context = bind_macro_args(macro_name, formal_args, actual_args, context)?;
+    body.iter()
+        .try_fold(NIL, |_, expr| eval(expr, &macro_context))
 }
 
-/// Extracts the name of variadic arguments from the given name.
-///
-/// If the name starts with `*` and has more than one character,
-/// returns the rest of the name. Otherwise, returns `None`.
-///
-fn get_variadic_args_name(name: &str) -> Option<&str> {
-    if name.starts_with("*") && name.len() > 1 {
-        Some(&name[1..])
-    } else {
-        None
-    }
+fn bind_macro_args(
+    macro_name: Option<&str>,
+    formal_args: &FormalArgs,
+    actual_args: &List,
+    context: &EvalContext,
+) -> Result<EvalContext, EvalError> {
+    let macro_name = macro_name.unwrap_or("unnamed-macro");
+    let macro_context = EvalContext::derive_from(context);
+    formal_args.bind(
+        macro_name,
+        actual_args.iter().cloned().collect(),
+        &macro_context,
+    )?;
+    Ok(macro_context)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{eval::Evaluator, macros::list};
+    use crate::{eval::Evaluator, macros::list, symbol::Symbol};
+
+    #[test]
+    fn test_expand() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        let quoted = |expr: Expr| list!(intern(QUOTE), expr);
+        let macro_ = Proc::Macro {
+            name: Some("m".to_owned()),
+            formal_args: Rc::new(FormalArgs::new(vec![Symbol::new("x")])),
+            body: Rc::new(list!(quoted(1.into()), intern("x"))),
+        };
+        assert_eq!(
+            macro_.expand(&list!(intern("y")), context),
+            Ok(Some(intern("y")))
+        );
+        assert!(macro_.expand(&list!(), context).is_err());
+
+        let Some(Expr::Proc(add, _)) = context.env.lookup("num-add") else {
+            panic!("num-add is not defined");
+        };
+        assert_eq!(add.expand(&list!(1, 2), context), Ok(None));
+    }
+
+    #[test]
+    fn test_arity() {
+        assert!(Arity::exact(2).accepts(2));
+        assert!(!Arity::exact(2).accepts(3));
+        assert!(Arity::at_least(1).accepts(100));
+        assert!(!Arity::between(1, 2).accepts(0));
+
+        assert_eq!(Arity::exact(1).to_string(), "1 arg");
+        assert_eq!(Arity::between(1, 2).to_string(), "1 to 2 args");
+        assert_eq!(Arity::at_least(0).to_string(), "at least 0 args");
+
+        let evaluator = Evaluator::with_builtin();
+        let Some(Expr::Proc(cons, _)) = evaluator.context().env.lookup("cons") else {
+            panic!("cons is not defined");
+        };
+        assert_eq!(cons.arity(), Some(Arity::exact(2)));
+        let Some(Expr::Proc(define, _)) = evaluator.context().env.lookup("define") else {
+            panic!("define is not defined");
+        };
+        assert_eq!(define.arity(), None);
+    }
 
     #[test]
-    fn test_get_variadic_args_name() {
-        assert_eq!(get_variadic_args_name("args"), None);
-        assert_eq!(get_variadic_args_name("*args"), Some("args"));
-        assert_eq!(get_variadic_args_name("*a"), Some("a"));
-        assert_eq!(get_variadic_args_name("*"), None);
+    fn test_apply() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        let Some(Expr::Proc(add, _)) = context.env.lookup("num-add") else {
+            panic!("num-add is not defined");
+        };
+        assert_eq!(add.apply(vec![1.into(), 2.into()], context), Ok(3.into()));
+
+        // arguments are not evaluated again
+        let Some(Expr::Proc(car, _)) = context.env.lookup("car") else {
+            panic!("car is not defined");
+        };
+        let arg = list!(intern("a"), intern("b")).into();
+        assert_eq!(car.apply(vec![arg], context), Ok(intern("a")));
     }
 
     #[test]
@@ -251,48 +634,50 @@ mod tests {
 
         let closure = Proc::Closure {
             name: Some("closure".into()),
-            formal_args: vec!["a".into(), "b".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
 
         let closure_same = Proc::Closure {
             name: Some("closure".into()),
-            formal_args: vec!["a".into(), "b".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
         assert_eq!(closure, closure_same);
+        assert!(!closure.is_same(&closure_same));
+        assert!(closure.is_same(&closure.clone()));
 
         let closure_name_diff = Proc::Closure {
             name: None,
-            formal_args: vec!["a".into(), "b".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
         assert_ne!(closure, closure_name_diff);
 
         let closure_args_diff = Proc::Closure {
             name: None,
-            formal_args: vec!["a".into(), "b".into(), "c".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into(), "c".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
         assert_ne!(closure, closure_args_diff);
 
         let closure_body_diff = Proc::Closure {
             name: None,
-            formal_args: vec!["a".into(), "b".into(), "c".into()],
-            body: Box::new(list!(1, 2, 3, 4)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into(), "c".into()])),
+            body: Rc::new(list!(1, 2, 3, 4)),
             outer_context: context.clone(),
         };
         assert_ne!(closure, closure_body_diff);
 
         let closure_context_diff = Proc::Closure {
             name: None,
-            formal_args: vec!["a".into(), "b".into(), "c".into()],
-            body: Box::new(list!(1, 2, 3, 4)),
-            outer_context: EvalContext::derive_from(&context),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into(), "c".into()])),
+            body: Rc::new(list!(1, 2, 3, 4)),
+            outer_context: EvalContext::derive_from(context),
         };
         assert_ne!(closure, closure_context_diff);
     }
@@ -304,20 +689,20 @@ mod tests {
 
         let closure1 = Proc::Closure {
             name: Some("closure".into()),
-            formal_args: vec!["a".into(), "b".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
         let closure2 = Proc::Closure {
             name: Some("closure".into()),
-            formal_args: vec!["a".into(), "b".into()],
-            body: Box::new(list!(1, 2, 3)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into(), "b".into()])),
+            body: Rc::new(list!(1, 2, 3)),
             outer_context: context.clone(),
         };
         let closure3 = Proc::Closure {
             name: Some("closure".into()),
-            formal_args: vec!["a".into()],
-            body: Box::new(list!(1, 2)),
+            formal_args: Rc::new(FormalArgs::new(vec!["a".into()])),
+            body: Rc::new(list!(1, 2)),
             outer_context: context.clone(),
         };
         assert_eq!(closure1.fingerprint(), closure2.fingerprint());
@@ -333,20 +718,23 @@ mod tests {
         let native1 = Proc::Native {
             name: "native".into(),
             func: native_fn_1,
+            arity: None,
         };
         let native1_1 = Proc::Native {
             name: "native".into(),
             func: native_fn_1,
+            arity: None,
         };
         let native2 = Proc::Native {
             name: "native".into(),
             func: native_fn_2,
+            arity: None,
         };
         assert_eq!(native1.fingerprint(), native1_1.fingerprint());
         assert_ne!(native1.fingerprint(), native2.fingerprint());
 
         // code coverage workaround (#[coverage(off)] is unstable)
-        native_fn_1("", &list!(), &context).unwrap();
-        native_fn_2("", &list!(), &context).unwrap();
+        native_fn_1("", &list!(), context).unwrap();
+        native_fn_2("", &list!(), context).unwrap();
     }
 }
@@ -0,0 +1,132 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::eval::{eval, EvalContext, EvalError, EvalResult};
+use crate::expr::Expr;
+
+/// The state of a [`Promise`].
+#[derive(Clone, Debug)]
+pub enum PromiseState {
+    /// The promise has been forced, or was created by `make-promise`.
+    Done(Expr),
+
+    /// The promise has not been forced yet. `expr` is evaluated in `context` when forced.
+    /// If `is_lazy` is `true`, as with `delay-force`, `expr` must evaluate to another promise,
+    /// which is then forced in place of this one.
+    Delayed {
+        expr: Expr,
+        context: EvalContext,
+        is_lazy: bool,
+    },
+}
+
+/// A promise created by `delay`, `delay-force` or `make-promise`.
+///
+/// A promise is forced at most once, and remembers its value. When a `delay-force`
+/// promise is forced, it takes over the state of the promise its expression evaluates to,
+/// so that a chain of `delay-force` promises is forced iteratively, as described in R7RS.
+#[derive(Debug)]
+pub struct Promise {
+    state: RefCell<Rc<RefCell<PromiseState>>>,
+}
+
+impl Promise {
+    /// Creates a promise that is already forced to `value`.
+    pub fn done(value: Expr) -> Rc<Self> {
+        Self::with_state(PromiseState::Done(value))
+    }
+
+    pub(crate) fn delayed(expr: Expr, context: EvalContext, is_lazy: bool) -> Rc<Self> {
+        Self::with_state(PromiseState::Delayed {
+            expr,
+            context,
+            is_lazy,
+        })
+    }
+
+    fn with_state(state: PromiseState) -> Rc<Self> {
+        Rc::new(Self {
+            state: RefCell::new(Rc::new(RefCell::new(state))),
+        })
+    }
+
+    /// Returns a snapshot of the current state of the promise.
+    pub fn state(&self) -> PromiseState {
+        self.state.borrow().borrow().clone()
+    }
+
+    /// Returns `true` if the promise has been forced.
+    pub fn is_done(&self) -> bool {
+        matches!(self.state(), PromiseState::Done(_))
+    }
+
+    /// Forces the promise, and returns its value.
+    pub fn force(&self, proc_name: &str) -> EvalResult {
+        loop {
+            let (expr, context, is_lazy) = match self.state() {
+                PromiseState::Done(value) => return Ok(value),
+                PromiseState::Delayed {
+                    expr,
+                    context,
+                    is_lazy,
+                } => (expr, context, is_lazy),
+            };
+
+            let value = eval(&expr, &context)?;
+
+            // Forcing the expression may have forced this promise already, in which case
+            // the first value wins.
+            if self.is_done() {
+                continue;
+            }
+
+            if !is_lazy {
+                self.set_state(PromiseState::Done(value));
+                continue;
+            }
+
+            let Expr::Promise(promise, _) = value else {
+                return Err(EvalError::new(
+                    format!("{proc_name}: `{value}` is not a promise."),
+                    expr.span(),
+                ));
+            };
+            // Take over the state of `promise`, and let it share ours from now on.
+            self.set_state(promise.state());
+            *promise.state.borrow_mut() = self.state.borrow().clone();
+        }
+    }
+
+    fn set_state(&self, state: PromiseState) {
+        *self.state.borrow().borrow_mut() = state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::Evaluator;
+
+    #[test]
+    fn test_force() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let promise = Promise::done(1.into());
+        assert!(promise.is_done());
+        assert_eq!(promise.force("force"), Ok(1.into()));
+
+        let promise = Promise::delayed(2.into(), context.clone(), false);
+        assert!(!promise.is_done());
+        assert_eq!(promise.force("force"), Ok(2.into()));
+        assert!(promise.is_done());
+
+        let inner = Promise::delayed(3.into(), context.clone(), false);
+        let promise = Promise::delayed(Expr::Promise(inner.clone(), None), context.clone(), true);
+        assert_eq!(promise.force("force"), Ok(3.into()));
+        assert!(inner.is_done());
+
+        let promise = Promise::delayed(4.into(), context.clone(), true);
+        assert!(promise.force("force").is_err());
+    }
+}
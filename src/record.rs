@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::eval::{eval, EvalContext, EvalError, EvalResult};
+use crate::expr::{display_container, Expr, NIL};
+use crate::list::List;
+use crate::proc::Arity;
+
+/// A record type created by the `define-record-type` form.
+#[derive(Debug, PartialEq)]
+pub struct RecordType {
+    /// The name of the type, without the conventional angle brackets, e.g. `point`.
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+impl RecordType {
+    pub fn new<T: Into<String>>(name: T, fields: Vec<String>) -> Self {
+        let name = name.into();
+        let name = match name
+            .strip_prefix('<')
+            .and_then(|name| name.strip_suffix('>'))
+        {
+            Some(stripped) if !stripped.is_empty() => stripped.to_owned(),
+            _ => name,
+        };
+        Self { name, fields }
+    }
+
+    /// Returns the index of the field with the given name.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field == name)
+    }
+}
+
+/// An instance of a [`RecordType`].
+///
+/// Records have identity: two records are equal only if they are the same object.
+#[derive(Debug)]
+pub struct Record {
+    pub record_type: Rc<RecordType>,
+    pub values: RefCell<Vec<Expr>>,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_container(f, self, |f| {
+            write!(f, "#<record {}", self.record_type.name)?;
+            for (field, value) in self
+                .record_type
+                .fields
+                .iter()
+                .zip(self.values.borrow().iter())
+            {
+                write!(f, " {}: {}", field, value)?;
+            }
+            write!(f, ">")
+        })
+    }
+}
+
+/// The operation of a procedure generated by `define-record-type`. See [`Proc::Record`].
+///
+/// [`Proc::Record`]: crate::proc::Proc::Record
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub enum RecordProc {
+    /// Creates a record, initializing the fields at the given indices from the arguments.
+    Constructor(Vec<usize>),
+
+    /// Tests whether the argument is a record of the type.
+    Predicate,
+
+    /// Returns the value of the field at the given index.
+    Accessor(usize),
+
+    /// Sets the value of the field at the given index.
+    Modifier(usize),
+}
+
+impl RecordProc {
+    pub fn arity(&self) -> Arity {
+        match self {
+            RecordProc::Constructor(indices) => Arity::exact(indices.len()),
+            RecordProc::Predicate | RecordProc::Accessor(_) => Arity::exact(1),
+            RecordProc::Modifier(_) => Arity::exact(2),
+        }
+    }
+
+    pub(crate) fn invoke(
+        &self,
+        proc_name: &str,
+        record_type: &Rc<RecordType>,
+        args: &List,
+        context: &EvalContext,
+    ) -> EvalResult {
+        let values = args
+            .iter()
+            .map(|arg| eval(arg, context))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expected_len = match self {
+            RecordProc::Constructor(indices) => indices.len(),
+            RecordProc::Predicate | RecordProc::Accessor(_) => 1,
+            RecordProc::Modifier(_) => 2,
+        };
+        if values.len() != expected_len {
+            return Err(EvalError::from(format!(
+                "{proc_name}: expects {expected_len} arguments, but got {}.",
+                values.len()
+            )));
+        }
+
+        let get_record = |expr: &Expr| match expr {
+            Expr::Record(record, _) if Rc::ptr_eq(&record.record_type, record_type) => {
+                Ok(record.clone())
+            }
+            _ => Err(EvalError::from(format!(
+                "{proc_name}: `{expr}` is not a record of type {}.",
+                record_type.name
+            ))),
+        };
+
+        match self {
+            RecordProc::Constructor(indices) => {
+                let mut fields = vec![NIL; record_type.fields.len()];
+                for (index, value) in indices.iter().zip(values) {
+                    fields[*index] = value;
+                }
+                let record = Record {
+                    record_type: record_type.clone(),
+                    values: RefCell::new(fields),
+                };
+                Ok(Expr::Record(Rc::new(record), None))
+            }
+            RecordProc::Predicate => Ok(get_record(&values[0]).is_ok().into()),
+            RecordProc::Accessor(index) => {
+                let record = get_record(&values[0])?;
+                let value = record.values.borrow()[*index].clone();
+                Ok(value)
+            }
+            RecordProc::Modifier(index) => {
+                let record = get_record(&values[0])?;
+                record.values.borrow_mut()[*index] = values[1].clone();
+                Ok(NIL)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::test_utils::num;
+
+    #[test]
+    fn test_record_type_name() {
+        assert_eq!(RecordType::new("<point>", vec![]).name, "point");
+        assert_eq!(RecordType::new("point", vec![]).name, "point");
+        assert_eq!(RecordType::new("<>", vec![]).name, "<>");
+    }
+
+    #[test]
+    fn test_display() {
+        let record_type = Rc::new(RecordType::new("<point>", vec!["x".into(), "y".into()]));
+        let record = Record {
+            record_type,
+            values: RefCell::new(vec![num(1), num(2)]),
+        };
+        assert_eq!(record.to_string(), "#<record point x: 1 y: 2>");
+    }
+
+    #[test]
+    fn test_display_cyclic() {
+        let record_type = Rc::new(RecordType::new("<node>", vec!["next".into()]));
+        let record = Rc::new(Record {
+            record_type,
+            values: RefCell::new(vec![NIL]),
+        });
+        record.values.borrow_mut()[0] = Expr::Record(record.clone(), None);
+        assert_eq!(record.to_string(), "#<record node next: #<cycle>>");
+
+        // break the cycle so that the record is dropped
+        record.values.borrow_mut()[0] = NIL;
+    }
+}
@@ -0,0 +1,297 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+
+use crate::env::Env;
+
+thread_local! {
+    static SYMBOL_TABLE: RefCell<HashMap<Box<str>, Weak<SymbolData>>> =
+        RefCell::new(HashMap::new());
+    static GENSYM_COUNTER: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An interned name, used for symbols and variable bindings.
+///
+/// Symbols with the same name share the same storage, so comparing and hashing a symbol
+/// only looks at its address instead of the whole name.
+///
+/// The symbol table is shared by all evaluators on the same thread rather than owned by
+/// an [`Evaluator`](crate::eval::Evaluator), because symbols are also created without one,
+/// e.g. by the parser. It only holds weak references, and a name is removed from it once
+/// its last symbol is dropped, so that long-running hosts do not accumulate names.
+#[derive(Clone)]
+pub struct Symbol(Rc<SymbolData>);
+
+struct SymbolData {
+    name: Box<str>,
+
+    /// The symbol that this one is renamed from by `syntax-rules`, and the environment
+    /// where the macro was defined.
+    renamed_from: Option<(Symbol, Weak<Env>)>,
+}
+
+impl Symbol {
+    /// Returns the symbol with the given name, adding it to the symbol table if needed.
+    pub fn new(name: &str) -> Self {
+        SYMBOL_TABLE.with(|table| {
+            let mut table = table.borrow_mut();
+            if let Some(data) = table.get(name).and_then(Weak::upgrade) {
+                return Self(data);
+            }
+            let symbol = Self::uninterned(name);
+            table.insert(Box::from(name), Rc::downgrade(&symbol.0));
+            symbol
+        })
+    }
+
+    /// Creates a symbol that is not in the symbol table, so that it is different from
+    /// every other symbol, even from the ones with the same name.
+    pub fn uninterned(name: &str) -> Self {
+        Self(Rc::new(SymbolData {
+            name: Box::from(name),
+            renamed_from: None,
+        }))
+    }
+
+    /// Creates an uninterned symbol for an identifier renamed by `syntax-rules`.
+    ///
+    /// Unless the expansion binds it, the renamed symbol refers to the binding of
+    /// `original` in `env`, the environment where the macro was defined.
+    /// See [`Env::lookup_identifier`].
+    pub(crate) fn renamed(original: &Symbol, env: &Rc<Env>) -> Self {
+        Self(Rc::new(SymbolData {
+            name: Box::from(original.as_str()),
+            renamed_from: Some((original.clone(), Rc::downgrade(env))),
+        }))
+    }
+
+    /// Returns the symbol that this one is renamed from by `syntax-rules`, and
+    /// the environment where the macro was defined.
+    pub(crate) fn renamed_from(&self) -> Option<(&Symbol, &Weak<Env>)> {
+        self.0
+            .renamed_from
+            .as_ref()
+            .map(|(original, env)| (original, env))
+    }
+
+    /// Returns the symbol as written in the source, i.e. the symbol itself unless it is
+    /// renamed by `syntax-rules`.
+    pub fn unrenamed(&self) -> &Symbol {
+        match self.renamed_from() {
+            Some((original, _)) => original.unrenamed(),
+            None => self,
+        }
+    }
+
+    /// Creates a fresh uninterned symbol, named with `prefix` followed by a number.
+    pub fn gensym(prefix: &str) -> Self {
+        let count = GENSYM_COUNTER.with(|counter| {
+            let count = counter.get() + 1;
+            counter.set(count);
+            count
+        });
+        Self::uninterned(&format!("{prefix}{count}"))
+    }
+
+    /// Returns `true` if the symbol is a keyword such as `#:name`, which evaluates to itself.
+    /// Keywords name the keyword arguments of a procedure call.
+    pub fn is_keyword(&self) -> bool {
+        self.starts_with("#:") && self.len() > 2
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0.name
+    }
+
+    /// Returns `true` if the symbol is in the symbol table, i.e. it is not created by
+    /// [`Symbol::gensym`] or [`Symbol::uninterned`].
+    pub fn is_interned(&self) -> bool {
+        SYMBOL_TABLE.with(|table| self.is_in(&table.borrow()))
+    }
+
+    fn is_in(&self, table: &HashMap<Box<str>, Weak<SymbolData>>) -> bool {
+        table
+            .get(self.as_str())
+            .is_some_and(|data| std::ptr::eq(data.as_ptr(), Rc::as_ptr(&self.0)))
+    }
+}
+
+impl Drop for Symbol {
+    fn drop(&mut self) {
+        if Rc::strong_count(&self.0) > 1 {
+            return;
+        }
+        // The table may be gone already if the thread is exiting, as symbols can also be
+        // held by other thread locals.
+        let _ = SYMBOL_TABLE.try_with(|table| {
+            let mut table = table.borrow_mut();
+            if self.is_in(&table) {
+                table.remove(self.as_str());
+            }
+        });
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Self {
+        Symbol::new(name)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(name: &String) -> Self {
+        Symbol::new(name)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(name: String) -> Self {
+        Symbol::new(&name)
+    }
+}
+
+impl From<&Symbol> for Symbol {
+    fn from(symbol: &Symbol) -> Self {
+        symbol.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning() {
+        let a1 = Symbol::new("a");
+        let a2 = Symbol::from(String::from("a"));
+        let b = Symbol::new("b");
+        assert_eq!(a1, a2);
+        assert!(Rc::ptr_eq(&a1.0, &a2.0));
+        assert_ne!(a1, b);
+        assert_eq!(a1, "a");
+        assert!(a1.is_interned());
+    }
+
+    #[test]
+    fn test_gensym() {
+        let g1 = Symbol::gensym("g");
+        let g2 = Symbol::gensym("g");
+        assert_ne!(g1, g2);
+        assert_ne!(g1.as_str(), g2.as_str());
+        assert!(g1.starts_with('g'));
+        assert!(!g1.is_interned());
+
+        // the name of a gensym does not make it the same symbol
+        assert_ne!(Symbol::new(&g1), g1);
+
+        let u = Symbol::uninterned("u");
+        let interned_u = Symbol::new("u");
+        assert_ne!(u, interned_u);
+        assert_ne!(u, Symbol::uninterned("u"));
+        assert!(!u.is_interned());
+        assert_eq!(u.unrenamed(), &u);
+    }
+
+    #[test]
+    fn test_renamed() {
+        let env = Env::root(Weak::new());
+        let a = Symbol::new("a");
+        let renamed = Symbol::renamed(&a, &env);
+        assert_ne!(renamed, a);
+        assert_eq!(renamed.as_str(), "a");
+        assert!(!renamed.is_interned());
+        assert_eq!(
+            renamed.renamed_from().map(|(original, _)| original),
+            Some(&a)
+        );
+        assert_eq!(Symbol::renamed(&renamed, &env).unrenamed(), &a);
+        assert!(a.renamed_from().is_none());
+    }
+
+    #[test]
+    fn test_table_cleanup() {
+        let in_table = |name: &str| SYMBOL_TABLE.with(|table| table.borrow().contains_key(name));
+
+        let s1 = Symbol::new("test_table_cleanup");
+        let s2 = s1.clone();
+        drop(s1);
+        assert!(in_table("test_table_cleanup"));
+        drop(s2);
+        assert!(!in_table("test_table_cleanup"));
+
+        // dropping an uninterned symbol does not remove the interned one
+        let s = Symbol::new("test_table_cleanup");
+        drop(Symbol::uninterned("test_table_cleanup"));
+        assert!(in_table("test_table_cleanup"));
+        assert!(s.is_interned());
+    }
+
+    #[test]
+    fn test_drop_on_thread_exit() {
+        thread_local! {
+            static HELD: RefCell<Option<Symbol>> = const { RefCell::new(None) };
+        }
+
+        // The held symbol may be dropped after the symbol table, which must not panic.
+        std::thread::spawn(|| {
+            HELD.with(|held| *held.borrow_mut() = Some(Symbol::new("held")));
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Symbol::new("sym").to_string(), "sym");
+        assert_eq!(format!("{:?}", Symbol::new("sym")), "\"sym\"");
+    }
+}
@@ -0,0 +1,542 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::builtin::quote::{QUASIQUOTE, QUOTE, UNQUOTE, UNQUOTE_SPLICING};
+use crate::env::Env;
+use crate::eval::EvalError;
+use crate::expr::{Expr, NIL};
+use crate::list::{cons, List};
+use crate::symbol::Symbol;
+
+const ELLIPSIS: &str = "...";
+const UNDERSCORE: &str = "_";
+
+/// A macro transformer created by the `syntax-rules` form. See [`Proc::SyntaxRules`].
+///
+/// Expanding a form finds the first rule whose pattern matches the form, and then fills
+/// the template of the rule with the matched pattern variables. The symbols introduced
+/// by the template are renamed to fresh uninterned symbols, so that the bindings made by
+/// the expansion never capture the symbols of the macro user. A renamed symbol that is
+/// not bound by the expansion refers to the binding of the original symbol in the
+/// environment where the macro was defined, so that the macro user cannot capture the
+/// symbols of the template either. Renamed symbols that end up as data, e.g. quoted,
+/// are restored to the original symbols.
+///
+/// [`Proc::SyntaxRules`]: crate::proc::Proc::SyntaxRules
+#[derive(Debug)]
+pub struct SyntaxRules {
+    ellipsis: Symbol,
+    literals: Vec<Symbol>,
+    rules: Vec<(Expr, Expr)>,
+    env: Rc<Env>,
+}
+
+/// What a pattern variable matched.
+#[derive(Clone, Debug)]
+enum Binding {
+    One(Expr),
+    /// The matches of a pattern variable that is followed by an ellipsis.
+    Many(Vec<Binding>),
+}
+
+type Bindings = HashMap<Symbol, Binding>;
+
+impl SyntaxRules {
+    /// Creates a transformer from `(pattern template)` pairs. The first element of each
+    /// pattern, which is the position of the macro keyword, is ignored. `env` is
+    /// the environment where the macro is defined.
+    pub fn new(
+        ellipsis: Option<Symbol>,
+        literals: Vec<Symbol>,
+        rules: Vec<(Expr, Expr)>,
+        env: Rc<Env>,
+    ) -> Result<Self, String> {
+        let syntax_rules = Self {
+            ellipsis: ellipsis.unwrap_or_else(|| Symbol::new(ELLIPSIS)),
+            literals,
+            rules: Vec::new(),
+            env,
+        };
+        let rules = rules
+            .into_iter()
+            .map(|(pattern, template)| match pattern {
+                Expr::List(List::Cons(cons), _) => {
+                    syntax_rules.check_pattern(&cons.cdr)?;
+                    Ok((cons.cdr.as_ref().clone(), template))
+                }
+                _ => Err(format!("pattern `{pattern}` is not a list.")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            rules,
+            ..syntax_rules
+        })
+    }
+
+    /// Returns the environment where the macro is defined.
+    pub fn env(&self) -> &Rc<Env> {
+        &self.env
+    }
+
+    /// Expands `args`, the operands of a macro use, with the first matching rule.
+    pub fn expand(&self, macro_name: &str, args: &List) -> Result<Expr, EvalError> {
+        let form = Expr::List(args.clone(), None);
+        for (pattern, template) in &self.rules {
+            let mut bindings = Bindings::new();
+            if self.match_pattern(pattern, &form, &mut bindings) {
+                let mut renames = HashMap::new();
+                return self
+                    .expand_template(template, &bindings, &mut renames, true)
+                    .map_err(|message| EvalError::from(format!("{macro_name}: {message}")));
+            }
+        }
+        Err(EvalError::from(format!(
+            "{macro_name}: no syntax rule matches `{args}`."
+        )))
+    }
+
+    fn is_ellipsis(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Sym(symbol, _) if symbol.as_str() == self.ellipsis.as_str())
+    }
+
+    fn is_literal(&self, symbol: &Symbol) -> bool {
+        self.literals
+            .iter()
+            .any(|literal| literal.as_str() == symbol.as_str())
+    }
+
+    fn check_pattern(&self, pattern: &Expr) -> Result<(), String> {
+        let list = match pattern {
+            Expr::List(list, _) => list.clone(),
+            Expr::Vector(vector, _) => to_list(&vector.borrow()),
+            _ => return Ok(()),
+        };
+        let (items, tail) = split(&list);
+        let ellipses = items.iter().filter(|item| self.is_ellipsis(item)).count();
+        if ellipses > 1 || items.first().is_some_and(|item| self.is_ellipsis(item)) {
+            return Err(format!("invalid use of ellipsis in pattern `{pattern}`."));
+        }
+        items
+            .into_iter()
+            .chain(tail)
+            .try_for_each(|item| self.check_pattern(item))
+    }
+
+    fn match_pattern(&self, pattern: &Expr, form: &Expr, bindings: &mut Bindings) -> bool {
+        match (pattern, form) {
+            (Expr::Sym(symbol, _), _) if self.is_literal(symbol) => {
+                matches!(form, Expr::Sym(name, _) if name.as_str() == symbol.as_str())
+            }
+            (Expr::Sym(symbol, _), _) => {
+                if symbol.as_str() != UNDERSCORE {
+                    bindings.insert(symbol.clone(), Binding::One(form.clone()));
+                }
+                true
+            }
+            (Expr::List(pattern, _), Expr::List(form, _)) => {
+                self.match_list(pattern, form, bindings)
+            }
+            (Expr::Vector(pattern, _), Expr::Vector(form, _)) => self.match_list(
+                &to_list(&pattern.borrow()),
+                &to_list(&form.borrow()),
+                bindings,
+            ),
+            (Expr::List(_, _) | Expr::Vector(_, _), _) => false,
+            _ => pattern == form,
+        }
+    }
+
+    fn match_list(&self, pattern: &List, form: &List, bindings: &mut Bindings) -> bool {
+        let (patterns, pattern_tail) = split(pattern);
+        let (forms, form_tail) = split(form);
+
+        let Some(ellipsis_pos) = patterns.iter().position(|p| self.is_ellipsis(p)) else {
+            if forms.len() < patterns.len()
+                || !patterns
+                    .iter()
+                    .zip(&forms)
+                    .all(|(p, f)| self.match_pattern(p, f, bindings))
+            {
+                return false;
+            }
+            let rest = join(&forms[patterns.len()..], form_tail);
+            return match pattern_tail {
+                Some(pattern_tail) => self.match_pattern(pattern_tail, &rest, bindings),
+                None => rest == NIL,
+            };
+        };
+
+        let repeated = patterns[ellipsis_pos - 1];
+        let before = &patterns[..ellipsis_pos - 1];
+        let after = &patterns[ellipsis_pos + 1..];
+        if forms.len() < before.len() + after.len()
+            || (pattern_tail.is_none() && form_tail.is_some())
+        {
+            return false;
+        }
+
+        let after_pos = forms.len() - after.len();
+        let mut matches = Vec::new();
+        for form in &forms[before.len()..after_pos] {
+            let mut repeated_bindings = Bindings::new();
+            if !self.match_pattern(repeated, form, &mut repeated_bindings) {
+                return false;
+            }
+            matches.push(repeated_bindings);
+        }
+        for var in self.pattern_vars(repeated) {
+            let binding = Binding::Many(matches.iter().map(|m| m[&var].clone()).collect());
+            bindings.insert(var, binding);
+        }
+
+        before
+            .iter()
+            .zip(&forms)
+            .chain(after.iter().zip(&forms[after_pos..]))
+            .all(|(p, f)| self.match_pattern(p, f, bindings))
+            && pattern_tail.is_none_or(|pattern_tail| {
+                self.match_pattern(pattern_tail, form_tail.unwrap_or(&NIL), bindings)
+            })
+    }
+
+    fn pattern_vars(&self, pattern: &Expr) -> Vec<Symbol> {
+        match pattern {
+            Expr::Sym(symbol, _)
+                if !self.is_literal(symbol)
+                    && !self.is_ellipsis(pattern)
+                    && symbol.as_str() != UNDERSCORE =>
+            {
+                vec![symbol.clone()]
+            }
+            Expr::List(list, _) => {
+                let (items, tail) = split(list);
+                items
+                    .into_iter()
+                    .chain(tail)
+                    .flat_map(|item| self.pattern_vars(item))
+                    .collect()
+            }
+            Expr::Vector(vector, _) => vector
+                .borrow()
+                .iter()
+                .flat_map(|item| self.pattern_vars(item))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Fills `template` with `bindings`. The other symbols are renamed if `rename` is
+    /// `true`, which is not the case for quoted data.
+    fn expand_template(
+        &self,
+        template: &Expr,
+        bindings: &Bindings,
+        renames: &mut HashMap<Symbol, Symbol>,
+        rename: bool,
+    ) -> Result<Expr, String> {
+        match template {
+            Expr::Sym(symbol, span) => match bindings.get(symbol) {
+                Some(Binding::One(expr)) => Ok(expr.clone()),
+                Some(Binding::Many(_)) => Err(format!(
+                    "pattern variable `{symbol}` is used without an ellipsis."
+                )),
+                None if rename => {
+                    let renamed = renames
+                        .entry(symbol.clone())
+                        .or_insert_with(|| Symbol::renamed(symbol, &self.env));
+                    Ok(Expr::Sym(renamed.clone(), *span))
+                }
+                None => Ok(template.clone()),
+            },
+            Expr::List(list, span) => {
+                let rename = match list {
+                    List::Cons(cons) => match cons.car.as_ref() {
+                        Expr::Sym(head, _) if bindings.contains_key(head) => rename,
+                        Expr::Sym(head, _) if *head == QUOTE || *head == QUASIQUOTE => false,
+                        Expr::Sym(head, _) if *head == UNQUOTE || *head == UNQUOTE_SPLICING => true,
+                        _ => rename,
+                    },
+                    List::Nil => rename,
+                };
+                let expanded = self.expand_list(list, bindings, renames, rename)?;
+                Ok(match expanded {
+                    Expr::List(list, _) => Expr::List(list, *span),
+                    expr => expr,
+                })
+            }
+            Expr::Vector(vector, span) => {
+                let list = to_list(&vector.borrow());
+                let expanded = self.expand_list(&list, bindings, renames, rename)?;
+                let Expr::List(expanded, _) = expanded else {
+                    unreachable!("a proper list template expands to a proper list");
+                };
+                let items = expanded.iter().cloned().collect();
+                Ok(Expr::Vector(Rc::new(RefCell::new(items)), *span))
+            }
+            _ => Ok(template.clone()),
+        }
+    }
+
+    fn expand_list(
+        &self,
+        list: &List,
+        bindings: &Bindings,
+        renames: &mut HashMap<Symbol, Symbol>,
+        rename: bool,
+    ) -> Result<Expr, String> {
+        let (items, tail) = split(list);
+        let mut expanded = Vec::new();
+        let mut i = 0;
+        while i < items.len() {
+            let depth = items[i + 1..]
+                .iter()
+                .take_while(|item| self.is_ellipsis(item))
+                .count();
+            if depth == 0 {
+                expanded.push(self.expand_template(items[i], bindings, renames, rename)?);
+            } else {
+                self.expand_repeated(items[i], depth, bindings, renames, rename, &mut expanded)?;
+            }
+            i += 1 + depth;
+        }
+        let tail = match tail {
+            Some(tail) => Some(self.expand_template(tail, bindings, renames, rename)?),
+            None => None,
+        };
+        let expanded = expanded.iter().collect::<Vec<_>>();
+        Ok(join(&expanded, tail.as_ref()))
+    }
+
+    /// Expands `template` followed by `depth` ellipses into `output`.
+    fn expand_repeated(
+        &self,
+        template: &Expr,
+        depth: usize,
+        bindings: &Bindings,
+        renames: &mut HashMap<Symbol, Symbol>,
+        rename: bool,
+        output: &mut Vec<Expr>,
+    ) -> Result<(), String> {
+        let mut vars = Vec::new();
+        collect_symbols(template, &mut vars);
+        let repeated: Vec<(&Symbol, &Vec<Binding>)> = vars
+            .iter()
+            .filter_map(|var| match bindings.get(var) {
+                Some(Binding::Many(matches)) => Some((var, matches)),
+                _ => None,
+            })
+            .collect();
+
+        let Some((_, first)) = repeated.first() else {
+            return Err(format!("no pattern variable to repeat in `{template}`."));
+        };
+        let count = first.len();
+        if repeated.iter().any(|(_, matches)| matches.len() != count) {
+            return Err(format!(
+                "pattern variables in `{template}` matched different numbers of forms."
+            ));
+        }
+
+        for i in 0..count {
+            let mut iteration = bindings.clone();
+            for (var, matches) in &repeated {
+                iteration.insert((*var).clone(), matches[i].clone());
+            }
+            if depth == 1 {
+                output.push(self.expand_template(template, &iteration, renames, rename)?);
+            } else {
+                self.expand_repeated(template, depth - 1, &iteration, renames, rename, output)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for SyntaxRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.ellipsis == other.ellipsis
+            && self.literals == other.literals
+            && self.rules == other.rules
+            && Rc::ptr_eq(&self.env, &other.env)
+    }
+}
+
+/// Replaces the symbols renamed by `syntax-rules` in `expr` with the original symbols.
+///
+/// This is for data, such as quoted forms and `case` data, in which renamed symbols
+/// would otherwise never be the same as the symbols written by the macro user.
+pub(crate) fn strip_renames(expr: &Expr) -> Cow<'_, Expr> {
+    if has_renames(expr) {
+        Cow::Owned(strip(expr))
+    } else {
+        Cow::Borrowed(expr)
+    }
+}
+
+fn has_renames(expr: &Expr) -> bool {
+    match expr {
+        Expr::Sym(symbol, _) => symbol.renamed_from().is_some(),
+        Expr::List(list, _) => {
+            let (items, tail) = split(list);
+            items.into_iter().chain(tail).any(has_renames)
+        }
+        Expr::Vector(vector, _) => vector.borrow().iter().any(has_renames),
+        _ => false,
+    }
+}
+
+fn strip(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Sym(symbol, span) => Expr::Sym(symbol.unrenamed().clone(), *span),
+        Expr::List(list, span) => {
+            let (items, tail) = split(list);
+            let items: Vec<Expr> = items.into_iter().map(strip).collect();
+            let tail = tail.map(strip);
+            match join(&items.iter().collect::<Vec<_>>(), tail.as_ref()) {
+                Expr::List(list, _) => Expr::List(list, *span),
+                expr => expr,
+            }
+        }
+        Expr::Vector(vector, span) => {
+            let items = vector.borrow().iter().map(strip).collect();
+            Expr::Vector(Rc::new(RefCell::new(items)), *span)
+        }
+        _ => expr.clone(),
+    }
+}
+
+/// Splits a list into its elements and its tail, if the list is improper.
+fn split(list: &List) -> (Vec<&Expr>, Option<&Expr>) {
+    let mut iter = list.iter();
+    let items = iter.by_ref().collect();
+    (items, iter.tail())
+}
+
+/// The reverse of [`split`].
+fn join(items: &[&Expr], tail: Option<&Expr>) -> Expr {
+    items
+        .iter()
+        .rev()
+        .fold(tail.cloned().unwrap_or(NIL), |cdr, car| {
+            cons((*car).clone(), cdr).into()
+        })
+}
+
+fn to_list(items: &[Expr]) -> List {
+    items
+        .iter()
+        .rev()
+        .fold(List::Nil, |cdr, car| cons(car.clone(), cdr))
+}
+
+fn collect_symbols(expr: &Expr, symbols: &mut Vec<Symbol>) {
+    match expr {
+        Expr::Sym(symbol, _) => symbols.push(symbol.clone()),
+        Expr::List(list, _) => list.iter().for_each(|item| collect_symbols(item, symbols)),
+        Expr::Vector(vector, _) => vector
+            .borrow()
+            .iter()
+            .for_each(|item| collect_symbols(item, symbols)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::intern;
+    use crate::macros::list;
+
+    fn sym(name: &str) -> Symbol {
+        Symbol::new(name)
+    }
+
+    fn env() -> Rc<Env> {
+        Env::root(std::rc::Weak::new())
+    }
+
+    #[test]
+    fn test_expand() {
+        // (_ a b ...) => (list a (b) ...)
+        let rules = SyntaxRules::new(
+            None,
+            Vec::new(),
+            vec![(
+                list!(intern("_"), intern("a"), intern("b"), intern("...")).into(),
+                list!(
+                    intern("list"),
+                    intern("a"),
+                    list!(intern("b")),
+                    intern("...")
+                )
+                .into(),
+            )],
+            env(),
+        )
+        .unwrap();
+
+        let expanded = rules.expand("m", &list!(1, 2, 3)).unwrap();
+        let Expr::List(expanded, _) = &expanded else {
+            panic!("expansion should be a list");
+        };
+        let items: Vec<_> = expanded.iter().cloned().collect();
+        assert_eq!(items[1..], [1.into(), list!(2).into(), list!(3).into()]);
+
+        // `list` is introduced by the template, so it is renamed
+        let Expr::Sym(head, _) = &items[0] else {
+            panic!("head should be a symbol");
+        };
+        assert_eq!(head.as_str(), "list");
+        assert!(!head.is_interned());
+        assert_eq!(head.unrenamed(), &sym("list"));
+
+        assert!(rules.expand("m", &list!()).is_err());
+    }
+
+    #[test]
+    fn test_literals() {
+        let rules = SyntaxRules::new(
+            None,
+            vec![sym("=>")],
+            vec![
+                (list!(intern("_"), intern("=>")).into(), 1.into()),
+                (list!(intern("_"), intern("x")).into(), 2.into()),
+            ],
+            env(),
+        )
+        .unwrap();
+
+        assert_eq!(rules.expand("m", &list!(intern("=>"))), Ok(1.into()));
+        assert_eq!(rules.expand("m", &list!(intern("x"))), Ok(2.into()));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let new =
+            |pattern: List| SyntaxRules::new(None, Vec::new(), vec![(pattern.into(), NIL)], env());
+
+        assert!(new(list!(intern("_"), intern("..."))).is_err());
+        assert!(new(list!(
+            intern("_"),
+            list!(intern("a"), intern("..."), intern("..."))
+        ),)
+        .is_err());
+        assert!(SyntaxRules::new(None, Vec::new(), vec![(1.into(), NIL)], env()).is_err());
+    }
+
+    #[test]
+    fn test_strip_renames() {
+        let renamed = |name: &str| Expr::Sym(Symbol::renamed(&sym(name), &env()), None);
+
+        let data: Expr = list!(1, intern("a")).into();
+        assert!(matches!(strip_renames(&data), Cow::Borrowed(_)));
+
+        let data: Expr = list!(renamed("a"), list!(renamed("b"))).into();
+        assert_eq!(
+            strip_renames(&data).into_owned(),
+            list!(intern("a"), list!(intern("b"))).into()
+        );
+    }
+}
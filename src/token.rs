@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use crate::expr::CharLiteral;
+use crate::number::Number;
 use crate::span::{Loc, Span};
 
 /// The enum that represents a lexical unit of the source code in Rusche.
@@ -11,6 +13,18 @@ pub enum Token {
     /// Close parenthesis `)`.
     CloseParen(Loc),
 
+    /// Vector open parenthesis `#(`.
+    OpenVector(Loc),
+
+    /// Bytevector open parenthesis `#u8(`.
+    OpenBytevector(Loc),
+
+    /// Hash table open parenthesis `#hash(`.
+    OpenHashTable(Loc),
+
+    /// Dot `.` of a dotted pair such as `(1 . 2)`.
+    Dot(Loc),
+
     /// Quote `'`.
     Quote(Loc),
 
@@ -23,8 +37,14 @@ pub enum Token {
     /// Unquote-splicing `,@`.
     UnquoteSplicing(Loc),
 
+    /// A boolean literal, `#t` or `#f`.
+    Bool(bool, Span),
+
     /// A number literal.
-    Num(f64, Span),
+    Num(Number, Span),
+
+    /// A character literal, e.g. `#\a` or `#\space`.
+    Char(char, Span),
 
     /// A string literal.
     Str(String, Span),
@@ -38,11 +58,20 @@ impl Token {
         match self {
             Token::OpenParen(loc)
             | Token::CloseParen(loc)
+            | Token::Dot(loc)
             | Token::Quote(loc)
             | Token::Quasiquote(loc)
             | Token::Unquote(loc) => Span::new(*loc, loc.with_column_offset(1)),
-            Token::UnquoteSplicing(loc) => Span::new(*loc, loc.with_column_offset(2)),
-            Token::Num(_, span) | Token::Str(_, span) | Token::Sym(_, span) => *span,
+            Token::OpenVector(loc) | Token::UnquoteSplicing(loc) => {
+                Span::new(*loc, loc.with_column_offset(2))
+            }
+            Token::OpenBytevector(loc) => Span::new(*loc, loc.with_column_offset(4)),
+            Token::OpenHashTable(loc) => Span::new(*loc, loc.with_column_offset(6)),
+            Token::Bool(_, span)
+            | Token::Num(_, span)
+            | Token::Char(_, span)
+            | Token::Str(_, span)
+            | Token::Sym(_, span) => *span,
         }
     }
 }
@@ -52,11 +81,17 @@ impl PartialEq for Token {
         match (self, other) {
             (Token::OpenParen(_), Token::OpenParen(_)) => true,
             (Token::CloseParen(_), Token::CloseParen(_)) => true,
+            (Token::Dot(_), Token::Dot(_)) => true,
+            (Token::OpenVector(_), Token::OpenVector(_)) => true,
+            (Token::OpenBytevector(_), Token::OpenBytevector(_)) => true,
+            (Token::OpenHashTable(_), Token::OpenHashTable(_)) => true,
             (Token::Quote(_), Token::Quote(_)) => true,
             (Token::Quasiquote(_), Token::Quasiquote(_)) => true,
             (Token::Unquote(_), Token::Unquote(_)) => true,
             (Token::UnquoteSplicing(_), Token::UnquoteSplicing(_)) => true,
+            (Token::Bool(a, _), Token::Bool(b, _)) => a == b,
             (Token::Num(a, _), Token::Num(b, _)) => a == b,
+            (Token::Char(a, _), Token::Char(b, _)) => a == b,
             (Token::Str(a, _), Token::Str(b, _)) => a == b,
             (Token::Sym(a, _), Token::Sym(b, _)) => a == b,
             _ => false,
@@ -69,11 +104,17 @@ impl Display for Token {
         match self {
             Token::OpenParen(_) => write!(f, "("),
             Token::CloseParen(_) => write!(f, ")"),
+            Token::Dot(_) => write!(f, "."),
+            Token::OpenVector(_) => write!(f, "#("),
+            Token::OpenBytevector(_) => write!(f, "#u8("),
+            Token::OpenHashTable(_) => write!(f, "#hash("),
             Token::Quote(_) => write!(f, "'"),
             Token::Quasiquote(_) => write!(f, "`"),
             Token::Unquote(_) => write!(f, ","),
             Token::UnquoteSplicing(_) => write!(f, ",@"),
+            Token::Bool(value, _) => write!(f, "{}", if *value { "#t" } else { "#f" }),
             Token::Num(value, _) => write!(f, "{}", value),
+            Token::Char(ch, _) => write!(f, "{}", CharLiteral(*ch)),
             Token::Str(text, _) => write!(f, "\"{}\"", text),
             Token::Sym(name, _) => write!(f, "{}", name),
         }
@@ -96,6 +137,10 @@ mod tests {
         }
         assert_token_span_length_eq!(1, OpenParen);
         assert_token_span_length_eq!(1, CloseParen);
+        assert_token_span_length_eq!(1, Dot);
+        assert_token_span_length_eq!(2, OpenVector);
+        assert_token_span_length_eq!(4, OpenBytevector);
+        assert_token_span_length_eq!(6, OpenHashTable);
         assert_token_span_length_eq!(1, Quote);
         assert_token_span_length_eq!(1, Quasiquote);
         assert_token_span_length_eq!(1, Unquote);
@@ -106,15 +151,23 @@ mod tests {
     fn test_eq() {
         assert_eq!(tok!(OpenParen), tok!(OpenParen));
         assert_eq!(tok!(CloseParen), tok!(CloseParen));
+        assert_eq!(tok!(Dot), tok!(Dot));
+        assert_eq!(tok!(OpenVector), tok!(OpenVector));
+        assert_eq!(tok!(OpenBytevector), tok!(OpenBytevector));
+        assert_eq!(tok!(OpenHashTable), tok!(OpenHashTable));
         assert_eq!(tok!(Quote), tok!(Quote));
         assert_eq!(tok!(Quasiquote), tok!(Quasiquote));
         assert_eq!(tok!(Unquote), tok!(Unquote));
         assert_eq!(tok!(UnquoteSplicing), tok!(UnquoteSplicing));
+        assert_eq!(tok!(Bool(true)), tok!(Bool(true)));
         assert_eq!(tok!(Num(1)), tok!(Num(1)));
+        assert_eq!(tok!(Char('a')), tok!(Char('a')));
         assert_eq!(tok!(Str("str")), tok!(Str("str")));
         assert_eq!(tok!(Sym("sym")), tok!(Sym("sym")));
 
+        assert_ne!(tok!(Bool(true)), tok!(Bool(false)));
         assert_ne!(tok!(Num(1)), tok!(Num(2)));
+        assert_ne!(tok!(Char('a')), tok!(Char('b')));
         assert_ne!(tok!(Str("str")), tok!(Str("abc")));
         assert_ne!(tok!(Sym("sym")), tok!(Sym("abc")));
         assert_ne!(tok!(Str("sym")), tok!(Sym("sym")));
@@ -133,7 +186,10 @@ mod tests {
                 assert_eq!(
                     format!(
                         "{}",
-                        Token::$token_case($value, Span::new(Loc::new(1, 1), Loc::new(1, 2)))
+                        Token::$token_case(
+                            $value.into(),
+                            Span::new(Loc::new(1, 1), Loc::new(1, 2))
+                        )
                     ),
                     $formatted
                 );
@@ -141,15 +197,25 @@ mod tests {
         }
         assert_token_format_eq!(OpenParen, "(");
         assert_token_format_eq!(CloseParen, ")");
+        assert_token_format_eq!(Dot, ".");
+        assert_token_format_eq!(OpenVector, "#(");
+        assert_token_format_eq!(OpenBytevector, "#u8(");
+        assert_token_format_eq!(OpenHashTable, "#hash(");
         assert_token_format_eq!(Quote, "'");
         assert_token_format_eq!(Quasiquote, "`");
         assert_token_format_eq!(Unquote, ",");
         assert_token_format_eq!(UnquoteSplicing, ",@");
-        assert_token_format_eq!(Num(0.0), "0");
+        assert_token_format_eq!(Bool(true), "#t");
+        assert_token_format_eq!(Bool(false), "#f");
+        assert_token_format_eq!(Num(0), "0");
+        assert_token_format_eq!(Num(0.0), "0.0");
         assert_token_format_eq!(Num(0.5), "0.5");
-        assert_token_format_eq!(Num(1.0), "1");
+        assert_token_format_eq!(Num(1), "1");
+        assert_token_format_eq!(Num(1.0), "1.0");
         assert_token_format_eq!(Num(123.456), "123.456");
         assert_token_format_eq!(Num(123.456), "123.456");
+        assert_token_format_eq!(Char('a'), "#\\a");
+        assert_token_format_eq!(Char(' '), "#\\space");
         assert_token_format_eq!(Str("str".to_string()), "\"str\"");
         assert_token_format_eq!(Sym("sym".to_string()), "sym");
     }
@@ -1,9 +1,15 @@
 use std::any::Any;
+use std::cell::{Ref, RefCell};
 use std::rc::Rc;
 
 use crate::eval::{eval, EvalContext, EvalError};
-use crate::expr::Expr;
+use crate::expr::{Bytevector, Expr, Vector};
+use crate::formal_args::{get_variadic_args_name, FormalArgs, KEY, OPTIONAL};
+use crate::hash_table::HashTable;
 use crate::list::List;
+use crate::number::Number;
+use crate::proc::Proc;
+use crate::symbol::Symbol;
 
 /// Get exactly one argument from a list.
 ///
@@ -143,6 +149,54 @@ pub fn get_exact_3_args<'a>(
     }
 }
 
+/// Get one or two arguments from a list.
+///
+/// Check if `args` contains one or two arguments. If so, return a tuple that contains
+/// a reference to the first argument and optional 2nd argument. Otherwise, return an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `args` - List of arguments.
+///
+/// # Example
+///
+/// ```
+/// use rusche::{
+///     expr::Expr,
+///     utils::get_1_or_2_args,
+///     list
+/// };
+///
+/// let args = list!(1);
+/// let result = get_1_or_2_args("number->string", &args);
+/// assert_eq!(result, Ok((&Expr::from(1), None)));
+///
+/// let args = list!(1, 2);
+/// let result = get_1_or_2_args("number->string", &args);
+/// assert_eq!(result, Ok((&Expr::from(1), Some(&Expr::from(2)))));
+/// ```
+pub fn get_1_or_2_args<'a>(
+    proc_name: &str,
+    args: &'a List,
+) -> Result<(&'a Expr, Option<&'a Expr>), EvalError> {
+    let mut iter = args.iter();
+
+    let arg1 = iter.next();
+    let arg2 = iter.next();
+    let arg3 = iter.next();
+
+    match (arg1, arg2, arg3) {
+        (Some(arg1), arg2, None) => Ok((arg1, arg2)),
+        (Some(_), Some(_), Some(_)) => Err(EvalError::from(format!(
+            "{proc_name}: takes only up to 2 arguments"
+        ))),
+        _ => Err(EvalError::from(format!(
+            "{proc_name}: requres at least 1 argument"
+        ))),
+    }
+}
+
 /// Get two or three arguments from a list.
 ///
 /// Check if `args` contains two or three arguments. If so, return a tuple that contains
@@ -192,26 +246,129 @@ pub fn get_2_or_3_args<'a>(
     }
 }
 
-/// Make a vector of symbol names from a list of arguments.
-///
-/// Check if `list` contains only symbols. If so, return a vector of the symbols.
-/// Otherwise, return an error message. This function can be used to extract formal
-/// arguments when implementing a function-like special form such as `lambda` or `defmacro`.
-pub fn make_formal_args(list: &List) -> Result<Vec<String>, EvalError> {
-    let mut formal_args = Vec::new();
-    for item in list.iter() {
-        let Expr::Sym(formal_arg, _) = item else {
-            return Err(EvalError {
-                message: format!("{item} is not a symbol."),
-                span: item.span(),
-            });
+/// Make formal arguments from a list of arguments, or from a single symbol for the rest
+/// arguments.
+///
+/// The list can have optional arguments after `#!optional`, keyword arguments after `#:key`,
+/// and the rest arguments as its dotted tail or as a symbol prefixed with `*`. See
+/// [`FormalArgs`]. Otherwise, return an error message. This function can be used to
+/// extract formal arguments when implementing a function-like special form such as
+/// `lambda` or `defmacro`.
+pub fn make_formal_args(expr: &Expr) -> Result<FormalArgs, EvalError> {
+    let list = match expr {
+        Expr::Sym(name, _) => {
+            return Ok(FormalArgs {
+                rest: Some(name.clone()),
+                ..FormalArgs::default()
+            })
+        }
+        Expr::List(list, _) => list,
+        _ => {
+            return Err(EvalError::new(
+                format!("{expr} is not a list of formal arguments."),
+                expr.span(),
+            ))
+        }
+    };
+
+    let mut formal_args = FormalArgs::default();
+    let mut section = None;
+    let mut iter = list.iter();
+    for item in iter.by_ref() {
+        if formal_args.rest.is_some() {
+            return Err(EvalError::new(
+                format!("{item} follows the rest arguments."),
+                item.span(),
+            ));
+        }
+
+        let (name, default) = match item {
+            Expr::Sym(marker, _)
+                if (marker == OPTIONAL && section.is_none())
+                    || (marker == KEY && section != Some(KEY)) =>
+            {
+                section = Some(marker.as_str());
+                continue;
+            }
+            Expr::Sym(marker, _) if marker == OPTIONAL || marker == KEY => {
+                return Err(EvalError::new(
+                    format!("{marker} is misplaced in {list}."),
+                    item.span(),
+                ));
+            }
+            Expr::Sym(name, _) if section.is_none() => {
+                match get_variadic_args_name(name) {
+                    Some(rest) => {
+                        let rest = Symbol::new(rest);
+                        check_unique_arg(&formal_args, &rest, list, item)?;
+                        formal_args.rest = Some(rest);
+                    }
+                    None => {
+                        check_unique_arg(&formal_args, name, list, item)?;
+                        formal_args.required.push(name.clone());
+                    }
+                }
+                continue;
+            }
+            Expr::Sym(name, _) => (name, None),
+            Expr::List(pair, _) if section.is_some() && pair.is_proper() && pair.len() == 2 => {
+                let mut items = pair.iter();
+                match (items.next(), items.next()) {
+                    (Some(Expr::Sym(name, _)), Some(default)) => (name, Some(default.clone())),
+                    _ => {
+                        return Err(EvalError::new(
+                            format!("{item} is not a formal argument."),
+                            item.span(),
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(EvalError::new(
+                    format!("{item} is not a formal argument."),
+                    item.span(),
+                ))
+            }
         };
-        formal_args.push(formal_arg.clone());
+        check_unique_arg(&formal_args, name, list, item)?;
+        match section {
+            Some(KEY) => formal_args.keys.push((name.clone(), default)),
+            _ => formal_args.optional.push((name.clone(), default)),
+        }
+    }
+
+    match iter.tail() {
+        Some(tail @ Expr::Sym(rest, _)) if formal_args.rest.is_none() => {
+            check_unique_arg(&formal_args, rest, list, tail)?;
+            formal_args.rest = Some(rest.clone());
+        }
+        Some(tail) => {
+            return Err(EvalError::new(
+                format!("{tail} is not a symbol for the rest arguments."),
+                tail.span(),
+            ));
+        }
+        None => {}
     }
 
     Ok(formal_args)
 }
 
+fn check_unique_arg(
+    formal_args: &FormalArgs,
+    name: &Symbol,
+    list: &List,
+    item: &Expr,
+) -> Result<(), EvalError> {
+    if formal_args.contains(name) {
+        return Err(EvalError::new(
+            format!("{name} is duplicated in {list}."),
+            item.span(),
+        ));
+    }
+    Ok(())
+}
+
 /// Evaluate an expression into a string.
 ///
 /// Check if `expr` evaluates to a string. If so, return the string. Otherwise, return an error message.
@@ -243,14 +400,52 @@ pub fn eval_into_str(
 ) -> Result<String, EvalError> {
     match eval(expr, context)? {
         Expr::Str(text, _) => Ok(text),
-        _ => Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a string."),
-            span: expr.span(),
-        }),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a string."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Evaluate an expression into a character.
+///
+/// Check if `expr` evaluates to a character. If so, return the character. Otherwise, return an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::Expr,
+///     utils::eval_into_char,
+/// };
+///
+/// let evaluator = Evaluator::new();
+/// let expr = Expr::from('a');
+/// let result = eval_into_char("test", &expr, evaluator.context());
+/// assert_eq!(result, Ok('a'));
+/// ```
+pub fn eval_into_char(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<char, EvalError> {
+    match eval(expr, context)? {
+        Expr::Char(ch, _) => Ok(ch),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a character."),
+            expr.span(),
+        )),
     }
 }
 
-/// Evaluate an expression into a number (`f64``).
+/// Evaluate an expression into a number ([`Number`]).
 ///
 /// Check if `expr` evaluates to a number. If so, return the number. Otherwise, return an error message.
 ///
@@ -266,32 +461,34 @@ pub fn eval_into_str(
 /// use rusche::{
 ///     eval::Evaluator,
 ///     expr::Expr,
+///     number::Number,
 ///     utils::eval_into_num,
 /// };
 ///
 /// let evaluator = Evaluator::new();
 /// let expr = Expr::from(12e-3);
 /// let result = eval_into_num("test", &expr, evaluator.context());
-/// assert_eq!(result, Ok(12e-3));
+/// assert_eq!(result, Ok(Number::from(12e-3)));
 /// ```
 pub fn eval_into_num(
     proc_name: &str,
     expr: &Expr,
     context: &EvalContext,
-) -> Result<f64, EvalError> {
+) -> Result<Number, EvalError> {
     match eval(expr, context)? {
         Expr::Num(value, _) => Ok(value),
-        _ => Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a number."),
-            span: expr.span(),
-        }),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a number."),
+            expr.span(),
+        )),
     }
 }
 
 /// Evaluate an expression into an integer (`i32`).
 ///
-/// Check if `expr` evaluates to `f64`` with `fract() == 0``. If so, return the number
-/// as i32. Otherwise, return an error message.
+/// Check if `expr` evaluates to an integer within the range of `i32`. If so, return the
+/// number as i32. Otherwise, return an error message. Inexact numbers are accepted as long
+/// as they have no fractional part.
 ///
 /// # Arguments
 ///
@@ -331,16 +528,25 @@ pub fn eval_into_int(
 ) -> Result<i32, EvalError> {
     let num = eval_into_num(proc_name, expr, context)?;
 
-    if num.fract() == 0.0 {
-        Ok(num as i32)
-    } else {
-        Err(EvalError {
-            message: format!(
+    let int = match num {
+        Number::Real(value) if value.fract() == 0.0 => num.to_exact(),
+        _ => Some(num.clone()),
+    };
+
+    match int.and_then(|int| int.to_i64()) {
+        Some(value) => i32::try_from(value).map_err(|_| {
+            EvalError::new(
+                format!("{proc_name}: {arg_name} is out of range: {num}."),
+                expr.span(),
+            )
+        }),
+        None => Err(EvalError::new(
+            format!(
                 "{}: {} must be an integer, but got {}.",
                 proc_name, arg_name, num
             ),
-            span: expr.span(),
-        })
+            expr.span(),
+        )),
     }
 }
 
@@ -379,10 +585,222 @@ pub fn eval_into_foreign(
 ) -> Result<Rc<dyn Any>, EvalError> {
     match eval(expr, context)? {
         Expr::Foreign(object) => Ok(object),
-        _ => Err(EvalError {
-            message: format!("{proc_name}: `{expr}` does not evaluate to a foreign object."),
-            span: expr.span(),
-        }),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a foreign object."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Evaluate an expression into a vector.
+///
+/// Check if `expr` evaluates to a vector. If so, return the shared reference to the vector.
+/// Otherwise, return an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::Expr,
+///     utils::eval_into_vector,
+/// };
+///
+/// let evaluator = Evaluator::new();
+/// let expr = Expr::Vector(Rc::new(RefCell::new(vec![Expr::from(1)])), None);
+/// let vector = eval_into_vector("test", &expr, evaluator.context()).unwrap();
+/// assert_eq!(vector.borrow().len(), 1);
+/// ```
+pub fn eval_into_vector(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<Vector, EvalError> {
+    match eval(expr, context)? {
+        Expr::Vector(vector, _) => Ok(vector),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a vector."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Evaluate an expression into a bytevector.
+///
+/// Check if `expr` evaluates to a bytevector. If so, return the shared reference to the
+/// bytevector. Otherwise, return an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::Expr,
+///     utils::eval_into_bytevector,
+/// };
+///
+/// let evaluator = Evaluator::new();
+/// let expr = Expr::Bytevector(Rc::new(RefCell::new(vec![1, 2])), None);
+/// let bytevector = eval_into_bytevector("test", &expr, evaluator.context()).unwrap();
+/// assert_eq!(bytevector.borrow().len(), 2);
+/// ```
+pub fn eval_into_bytevector(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<Bytevector, EvalError> {
+    match eval(expr, context)? {
+        Expr::Bytevector(bytes, _) => Ok(bytes),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a bytevector."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Bytes of a bytevector, returned by [`eval_into_bytes`].
+///
+/// Shares the storage of the bytevector instead of copying it. Call [`Bytes::borrow`] to read
+/// the bytes as a slice.
+#[derive(Debug, Clone)]
+pub struct Bytes(Bytevector);
+
+impl Bytes {
+    /// Borrow the bytes as a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytevector is currently mutably borrowed.
+    pub fn borrow(&self) -> Ref<'_, [u8]> {
+        Ref::map(self.0.borrow(), Vec::as_slice)
+    }
+}
+
+/// Evaluate an expression into bytes.
+///
+/// Check if `expr` evaluates to a bytevector. If so, return read-only access to its bytes
+/// without copying them. Otherwise, return an error message. Use [`eval_into_bytevector`]
+/// instead to mutate the bytevector.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::Expr,
+///     utils::eval_into_bytes,
+/// };
+///
+/// let evaluator = Evaluator::new();
+/// let expr = Expr::Bytevector(Rc::new(RefCell::new(vec![0xca, 0xfe])), None);
+/// let bytes = eval_into_bytes("test", &expr, evaluator.context()).unwrap();
+/// assert_eq!(&bytes.borrow()[..], &[0xca, 0xfe]);
+/// ```
+pub fn eval_into_bytes(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<Bytes, EvalError> {
+    eval_into_bytevector(proc_name, expr, context).map(Bytes)
+}
+
+/// Evaluate an expression into a hash table.
+///
+/// Check if `expr` evaluates to a hash table. If so, return the shared reference to the hash
+/// table. Otherwise, return an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::Expr,
+///     hash_table::HashTable,
+///     utils::eval_into_hash_table,
+/// };
+///
+/// let evaluator = Evaluator::new();
+/// let expr = Expr::HashTable(Rc::new(RefCell::new(HashTable::new())), None);
+/// let table = eval_into_hash_table("test", &expr, evaluator.context()).unwrap();
+/// assert!(table.borrow().is_empty());
+/// ```
+pub fn eval_into_hash_table(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<Rc<RefCell<HashTable>>, EvalError> {
+    match eval(expr, context)? {
+        Expr::HashTable(table, _) => Ok(table),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a hash table."),
+            expr.span(),
+        )),
+    }
+}
+
+/// Evaluate an expression into a procedure.
+///
+/// Check if `expr` evaluates to a procedure. If so, return the procedure. Otherwise, return
+/// an error message.
+///
+/// # Arguments
+///
+/// * `proc_name` - Name of the procedure who is calling this function.
+/// * `expr` - Expression to evaluate.
+/// * `context` - Evaluation context.
+///
+/// # Example
+///
+/// ```
+/// use rusche::{
+///     eval::Evaluator,
+///     expr::intern,
+///     utils::eval_into_proc,
+/// };
+///
+/// let evaluator = Evaluator::with_builtin();
+/// let result = eval_into_proc("test", &intern("car"), evaluator.context());
+/// assert!(result.is_ok());
+/// ```
+pub fn eval_into_proc(
+    proc_name: &str,
+    expr: &Expr,
+    context: &EvalContext,
+) -> Result<Proc, EvalError> {
+    match eval(expr, context)? {
+        Expr::Proc(proc, _) => Ok(proc),
+        _ => Err(EvalError::new(
+            format!("{proc_name}: `{expr}` does not evaluate to a procedure."),
+            expr.span(),
+        )),
     }
 }
 
@@ -451,13 +869,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_eval_into_char() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let result = eval_into_char("test", &Expr::from('a'), context);
+        assert_eq!(result, Ok('a'));
+
+        let result = eval_into_char("test", &Expr::from("a"), context);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_eval_into_num() {
         let evaluator = Evaluator::new();
         let context = evaluator.context();
 
         let result = eval_into_num("test", &Expr::from(1), context);
-        assert_eq!(result, Ok(1_f64));
+        assert_eq!(result, Ok(Number::from(1)));
+        assert!(result.unwrap().is_exact());
 
         let result = eval_into_num("test", &Expr::from("1"), context);
         assert!(result.is_err());
@@ -471,9 +902,15 @@ mod tests {
         let result = eval_into_int("test", "index", &Expr::from(1), context);
         assert_eq!(result, Ok(1));
 
+        let result = eval_into_int("test", "index", &Expr::from(1.0), context);
+        assert_eq!(result, Ok(1));
+
         let result = eval_into_int("test", "index", &Expr::from(1.1), context);
         assert!(result.is_err());
 
+        let result = eval_into_int("test", "index", &Expr::from(i64::MAX), context);
+        assert!(result.is_err());
+
         let result = eval_into_int("test", "index", &Expr::from("1"), context);
         assert!(result.is_err());
     }
@@ -491,4 +928,113 @@ mod tests {
         assert!(eval_into_foreign("test", &Expr::from("str"), context).is_err());
         assert!(eval_into_foreign("test", &intern("sym"), context).is_err());
     }
+
+    #[test]
+    fn test_eval_into_vector() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let expr = Expr::Vector(Rc::new(RefCell::new(vec![num(1)])), None);
+        let vector = eval_into_vector("test", &expr, context).unwrap();
+        assert_eq!(*vector.borrow(), vec![num(1)]);
+
+        assert!(eval_into_vector("test", &Expr::from(1), context).is_err());
+        assert!(eval_into_vector("test", &list!(1).into(), context).is_err());
+    }
+
+    #[test]
+    fn test_eval_into_bytevector() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let expr = Expr::Bytevector(Rc::new(RefCell::new(vec![1])), None);
+        let bytevector = eval_into_bytevector("test", &expr, context).unwrap();
+        bytevector.borrow_mut()[0] = 2;
+        let bytes = eval_into_bytes("test", &expr, context).unwrap();
+        assert_eq!(&bytes.borrow()[..], &[2]);
+
+        // The bytes share the storage of the bytevector.
+        bytevector.borrow_mut()[0] = 3;
+        assert_eq!(&bytes.borrow()[..], &[3]);
+
+        let vector = Expr::Vector(Rc::new(RefCell::new(vec![Expr::from(1)])), None);
+        assert!(eval_into_bytevector("test", &vector, context).is_err());
+        assert!(eval_into_bytes("test", &Expr::from("str"), context).is_err());
+    }
+
+    #[test]
+    fn test_eval_into_hash_table() {
+        let evaluator = Evaluator::new();
+        let context = evaluator.context();
+
+        let expr = Expr::HashTable(Rc::new(RefCell::new(HashTable::new())), None);
+        let table = eval_into_hash_table("test", &expr, context).unwrap();
+        assert!(table.borrow().is_empty());
+
+        assert!(eval_into_hash_table("test", &Expr::from(1), context).is_err());
+        let vector = Expr::Vector(Rc::new(RefCell::new(vec![])), None);
+        assert!(eval_into_hash_table("test", &vector, context).is_err());
+    }
+
+    #[test]
+    fn test_eval_into_proc() {
+        let evaluator = Evaluator::with_builtin();
+        let context = evaluator.context();
+
+        assert!(eval_into_proc("test", &intern("car"), context).is_ok());
+        assert!(eval_into_proc("test", &Expr::from(1), context).is_err());
+        assert!(eval_into_proc("test", &intern("undefined"), context).is_err());
+    }
+
+    #[test]
+    fn test_make_formal_args() {
+        let formal_args = |expr: Expr| make_formal_args(&expr).map(|f| f.to_string());
+
+        assert_eq!(
+            formal_args(list!(intern("a"), intern("b")).into()),
+            Ok("(a b)".into())
+        );
+        assert_eq!(formal_args(intern("args")), Ok("args".into()));
+        assert_eq!(
+            formal_args(list!(intern("a"), intern("*rest")).into()),
+            Ok("(a . rest)".into())
+        );
+        assert_eq!(
+            formal_args(
+                list!(
+                    intern("a"),
+                    intern("#!optional"),
+                    list!(intern("b"), 1),
+                    intern("#:key"),
+                    intern("c")
+                )
+                .into()
+            ),
+            Ok("(a #!optional (b 1) #:key c)".into())
+        );
+        let dotted = crate::list::cons(intern("a"), intern("rest"));
+        assert_eq!(formal_args(dotted.into()), Ok("(a . rest)".into()));
+
+        assert!(formal_args(1.into()).is_err());
+        assert!(formal_args(list!(1).into()).is_err());
+        assert!(formal_args(list!(list!(intern("a"), 1)).into()).is_err());
+        assert!(formal_args(list!(intern("*rest"), intern("a")).into()).is_err());
+        assert!(formal_args(list!(intern("#:key"), intern("#!optional")).into()).is_err());
+
+        // duplicate names
+        assert!(formal_args(list!(intern("a"), intern("a")).into()).is_err());
+        assert!(formal_args(list!(intern("a"), intern("*a")).into()).is_err());
+        assert!(formal_args(crate::list::cons(intern("a"), intern("a")).into()).is_err());
+        assert!(formal_args(
+            list!(
+                intern("a"),
+                intern("#!optional"),
+                list!(intern("b"), 1),
+                intern("#:key"),
+                intern("b")
+            )
+            .into()
+        )
+        .is_err());
+    }
 }
@@ -1,7 +1,11 @@
 mod common;
 
 use common::EvalToStr;
-use rusche::eval::{EvalContext, Evaluator};
+use rusche::{
+    eval::{EvalContext, Evaluator},
+    lexer::tokenize,
+    parser::Parser,
+};
 
 fn eval_str(src: &str) -> String {
     Evaluator::with_builtin().eval_to_str(src)
@@ -17,6 +21,276 @@ fn test_cdr() {
     assert_eq!(eval_str("(cdr '(1 2))"), "(2)");
 }
 
+#[test]
+fn test_pairs() {
+    assert_eq!(eval_str("(cons 1 2)"), "(1 . 2)");
+    assert_eq!(eval_str("(cons 1 (cons 2 3))"), "(1 2 . 3)");
+    assert_eq!(eval_str("(car '(1 . 2))"), "1");
+    assert_eq!(eval_str("(cdr '(1 . 2))"), "2");
+    assert_eq!(eval_str("(pair? '(1 . 2))"), "#t");
+    assert_eq!(eval_str("(pair? '())"), "#f");
+    assert_eq!(eval_str("(list? '(1 . 2))"), "#f");
+    assert_eq!(eval_str("(list? '(1 2))"), "#t");
+    assert!(eval_str("(num-add 1 . 2)").starts_with("Err:"));
+}
+
+#[test]
+fn test_vectors() {
+    assert_eq!(eval_str("#(1 \"two\" (3))"), "#(1 \"two\" (3))");
+    assert_eq!(eval_str("(vector? #(1 2))"), "#t");
+    assert_eq!(eval_str("(vector? '(1 2))"), "#f");
+    assert_eq!(eval_str("(vector 1 (num-add 1 1))"), "#(1 2)");
+    assert_eq!(eval_str("(make-vector 3 'a)"), "#(a a a)");
+    assert_eq!(eval_str("(vector-ref #(1 2 3) 1)"), "2");
+    assert_eq!(eval_str("(vector-length #(1 2 3))"), "3");
+    assert_eq!(eval_str("(vector->list #(1 2 3))"), "(1 2 3)");
+    assert_eq!(eval_str("(vector->list #(1 2 3) 1 2)"), "(2)");
+    assert_eq!(eval_str("(list->vector '(1 2 3))"), "#(1 2 3)");
+    assert_eq!(
+        eval_str("(vector-map num-multiply #(1 2 3) #(4 5 6))"),
+        "#(4 10 18)"
+    );
+    assert_eq!(
+        eval_str("`#(1 ,(num-add 1 1) ,@(vector->list #(3 4)))"),
+        "#(1 2 3 4)"
+    );
+    assert!(eval_str("(vector-ref #(1 2 3) 3)").starts_with("Err:"));
+
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define v (make-vector 3 0))");
+    let _ = e.eval_to_str("(vector-set! v 0 'x)");
+    let _ = e.eval_to_str("(vector-fill! v 'y 1)");
+    assert_eq!(e.eval_to_str("v"), "#(x y y)");
+
+    let _ = e.eval_to_str("(define sum 0)");
+    let _ = e.eval_to_str("(vector-for-each (lambda (x) (set! sum (num-add sum x))) #(1 2 3))");
+    assert_eq!(e.eval_to_str("sum"), "6");
+
+    let _ = e.eval_to_str("(define c (make-vector 2 0))");
+    let _ = e.eval_to_str("(vector-set! c 1 c)");
+    assert_eq!(e.eval_to_str("c"), "#(0 #<cycle>)");
+    let _ = e.eval_to_str("(define d (make-vector 2 0))");
+    let _ = e.eval_to_str("(vector-set! d 1 d)");
+    assert_eq!(e.eval_to_str("(equal? c d)"), "#t");
+    let _ = e.eval_to_str("(vector-set! d 0 1)");
+    assert_eq!(e.eval_to_str("(equal? c d)"), "#f");
+}
+
+#[test]
+fn test_bytevectors() {
+    assert_eq!(eval_str("#u8(1 2 255)"), "#u8(1 2 255)");
+    assert_eq!(eval_str("(bytevector? #u8())"), "#t");
+    assert_eq!(eval_str("(bytevector? #(1))"), "#f");
+    assert_eq!(eval_str("(bytevector 1 (num-add 1 1))"), "#u8(1 2)");
+    assert_eq!(eval_str("(make-bytevector 2 9)"), "#u8(9 9)");
+    assert_eq!(eval_str("(bytevector-length #u8(1 2 3))"), "3");
+    assert_eq!(eval_str("(bytevector-u8-ref #u8(1 2 3) 2)"), "3");
+    assert_eq!(eval_str("(bytevector-copy #u8(1 2 3 4) 1 3)"), "#u8(2 3)");
+    assert_eq!(
+        eval_str("(bytevector-append #u8(1) #u8(2 3))"),
+        "#u8(1 2 3)"
+    );
+    assert_eq!(eval_str(r#"(string->utf8 "hi")"#), "#u8(104 105)");
+    assert_eq!(eval_str("(utf8->string #u8(104 105))"), r#""hi""#);
+    assert!(eval_str("(bytevector-u8-ref #u8(1 2 3) 3)").starts_with("Err:"));
+    assert!(eval_str("(utf8->string #u8(255))").starts_with("Err:"));
+
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define b (make-bytevector 3))");
+    let _ = e.eval_to_str("(bytevector-u8-set! b 2 16)");
+    assert_eq!(e.eval_to_str("b"), "#u8(0 0 16)");
+    assert!(e
+        .eval_to_str("(bytevector-u8-set! b 0 256)")
+        .starts_with("Err:"));
+}
+
+#[test]
+fn test_hash_tables() {
+    assert_eq!(
+        eval_str("#hash((a . 1) (\"b\" . 2))"),
+        "#hash((a . 1) (\"b\" . 2))"
+    );
+    assert_eq!(eval_str("(hash-table? #hash())"), "#t");
+    assert_eq!(eval_str("(hash-table? #(1 2))"), "#f");
+    assert_eq!(eval_str("(make-hash-table)"), "#hash()");
+    assert_eq!(eval_str("(hash-table-ref #hash((1 . one)) 1)"), "one");
+    assert_eq!(
+        eval_str("(hash-table-ref #hash((1 . one)) 2 (lambda () 'none))"),
+        "none"
+    );
+    assert_eq!(
+        eval_str("(hash-table-ref/default #hash((1 . one)) 1.0 'none)"),
+        "none"
+    );
+    assert_eq!(eval_str("(hash-table-contains? #hash((#t . 1)) #t)"), "#t");
+    assert_eq!(eval_str("(hash-table-size #hash((a . 1) (b . 2)))"), "2");
+    assert_eq!(
+        eval_str("(hash-table-keys #hash((a . 1) (b . 2)))"),
+        "(a b)"
+    );
+    assert_eq!(
+        eval_str("(hash-table-values #hash((a . 1) (b . 2)))"),
+        "(1 2)"
+    );
+    assert_eq!(
+        eval_str("(hash-table->alist (alist->hash-table '((a . 1) (b . 2) (a . 3))))"),
+        "((a . 1) (b . 2))"
+    );
+    assert!(eval_str("(hash-table-ref #hash() 'a)").starts_with("Err:"));
+    assert!(eval_str("(hash-table-set! (make-hash-table) '(1) 1)").starts_with("Err:"));
+
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define t (make-hash-table))");
+    let _ = e.eval_to_str("(hash-table-set! t 'x 1)");
+    let _ = e.eval_to_str("(hash-table-set! t 1/2 2)");
+    let _ = e.eval_to_str("(hash-table-set! t 'x 3)");
+    assert_eq!(e.eval_to_str("t"), "#hash((x . 3) (1/2 . 2))");
+    let _ = e.eval_to_str("(hash-table-delete! t 'x)");
+    assert_eq!(e.eval_to_str("t"), "#hash((1/2 . 2))");
+
+    let _ = e.eval_to_str("(define sum 0)");
+    let _ = e.eval_to_str(
+        "(hash-table-walk #hash((1 . 2) (3 . 4)) (lambda (k v) (set! sum (num-add sum k v))))",
+    );
+    assert_eq!(e.eval_to_str("sum"), "10");
+
+    let _ = e.eval_to_str("(define h (make-hash-table))");
+    let _ = e.eval_to_str("(hash-table-set! h 'self h)");
+    assert_eq!(e.eval_to_str("h"), "#hash((self . #<cycle>))");
+    assert_eq!(
+        e.eval_to_str("(hash-table-ref h 'self)"),
+        "#hash((self . #<cycle>))"
+    );
+}
+
+#[test]
+fn test_symbols() {
+    assert_eq!(eval_str("(symbol? 'a)"), "#t");
+    assert_eq!(eval_str(r#"(symbol? "a")"#), "#f");
+    assert_eq!(eval_str(r#"(eq? (string->symbol "abc") 'abc)"#), "#t");
+    assert_eq!(eval_str("(symbol->string 'abc)"), r#""abc""#);
+    assert_eq!(eval_str("(eq? (gensym) (gensym))"), "#f");
+
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str(r#"(define g (gensym "tmp"))"#);
+    assert_eq!(e.eval_to_str("(eq? g g)"), "#t");
+    assert_eq!(
+        e.eval_to_str("(eq? g (string->symbol (symbol->string g)))"),
+        "#f"
+    );
+
+    // a gensym does not refer to the variable with the same name
+    let _ = e.eval_to_str("(eval (list 'define (string->symbol (symbol->string g)) ''captured))");
+    assert!(e.eval_to_str("(eval g)").starts_with("Err:"));
+    assert!(e.eval_to_str("(eval (list 'set! g 2))").starts_with("Err:"));
+    assert_eq!(
+        e.eval_to_str("(eval (string->symbol (symbol->string g)))"),
+        "captured"
+    );
+}
+
+#[test]
+fn test_chars() {
+    assert_eq!(eval_str(r"#\a"), r"#\a");
+    assert_eq!(
+        eval_str(r"'(#\space #\newline #\x41)"),
+        r"(#\space #\newline #\A)"
+    );
+    assert_eq!(eval_str(r"(char? #\a)"), "#t");
+    assert_eq!(eval_str(r#"(char? "a")"#), "#f");
+    assert_eq!(eval_str(r"(char->integer #\A)"), "65");
+    assert_eq!(eval_str("(integer->char 955)"), r"#\λ");
+    assert_eq!(eval_str(r"(char-upcase #\a)"), r"#\A");
+    assert_eq!(eval_str(r"(char-alphabetic? #\1)"), "#f");
+    assert_eq!(eval_str(r#"(string-ref "hello" 1)"#), r"#\e");
+    assert_eq!(eval_str(r#"(string->list "abc")"#), r"(#\a #\b #\c)");
+    assert_eq!(eval_str(r"(list->string '(#\a #\space #\b))"), r#""a b""#);
+    assert_eq!(eval_str(r"(eq? #\a #\a)"), "#t");
+    assert!(eval_str(r#"(string-ref "abc" 3)"#).starts_with("Err:"));
+}
+
+#[test]
+fn test_strings() {
+    assert_eq!(
+        eval_str(r#"(string-split "하나 둘  셋")"#),
+        r#"("하나" "둘" "셋")"#
+    );
+    assert_eq!(
+        eval_str(r#"(string-join (string-split "a/b/c" #\/) "::")"#),
+        r#""a::b::c""#
+    );
+    assert_eq!(eval_str(r#"(string-join '("x" "y"))"#), r#""x y""#);
+    assert_eq!(eval_str(r#"(string-index "αβ1" char-numeric?)"#), "2");
+    assert_eq!(eval_str(r#"(string-index "αβγ" #\δ)"#), "#f");
+    assert_eq!(eval_str(r#"(string-contains "日本語テキスト" "テ")"#), "3");
+    assert_eq!(eval_str(r#"(string-prefix? "日本" "日本語")"#), "#t");
+    assert_eq!(eval_str(r#"(string-suffix? "日本" "日本語")"#), "#f");
+    assert_eq!(eval_str(r#"(string-upcase "straße")"#), r#""STRASSE""#);
+    assert_eq!(eval_str(r#"(string-downcase "ΟΔΟΣ")"#), r#""οδος""#);
+    assert_eq!(eval_str(r#"(string-foldcase "ΟΔΟΣ")"#), r#""οδοσ""#);
+    assert_eq!(eval_str(r#"(string-trim "  a  ")"#), r#""a  ""#);
+    assert_eq!(eval_str(r#"(string-trim-right "  a  ")"#), r#""  a""#);
+    assert_eq!(
+        eval_str(r#"(string-trim-both "123abc456" char-numeric?)"#),
+        r#""abc""#
+    );
+    assert_eq!(
+        eval_str(r#"(string-replace "α-β-γ" "-" "→")"#),
+        r#""α→β→γ""#
+    );
+    assert_eq!(eval_str(r#"(string-pad "7" 3 #\0)"#), r#""007""#);
+    assert_eq!(eval_str(r#"(string-pad-right "αβγ" 2)"#), r#""αβ""#);
+    assert_eq!(eval_str(r#"(string-pad-right "α" 3 #\.)"#), r#""α..""#);
+    assert_eq!(eval_str(r#"(string-copy "αβγ" 1)"#), r#""βγ""#);
+    assert_eq!(eval_str("(number->string 255 16)"), r#""ff""#);
+    assert_eq!(eval_str("(number->string -10 2)"), r#""-1010""#);
+    assert_eq!(eval_str(r#"(string->number "ff" 16)"#), "255");
+    assert_eq!(eval_str(r#"(string->number "1/2")"#), "1/2");
+    assert_eq!(eval_str(r#"(string->number "abc")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "inf")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "nan")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "infinity")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "NaN")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "+Infinity")"#), "#f");
+    assert_eq!(eval_str(r#"(string->number "-inf.0")"#), "-inf.0");
+    assert_eq!(eval_str(r#"(string->number "+nan.0")"#), "+nan.0");
+    assert_eq!(eval_str(r#"(string->symbol "λ")"#), "λ");
+    assert!(eval_str(r#"(string-replace "abc" "" "x")"#).starts_with("Err:"));
+    assert!(eval_str(r#"(string-join '("a" 1))"#).starts_with("Err:"));
+}
+
+#[test]
+fn test_records() {
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str(
+        "(define-record-type <point> (make-point x y) point? (x point-x set-point-x!) (y point-y))",
+    );
+    let _ = e.eval_to_str("(define p (make-point 1 2))");
+    assert_eq!(e.eval_to_str("p"), "#<record point x: 1 y: 2>");
+    assert_eq!(e.eval_to_str("(point? p)"), "#t");
+    assert_eq!(e.eval_to_str("(point? '(1 2))"), "#f");
+    assert_eq!(e.eval_to_str("(point-y p)"), "2");
+    let _ = e.eval_to_str("(set-point-x! p 10)");
+    assert_eq!(e.eval_to_str("(point-x p)"), "10");
+
+    // records are compared by identity
+    assert_eq!(e.eval_to_str("(eq? p p)"), "#t");
+    assert_eq!(
+        e.eval_to_str("(eq? (make-point 1 2) (make-point 1 2))"),
+        "#f"
+    );
+
+    assert!(e.eval_to_str("(point-x 1)").starts_with("Err:"));
+    assert!(e.eval_to_str("(make-point 1)").starts_with("Err:"));
+
+    let _ = e.eval_to_str(
+        "(define-record-type node (make-node next) node? (next node-next set-node-next!))",
+    );
+    let _ = e.eval_to_str("(define n (make-node '()))");
+    let _ = e.eval_to_str("(set-node-next! n (vector n))");
+    assert_eq!(e.eval_to_str("n"), "#<record node next: #(#<cycle>)>");
+}
+
 #[test]
 fn test_define_variable() {
     let e = Evaluator::with_builtin();
@@ -26,7 +300,7 @@ fn test_define_variable() {
     let _ = outer_context.eval_to_str("(set! x 2)");
     assert_eq!(outer_context.eval_to_str("x"), "2");
 
-    let inner_context = EvalContext::derive_from(&outer_context);
+    let inner_context = EvalContext::derive_from(outer_context);
 
     let _ = inner_context.eval_to_str("(define y 100)");
     assert_eq!(inner_context.eval_to_str("y"), "100");
@@ -49,25 +323,256 @@ fn test_eval() {
     assert_eq!(eval_str("(eval '(num-add 1 2))"), "3");
 }
 
+#[test]
+fn test_and_or() {
+    assert_eq!(eval_str("(and)"), "#t");
+    assert_eq!(eval_str("(and 1 2 3)"), "3");
+    assert_eq!(eval_str("(and 1 #f 3)"), "#f");
+    assert_eq!(eval_str("(and (pair? 1) (car 1))"), "#f");
+    assert_eq!(eval_str("(or)"), "#f");
+    assert_eq!(eval_str("(or #f 2 3)"), "2");
+    assert_eq!(eval_str("(or (pair? '(1)) (car 1))"), "#t");
+
+    // the last operand is in tail position
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define (count-down n) (or (eq? n 0) (count-down (num-subtract n 1))))");
+    assert_eq!(e.eval_to_str("(count-down 100000)"), "#t");
+}
+
+#[test]
+fn test_let_forms() {
+    assert_eq!(eval_str("(let ((x 1) (y 2)) (num-add x y))"), "3");
+    assert_eq!(eval_str("(let ((x 1)) (let ((x 2) (y x)) y))"), "1");
+    assert_eq!(eval_str("(let* ((x 1) (y (num-add x 1))) y)"), "2");
+    assert_eq!(
+        eval_str(
+            "(letrec ((even? (lambda (n) (if (eq? n 0) #t (odd? (num-subtract n 1)))))
+                      (odd? (lambda (n) (if (eq? n 0) #f (even? (num-subtract n 1))))))
+                (even? 100))"
+        ),
+        "#t"
+    );
+    assert_eq!(eval_str("(letrec* ((x 1) (y (num-add x 1))) y)"), "2");
+    assert!(eval_str("(let ((x)) x)").starts_with("Err:"));
+
+    let e = Evaluator::with_builtin();
+
+    // named let loops in tail position
+    assert_eq!(
+        e.eval_to_str(
+            "(let loop ((i 0) (sum 0)) (if (eq? i 10000) sum (loop (num-add i 1) (num-add sum i))))"
+        ),
+        "49995000"
+    );
+    assert!(e.eval_to_str("loop").starts_with("Err:"));
+
+    // internal defines stay inside the body
+    assert_eq!(e.eval_to_str("(let () (define z 1) z)"), "1");
+    assert!(e.eval_to_str("z").starts_with("Err:"));
+
+    // while does not leak a `loop` binding
+    let _ = e.eval_to_str("(define n 0)");
+    let _ = e.eval_to_str("(while (num-less n 5) (set! n (num-add n 1)))");
+    assert_eq!(e.eval_to_str("n"), "5");
+    assert!(e.eval_to_str("loop").starts_with("Err:"));
+}
+
+#[test]
+fn test_sequencing_forms() {
+    assert_eq!(eval_str("(begin)"), "()");
+    assert_eq!(eval_str("(begin 1 2 3)"), "3");
+    assert_eq!(eval_str("(when (pair? '(1)) 'a 'b)"), "b");
+    assert_eq!(eval_str("(when #f 'a)"), "()");
+    assert_eq!(eval_str("(unless #f 'a 'b)"), "b");
+    assert_eq!(
+        eval_str("(case (num-multiply 2 3) ((2 3 5 7) 'prime) ((1 4 6 8 9) 'composite))"),
+        "composite"
+    );
+    assert_eq!(eval_str("(case 'x ((a) 1) (else 2))"), "2");
+    assert_eq!(
+        eval_str("(case 1 ((1) => (lambda (x) (num-add x 1))))"),
+        "2"
+    );
+    assert_eq!(eval_str("(case 9 ((1) 1) (else => (lambda (x) x)))"), "9");
+    assert_eq!(eval_str("(case 9 ((1) 1) ((2 3) 2))"), "#f");
+    assert_eq!(eval_str("(case 9)"), "#f");
+    assert_eq!(
+        eval_str("(do ((i 0 (num-add i 1)) (acc '() (cons i acc))) ((eq? i 3) acc))"),
+        "(2 1 0)"
+    );
+
+    // each iteration has its own bindings, which closures keep
+    assert_eq!(
+        eval_str(
+            "(do ((i 0 (num-add i 1)) (acc '() (cons (lambda () i) acc))) \
+                 ((eq? i 3) (list ((car acc)) ((car (cdr acc))) ((car (cdr (cdr acc)))))))"
+        ),
+        "(2 1 0)"
+    );
+    assert_eq!(
+        eval_str("(do ((i 0 (num-add i 1)) (j 10)) ((eq? i 2) j) (set! j (num-add j i)))"),
+        "11"
+    );
+
+    let e = Evaluator::with_builtin();
+
+    // begin evaluates in the current environment
+    let _ = e.eval_to_str("(begin (define x 1) (define y 2))");
+    assert_eq!(e.eval_to_str("(num-add x y)"), "3");
+
+    // the last expression is in tail position
+    let _ = e.eval_to_str(
+        "(define (count-down n) (when (num-greater n 0) 'ignored (count-down (num-subtract n 1))))",
+    );
+    assert_eq!(e.eval_to_str("(count-down 10000)"), "()");
+    let _ = e.eval_to_str("(define v (make-vector 3 0))");
+    let _ = e.eval_to_str("(do ((i 0 (num-add i 1))) ((eq? i 3)) (vector-set! v i i))");
+    assert_eq!(e.eval_to_str("v"), "#(0 1 2)");
+}
+
+#[test]
+fn test_cond() {
+    assert_eq!(eval_str("(cond ((pair? 1) (car 1)) (else 'no))"), "no");
+    assert_eq!(eval_str("(cond (#f 1) ((num-add 1 2)))"), "3");
+    assert_eq!(eval_str("(cond ((cdr '(1 2)) => car) (else 'no))"), "2");
+    assert_eq!(eval_str("(cond (#f 1))"), "#f");
+
+    // the chosen body is in tail position
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str(
+        "(define (count-down n) (cond ((eq? n 0) 'done) (else (count-down (num-subtract n 1)))))",
+    );
+    assert_eq!(e.eval_to_str("(count-down 10000)"), "done");
+}
+
+#[test]
+fn test_exceptions() {
+    assert_eq!(eval_str("(guard (e (#t e)) (raise 'boom))"), "boom");
+    assert!(
+        eval_str("(guard (e ((symbol? e) 'sym) ((str? e) 'str)) (raise 1))")
+            .ends_with("uncaught exception: 1")
+    );
+    assert_eq!(
+        eval_str(r#"(guard (e ((error-object? e) (error-object-message e))) (error "bad" 1))"#),
+        r#""bad""#
+    );
+    assert_eq!(
+        eval_str(r#"(guard (e (#t (error-object-irritants e))) (error "bad" 1 'two))"#),
+        "(1 two)"
+    );
+    assert_eq!(
+        eval_str("(guard (e ((error-object? e) (error-object-message e))) (car 1))"),
+        r#""car: `1` does not evaluate to a list.""#
+    );
+    assert_eq!(eval_str("(guard (e (else 'caught)) 1 2)"), "2");
+    assert_eq!(eval_str("(guard (e ((cdr e) => car)) (raise '(1 2)))"), "2");
+    assert_eq!(
+        eval_str("(with-exception-handler (lambda (e) 10) (lambda () (num-add 1 (raise-continuable 'c))))"),
+        "11"
+    );
+    assert_eq!(
+        eval_str(
+            "(guard (e (#t (cons 'outer e)))
+                (with-exception-handler
+                    (lambda (e) (raise (cons 'inner e)))
+                    (lambda () (raise 'boom))))"
+        ),
+        "(outer inner . boom)"
+    );
+    assert_eq!(
+        eval_str(
+            "(guard (e (#t e))
+                (with-exception-handler
+                    (lambda (e) (raise 'handled))
+                    (lambda () (car 1))))"
+        ),
+        "handled"
+    );
+    assert!(eval_str(r#"(error "bad thing:" 42)"#).ends_with("bad thing: 42"));
+
+    // the handler is called in the dynamic context of `raise`, before unwinding
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define log '())");
+    let _ = e.eval_to_str(
+        "(guard (e (#t (set! log (cons 'guard log))))
+            (with-exception-handler
+                (lambda (e) (set! log (cons 'handler log)))
+                (lambda () (raise 'boom))))",
+    );
+    assert_eq!(e.eval_to_str("log"), "(guard handler)");
+
+    // an exception not handled by a guard reaches the handlers outside of it
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(define log '())");
+    let _ = e.eval_to_str("(define (note tag) (lambda (e) (set! log (cons (cons tag e) log))))");
+    let _ = e.eval_to_str(
+        "(guard (e (#t (set! log (cons 'outer-guard log))))
+            (with-exception-handler (note 'outer)
+                (lambda ()
+                    (guard (e ((str? e) 'unreachable))
+                        (with-exception-handler (note 'inner)
+                            (lambda () (raise 'boom)))))))",
+    );
+    assert_eq!(
+        e.eval_to_str("log"),
+        "(outer-guard (outer . boom) (inner . boom))"
+    );
+    let _ = e.eval_to_str("(set! log '())");
+    let _ = e.eval_to_str("(define (note-tag tag) (lambda (e) (set! log (cons tag log))))");
+    assert_eq!(
+        e.eval_to_str(
+            "(guard (e ((error-object? e) (error-object-message e)))
+                (with-exception-handler (note-tag 'outer)
+                    (lambda ()
+                        (guard (e ((str? e) 'unreachable))
+                            (with-exception-handler (note-tag 'inner)
+                                (lambda () (car 1)))))))"
+        ),
+        r#""car: `1` does not evaluate to a list.""#
+    );
+    assert_eq!(e.eval_to_str("log"), "(outer inner)");
+
+    // the body of a guard has its own environment
+    let e = Evaluator::with_builtin();
+    let _ = e.eval_to_str("(guard (e (#t e)) (define local 1) local)");
+    assert!(e.eval_to_str("local").starts_with("Err:"));
+}
+
+#[test]
+fn test_exception_payload() {
+    let e = Evaluator::with_builtin();
+    let tokens = tokenize("(raise '(1 2))", None).unwrap();
+    let expr = Parser::with_tokens(tokens).parse().unwrap().unwrap();
+    let error = e.eval(&expr).unwrap_err();
+    assert_eq!(
+        error.payload.map(|payload| payload.to_string()),
+        Some("(1 2)".to_owned())
+    );
+}
+
 #[test]
 fn test_if() {
     assert_eq!(eval_str("(if 't 1)"), "1");
     assert_eq!(eval_str("(if 't 1 2)"), "1");
 
-    assert_eq!(eval_str("(if '() 1)"), "()");
-    assert_eq!(eval_str("(if '() 1 2)"), "2");
+    assert_eq!(eval_str("(if #f 1)"), "()");
+    assert_eq!(eval_str("(if #f 1 2)"), "2");
 }
 
 #[test]
 fn test_lambda() {
     assert_eq!(eval_str("((lambda (x) (num-multiply x 2)) 5)"), "10");
+    assert_eq!(
+        eval_str("(lambda (a a) a)"),
+        "Err: 1:12: a is duplicated in (a a)."
+    );
 }
 
 #[test]
 fn test_set() {
     let e = Evaluator::with_builtin();
     let outer_context = e.context();
-    let inner_context = EvalContext::derive_from(&outer_context);
+    let inner_context = EvalContext::derive_from(outer_context);
 
     let _ = outer_context.eval_to_str("(define x 1)");
     assert_eq!(outer_context.eval_to_str("x"), "1");
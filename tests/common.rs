@@ -1,5 +1,6 @@
 use rusche::{
-    eval::{eval, EvalContext, Evaluator},
+    eval::{eval_values, EvalContext, Evaluator},
+    expr::Expr,
     lexer::tokenize,
     parser::Parser,
 };
@@ -10,17 +11,17 @@ pub trait EvalToStr {
 
 impl EvalToStr for EvalContext {
     fn eval_to_str(&self, src: &str) -> String {
-        let tokens = tokenize(src, None).expect(&format!("Failed to tokenize: {}", src));
+        let tokens = tokenize(src, None).unwrap_or_else(|_| panic!("Failed to tokenize: {}", src));
         let mut parser = Parser::with_tokens(tokens);
         let Some(expr) = parser
             .parse()
-            .expect(&format!("Failed to parse an expression: {}", src))
+            .unwrap_or_else(|_| panic!("Failed to parse an expression: {}", src))
         else {
             panic!("No expression parsed from: {}", src);
         };
 
-        match eval(&expr, self) {
-            Ok(result) => result.to_string(),
+        match eval_values(&expr, self) {
+            Ok(values) => Expr::Values(values).to_string(),
             Err(error) => format!("Err: {error}"),
         }
     }
@@ -32,3 +32,87 @@ fn test_gc() {
     e.collect_garbage();
     assert_eq!(e.count_unreachable_envs(), 0);
 }
+
+#[test]
+fn test_gc_closure_in_vector() {
+    let e = Evaluator::with_builtin();
+
+    let _ = e.eval_to_str(
+        r#"
+        (define (make-adder n)
+            (define (adder x) (num-add x n))
+            adder)
+        "#,
+    );
+    let _ = e.eval_to_str("(define adders (vector (make-adder 1) (make-adder 2)))");
+    let _ = e.eval_to_str("(vector-set! adders 0 adders)"); // cyclic
+    let _ = e.eval_to_str("(make-adder 3)");
+
+    // envs of `(make-adder 1)` and `(make-adder 3)` are unreachable
+    assert_eq!(e.count_unreachable_envs(), 2);
+
+    e.collect_garbage();
+    assert_eq!(e.count_unreachable_envs(), 0);
+    assert_eq!(e.eval_to_str("((vector-ref adders 1) 10)"), "12");
+
+    // break the cycle, so that the vector can be dropped
+    let _ = e.eval_to_str("(vector-set! adders 0 #f)");
+}
+
+#[test]
+fn test_gc_closure_in_hash_table() {
+    let e = Evaluator::with_builtin();
+
+    let _ = e.eval_to_str(
+        r#"
+        (define (make-adder n)
+            (define (adder x) (num-add x n))
+            adder)
+        "#,
+    );
+    let _ = e.eval_to_str("(define adders (make-hash-table))");
+    let _ = e.eval_to_str("(hash-table-set! adders 'one (make-adder 1))");
+    let _ = e.eval_to_str("(hash-table-set! adders 'two (make-adder 2))");
+    let _ = e.eval_to_str("(hash-table-set! adders 'self adders)"); // cyclic
+    let _ = e.eval_to_str("(hash-table-delete! adders 'one)");
+
+    // env of `(make-adder 1)` is unreachable
+    assert_eq!(e.count_unreachable_envs(), 1);
+
+    e.collect_garbage();
+    assert_eq!(e.count_unreachable_envs(), 0);
+    assert_eq!(e.eval_to_str("((hash-table-ref adders 'two) 10)"), "12");
+
+    // break the cycle, so that the hash table can be dropped
+    let _ = e.eval_to_str("(hash-table-delete! adders 'self)");
+}
+
+#[test]
+fn test_gc_closure_in_record() {
+    let e = Evaluator::with_builtin();
+
+    let _ = e.eval_to_str(
+        r#"
+        (define (make-adder n)
+            (define (adder x) (num-add x n))
+            adder)
+        "#,
+    );
+    let _ =
+        e.eval_to_str("(define-record-type <box> (make-box value) box? (value unbox set-box!))");
+    let _ = e.eval_to_str("(define b (make-box (make-adder 1)))");
+    let _ = e.eval_to_str("(make-adder 2)");
+
+    // env of `(make-adder 2)` is unreachable
+    assert_eq!(e.count_unreachable_envs(), 1);
+
+    e.collect_garbage();
+    assert_eq!(e.count_unreachable_envs(), 0);
+    assert_eq!(e.eval_to_str("((unbox b) 10)"), "11");
+
+    let _ = e.eval_to_str("(set-box! b b)"); // cyclic
+    assert_eq!(e.count_unreachable_envs(), 1);
+
+    // break the cycle, so that the record can be dropped
+    let _ = e.eval_to_str("(set-box! b #f)");
+}
@@ -1,7 +1,7 @@
 mod common;
 
 use common::EvalToStr;
-use rusche::eval::Evaluator;
+use rusche::{eval::Evaluator, lexer::tokenize, parser::Parser};
 
 fn eval_str(src: &str) -> String {
     Evaluator::with_prelude().eval_to_str(src)
@@ -9,8 +9,90 @@ fn eval_str(src: &str) -> String {
 
 #[test]
 fn test_t_f() {
-    assert_eq!(eval_str("#t"), "1");
-    assert_eq!(eval_str("#f"), "()");
+    assert_eq!(eval_str("#t"), "#t");
+    assert_eq!(eval_str("#f"), "#f");
+    assert_eq!(eval_str("#true"), "#t");
+    assert_eq!(eval_str("#false"), "#f");
+}
+
+#[test]
+fn test_predicates() {
+    assert_eq!(eval_str("(eq? 1 1)"), "#t");
+    assert_eq!(eval_str("(eq? 1 2)"), "#f");
+    assert_eq!(eval_str("(num? 1)"), "#t");
+    assert_eq!(eval_str("(str? 1)"), "#f");
+    assert_eq!(eval_str("(null? '())"), "#t");
+    assert_eq!(eval_str("(null? #f)"), "#f");
+}
+
+#[test]
+fn test_exact_numbers() {
+    assert_eq!(eval_str("(exact? 1)"), "#t");
+    assert_eq!(eval_str("(exact? 1.0)"), "#f");
+    assert_eq!(eval_str("(inexact? (+ 1 0.5))"), "#t");
+    assert_eq!(eval_str("(exact->inexact 3)"), "3.0");
+    assert_eq!(eval_str("(inexact->exact 3.0)"), "3");
+    assert_eq!(eval_str("(/ 6 3)"), "2");
+    assert_eq!(eval_str("(/ 1 2)"), "1/2");
+    assert_eq!(eval_str("(/ 1.0 2)"), "0.5");
+    assert!(eval_str("(/ 1 0)").starts_with("Err:"));
+}
+
+#[test]
+fn test_rationals() {
+    assert_eq!(eval_str("(/ 1 3)"), "1/3");
+    assert_eq!(eval_str("1/3"), "1/3");
+    assert_eq!(eval_str("-6/4"), "-3/2");
+    assert_eq!(eval_str("(+ 1/3 1/6)"), "1/2");
+    assert_eq!(eval_str("(+ 1/10 2/10)"), "3/10");
+    assert_eq!(eval_str("(* 3 1/3)"), "1");
+    assert_eq!(eval_str("(exact? 1/3)"), "#t");
+    assert_eq!(eval_str("(< 1/3 0.34)"), "#t");
+    assert_eq!(eval_str("(numerator 6/4)"), "3");
+    assert_eq!(eval_str("(denominator 6/4)"), "2");
+    assert_eq!(eval_str("(floor 7/2)"), "3");
+    assert_eq!(eval_str("(round 7/2)"), "4");
+    assert_eq!(eval_str("(round 5/2)"), "2");
+    assert_eq!(eval_str("(truncate -7/2)"), "-3");
+    assert_eq!(eval_str("(exact->inexact 1/4)"), "0.25");
+    assert_eq!(eval_str("(inexact->exact 0.25)"), "1/4");
+    assert!(eval_str("(/ 1/3 0)").starts_with("Err:"));
+}
+
+#[test]
+fn test_big_integers() {
+    assert_eq!(
+        eval_str("(* 9223372036854775807 9223372036854775807)"),
+        "85070591730234615847396907784232501249"
+    );
+    assert_eq!(
+        eval_str("(- (+ 9223372036854775807 1) 1)"),
+        "9223372036854775807"
+    );
+    assert_eq!(
+        eval_str("123456789012345678901234567890"),
+        "123456789012345678901234567890"
+    );
+    assert_eq!(eval_str("(exact? (* 9223372036854775807 2))"), "#t");
+    assert_eq!(eval_str("(= 9007199254740992.0 9007199254740993)"), "#f");
+    assert_eq!(eval_str("(< 9007199254740992.0 9007199254740993)"), "#t");
+}
+
+#[test]
+fn test_dotted_alist() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str("(define prices '((apple . 3) (banana . 1/2)))");
+    assert_eq!(e.eval_to_str("(assoc 'banana prices)"), "(banana . 1/2)");
+    assert_eq!(e.eval_to_str("(cdr (assoc 'apple prices))"), "3");
+    assert_eq!(e.eval_to_str("(map car prices)"), "(apple banana)");
+}
+
+#[test]
+fn test_char_processing() {
+    assert_eq!(
+        eval_str(r#"(list->string (map char-upcase (string->list "abc")))"#),
+        r#""ABC""#
+    );
 }
 
 #[test]
@@ -26,6 +108,12 @@ fn test_if() {
     assert_eq!(eval_str("(if #t 123 456)"), "123");
     assert_eq!(eval_str("(if #f 123 456)"), "456");
     assert_eq!(eval_str("(if 1 (+ 1 2) (+ 3 4))"), "3");
+    assert_eq!(eval_str("(if 0 (+ 1 2) (+ 3 4))"), "3");
+
+    #[cfg(not(feature = "legacy_falsy_nil"))]
+    assert_eq!(eval_str("(if '() (+ 1 2) (+ 3 4))"), "3");
+
+    #[cfg(feature = "legacy_falsy_nil")]
     assert_eq!(eval_str("(if '() (+ 1 2) (+ 3 4))"), "7");
 }
 
@@ -54,18 +142,18 @@ fn test_let() {
 
 #[test]
 fn test_and_or_not() {
-    assert_eq!(eval_str("(and #f #f)"), "()");
-    assert_eq!(eval_str("(and #f #t)"), "()");
-    assert_eq!(eval_str("(and #t #f)"), "()");
-    assert_eq!(eval_str("(and #t #t)"), "1");
+    assert_eq!(eval_str("(and #f #f)"), "#f");
+    assert_eq!(eval_str("(and #f #t)"), "#f");
+    assert_eq!(eval_str("(and #t #f)"), "#f");
+    assert_eq!(eval_str("(and #t #t)"), "#t");
 
-    assert_eq!(eval_str("(or #f #f)"), "()");
-    assert_eq!(eval_str("(or #f #t)"), "1");
-    assert_eq!(eval_str("(or #t #f)"), "1");
-    assert_eq!(eval_str("(or #t #t)"), "1");
+    assert_eq!(eval_str("(or #f #f)"), "#f");
+    assert_eq!(eval_str("(or #f #t)"), "#t");
+    assert_eq!(eval_str("(or #t #f)"), "#t");
+    assert_eq!(eval_str("(or #t #t)"), "#t");
 
-    assert_eq!(eval_str("(not #f)"), "1");
-    assert_eq!(eval_str("(not #t)"), "()");
+    assert_eq!(eval_str("(not #f)"), "#t");
+    assert_eq!(eval_str("(not #t)"), "#f");
 }
 
 #[test]
@@ -78,10 +166,10 @@ fn test_append() {
 
 #[test]
 fn test_cond() {
-    assert_eq!(eval_str("(cond ('t  0) ('t  1))"), "0");
-    assert_eq!(eval_str("(cond ('t  0) ('() 1))"), "0");
-    assert_eq!(eval_str("(cond ('() 0) ('t  1))"), "1");
-    assert_eq!(eval_str("(cond ('() 0) ('() 1))"), "()");
+    assert_eq!(eval_str("(cond (#t 0) (#t 1))"), "0");
+    assert_eq!(eval_str("(cond (#t 0) (#f 1))"), "0");
+    assert_eq!(eval_str("(cond (#f 0) (#t 1))"), "1");
+    assert_eq!(eval_str("(cond (#f 0) (#f 1))"), "#f");
 }
 
 #[test]
@@ -102,7 +190,7 @@ fn test_pair() {
 fn test_assoc() {
     assert_eq!(eval_str("(assoc 'a '((a 1) (b 2) (c 3)))"), "(a 1)");
     assert_eq!(eval_str("(assoc 'b '((a 1) (b 2) (c 3)))"), "(b 2)");
-    assert_eq!(eval_str("(assoc 'x '((a 1) (b 2) (c 3)))"), "()");
+    assert_eq!(eval_str("(assoc 'x '((a 1) (b 2) (c 3)))"), "#f");
 }
 
 #[test]
@@ -114,3 +202,645 @@ fn test_subst() {
 fn test_reverse() {
     assert_eq!(eval_str("(reverse '(a b c d))"), "(d c b a)");
 }
+
+#[test]
+fn test_call_cc() {
+    assert_eq!(eval_str("(+ 1 (call/cc (lambda (k) (+ 10 (k 2)))))"), "3");
+    assert_eq!(
+        eval_str(
+            "(call-with-current-continuation
+                (lambda (return)
+                    (map (lambda (x) (if (< x 0) (return x) (* x 2))) '(1 -2 3))))"
+        ),
+        "-2"
+    );
+    assert_eq!(
+        eval_str("(call/cc (lambda (k) (map (lambda (x) (* x 2)) '(1 2 3))))"),
+        "(2 4 6)"
+    );
+
+    // nested continuations escape to their own call/cc
+    assert_eq!(
+        eval_str("(call/cc (lambda (outer) (+ 1 (call/cc (lambda (inner) (outer 10))))))"),
+        "10"
+    );
+
+    // continuations are not caught by guard
+    assert_eq!(
+        eval_str("(call/cc (lambda (k) (guard (e (#t 'caught)) (k 'escaped))))"),
+        "escaped"
+    );
+
+    // a continuation cannot be invoked after its call/cc has returned
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str("(define saved (call/cc (lambda (k) k)))");
+    assert!(e.eval_to_str("(saved 1)").starts_with("Err:"));
+    assert_eq!(
+        e.eval_to_str("(guard (e (#t 'caught)) (raise 'x))"),
+        "caught"
+    );
+}
+
+#[test]
+fn test_dynamic_wind() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str("(define log '())");
+    let _ = e.eval_to_str("(define (note x) (set! log (cons x log)))");
+
+    let _ = e.eval_to_str(
+        "(call/cc (lambda (k)
+            (dynamic-wind
+                (lambda () (note 'before))
+                (lambda () (k 'escaped) (note 'not-reached))
+                (lambda () (note 'after)))))",
+    );
+    assert_eq!(e.eval_to_str("(reverse log)"), "(before after)");
+
+    let _ = e.eval_to_str("(set! log '())");
+    let _ = e.eval_to_str(
+        "(guard (e (#t (note e)))
+            (dynamic-wind
+                (lambda () (note 'before))
+                (lambda () (raise 'error))
+                (lambda () (note 'after))))",
+    );
+    assert_eq!(e.eval_to_str("(reverse log)"), "(before after error)");
+
+    assert_eq!(
+        e.eval_to_str("(dynamic-wind (lambda () 1) (lambda () 2) (lambda () 3))"),
+        "2"
+    );
+}
+
+#[test]
+fn test_syntax_rules() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str(
+        "(define-syntax swap!
+            (syntax-rules ()
+                ((_ a b) (let ((tmp a)) (set! a b) (set! b tmp)))))",
+    );
+    let _ = e.eval_to_str("(define tmp 1)");
+    let _ = e.eval_to_str("(define other 2)");
+    let _ = e.eval_to_str("(swap! tmp other)");
+    assert_eq!(e.eval_to_str("(list tmp other)"), "(2 1)");
+
+    // the `t` bound by the expansion does not capture the user's `t`
+    let _ = e.eval_to_str(
+        "(define-syntax my-or
+            (syntax-rules ()
+                ((_) #f)
+                ((_ e) e)
+                ((_ e r ...) (let ((t e)) (if t t (my-or r ...))))))",
+    );
+    assert_eq!(e.eval_to_str("(let ((t 5)) (my-or #f t))"), "5");
+    assert_eq!(e.eval_to_str("(my-or)"), "#f");
+    assert_eq!(e.eval_to_str("(my-or #f #f 3)"), "3");
+
+    // literals and nested ellipses
+    let _ = e.eval_to_str(
+        "(define-syntax my-cond
+            (syntax-rules (else)
+                ((_ (else body ...)) (begin body ...))
+                ((_ (test body ...) clause ...) (if test (begin body ...) (my-cond clause ...)))))",
+    );
+    assert_eq!(e.eval_to_str("(my-cond (#f 1) ((= 1 1) 2) (else 3))"), "2");
+    assert_eq!(e.eval_to_str("(my-cond (#f 1) (else 2 3))"), "3");
+
+    let _ = e.eval_to_str(
+        "(define-syntax my-let*
+            (syntax-rules ()
+                ((_ () body ...) (let () body ...))
+                ((_ ((name val) rest ...) body ...)
+                    (let ((name val)) (my-let* (rest ...) body ...)))))",
+    );
+    assert_eq!(e.eval_to_str("(my-let* ((a 1) (b (+ a 1))) (* a b))"), "2");
+
+    let _ = e.eval_to_str(
+        "(define-syntax flatten
+            (syntax-rules ()
+                ((_ (a ...) ...) '(a ... ...))))",
+    );
+    assert_eq!(e.eval_to_str("(flatten (1 2) () (3))"), "(1 2 3)");
+
+    // quoted symbols in templates are not renamed
+    let _ = e.eval_to_str("(define-syntax sym (syntax-rules () ((_) 'foo)))");
+    assert_eq!(e.eval_to_str("(eq? (sym) 'foo)"), "#t");
+
+    // dotted patterns and vectors
+    let _ = e.eval_to_str("(define-syntax rest (syntax-rules () ((_ a . b) 'b)))");
+    assert_eq!(e.eval_to_str("(rest 1 2 3)"), "(2 3)");
+    let _ = e.eval_to_str("(define-syntax vec (syntax-rules () ((_ #(a ...)) (list a ...))))");
+    assert_eq!(e.eval_to_str("(vec #(1 2 3))"), "(1 2 3)");
+
+    assert!(e.eval_to_str("(swap! 1)").starts_with("Err:"));
+
+    // free symbols in templates refer to the bindings where the macro is defined
+    let _ = e.eval_to_str("(define-syntax my-list (syntax-rules () ((_ a) (list a))))");
+    assert_eq!(
+        e.eval_to_str("(let ((list (lambda (x) 'captured))) (my-list 1))"),
+        "(1)"
+    );
+    let _ = e.eval_to_str("(define counter 0)");
+    let _ = e
+        .eval_to_str("(define-syntax count! (syntax-rules () ((_) (set! counter (+ counter 1)))))");
+    assert_eq!(e.eval_to_str("(let ((counter 10)) (count!) counter)"), "10");
+    assert_eq!(e.eval_to_str("counter"), "1");
+
+    // symbols in templates that end up as data are not renamed
+    let _ = e.eval_to_str(
+        "(define-syntax mk-case (syntax-rules () ((_ x) (case x ((a) 'is-a) (else 'other)))))",
+    );
+    assert_eq!(e.eval_to_str("(mk-case 'a)"), "is-a");
+    assert_eq!(e.eval_to_str("(mk-case 'b)"), "other");
+    let _ = e.eval_to_str("(define-syntax quote-a (syntax-rules () ((_) '(a #(b)))))");
+    assert_eq!(e.eval_to_str("(quote-a)"), "(a #(b))");
+    assert_eq!(e.eval_to_str("(eq? (car (quote-a)) 'a)"), "#t");
+    let _ = e.eval_to_str("(define-syntax qq-a (syntax-rules () ((_ x) `(a ,x))))");
+    assert_eq!(e.eval_to_str("(eq? (car (qq-a 1)) 'a)"), "#t");
+}
+
+#[test]
+fn test_let_syntax() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(
+        e.eval_to_str(
+            "(let-syntax ((double (syntax-rules () ((_ x) (* 2 x)))))
+                (double 21))"
+        ),
+        "42"
+    );
+    assert!(e.eval_to_str("(double 1)").starts_with("Err:"));
+    assert_eq!(
+        e.eval_to_str(
+            "(letrec-syntax
+                ((my-and (syntax-rules ()
+                    ((_) #t)
+                    ((_ e) e)
+                    ((_ e r ...) (if e (my-and r ...) #f)))))
+                (my-and 1 2 3))"
+        ),
+        "3"
+    );
+    assert_eq!(
+        e.eval_to_str(
+            "(let ((x 'outer))
+                (let-syntax ((m (syntax-rules () ((_) x))))
+                    (let ((x 'inner)) (m))))"
+        ),
+        "outer"
+    );
+}
+
+#[test]
+fn test_macroexpand() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(
+        e.eval_to_str("(macroexpand-1 '(defun f (x) (* x x)))"),
+        "(define f (lambda (x) (* x x)))"
+    );
+    assert_eq!(e.eval_to_str("(macroexpand-1 '(list 1 2))"), "(list 1 2)");
+    assert_eq!(e.eval_to_str("(macroexpand-1 '(+ 1 2))"), "(+ 1 2)");
+    assert_eq!(e.eval_to_str("(macroexpand 1)"), "1");
+
+    let _ = e.eval_to_str("(defmacro my-defun (name *rest) `(defun ,name ,@rest))");
+    assert_eq!(
+        e.eval_to_str("(macroexpand-1 '(my-defun f () 1))"),
+        "(defun f () 1)"
+    );
+    assert_eq!(
+        e.eval_to_str("(macroexpand '(my-defun f () 1))"),
+        "(define f (lambda () 1))"
+    );
+
+    let _ = e.eval_to_str("(define-syntax inc! (syntax-rules () ((_ x) (set! x (+ x 1)))))");
+    assert_eq!(e.eval_to_str("(macroexpand '(inc! n))"), "(set! n (+ n 1))");
+}
+
+#[test]
+fn test_gensym_in_macro() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str(
+        "(defmacro my-swap! (a b)
+            (let ((tmp (gensym)))
+                `(let ((,tmp ,a)) (set! ,a ,b) (set! ,b ,tmp))))",
+    );
+    let _ = e.eval_to_str("(define tmp 1)");
+    let _ = e.eval_to_str("(define other 2)");
+    let _ = e.eval_to_str("(my-swap! tmp other)");
+    assert_eq!(e.eval_to_str("(list tmp other)"), "(2 1)");
+}
+
+#[test]
+fn test_macro_with_several_body_expressions() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str(
+        "(defmacro my-inc! (var)
+            (define one 1)
+            (define tmp (gensym))
+            `(let ((,tmp (+ ,var ,one))) (set! ,var ,tmp) ,tmp))",
+    );
+    let _ = e.eval_to_str("(define n 1)");
+    assert_eq!(e.eval_to_str("(my-inc! n)"), "2");
+    assert_eq!(e.eval_to_str("n"), "2");
+    assert_eq!(e.eval_to_str("(car (macroexpand '(my-inc! n)))"), "let");
+    // the defines in the macro body do not leak into the caller
+    assert!(e.eval_to_str("one").starts_with("Err:"));
+}
+
+#[test]
+fn test_evaluator_macroexpand() {
+    let e = Evaluator::with_prelude();
+    let tokens = tokenize("(defun f (x) x)", None).unwrap();
+    let expr = Parser::with_tokens(tokens).parse().unwrap().unwrap();
+    assert_eq!(
+        e.macroexpand(&expr).unwrap().to_string(),
+        "(define f (lambda (x) x))"
+    );
+}
+
+#[test]
+fn test_lambda_formals() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(e.eval_to_str("((lambda (a . rest) rest) 1 2 3)"), "(2 3)");
+    assert_eq!(e.eval_to_str("((lambda args args) 1 (+ 1 1))"), "(1 2)");
+    assert_eq!(e.eval_to_str("((lambda (*args) args) (+ 1 2))"), "(3)");
+
+    let _ = e.eval_to_str("(define (sum a . rest) (+ a (apply + rest)))");
+    assert_eq!(e.eval_to_str("(sum 1 2 3)"), "6");
+
+    let _ =
+        e.eval_to_str("(define (greet name #!optional (greeting \"hello\")) (list greeting name))");
+    assert_eq!(e.eval_to_str("(greet 'bob)"), "(\"hello\" bob)");
+    assert_eq!(e.eval_to_str("(greet 'bob \"hi\")"), "(\"hi\" bob)");
+
+    let _ = e.eval_to_str(
+        "(define (make-window title #:key (width 80) (height (/ width 2)) visible)
+            (list title width height visible))",
+    );
+    assert_eq!(e.eval_to_str("(make-window 'main)"), "(main 80 40 #f)");
+    assert_eq!(
+        e.eval_to_str("(make-window 'main #:height 10 #:width 100)"),
+        "(main 100 10 #f)"
+    );
+    assert_eq!(e.eval_to_str("#:width"), "#:width");
+    assert!(e
+        .eval_to_str("(make-window 'main #:depth 1)")
+        .starts_with("Err:"));
+
+    let _ = e.eval_to_str("(define (pair a b) (cons a b))");
+    assert!(e
+        .eval_to_str("(pair 1)")
+        .ends_with("pair: expects 2 args, but got 1."));
+    assert!(e
+        .eval_to_str("((lambda (a #!optional b) a))")
+        .ends_with("unnamed-closure: expects 1 to 2 args, but got 0."));
+    assert!(e
+        .eval_to_str("(sum)")
+        .ends_with("sum: expects at least 1 arg, but got 0."));
+}
+
+#[test]
+fn test_case_lambda() {
+    let e = Evaluator::with_prelude();
+    let _ = e.eval_to_str(
+        "(define area
+            (case-lambda
+                ((r) (* 3 r r))
+                ((w h) (* w h))
+                ((a b . rest) (list a b rest))))",
+    );
+    assert_eq!(e.eval_to_str("(area 2)"), "12");
+    assert_eq!(e.eval_to_str("(area 2 3)"), "6");
+    assert_eq!(e.eval_to_str("(area 1 2 3 4)"), "(1 2 (3 4))");
+    assert!(e.eval_to_str("(area)").starts_with("Err:"));
+
+    let _ = e.eval_to_str(
+        "(define count-down
+            (case-lambda
+                ((n) (count-down n 0))
+                ((n acc) (if (= n 0) acc (count-down (- n 1) (+ acc 1))))))",
+    );
+    assert_eq!(e.eval_to_str("(count-down 100000)"), "100000");
+}
+
+#[test]
+fn test_procedure_arity() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(e.eval_to_str("(procedure-arity car)"), "(1 . 1)");
+    assert_eq!(e.eval_to_str("(procedure-arity num-add)"), "(0 . #f)");
+    assert_eq!(e.eval_to_str("(procedure-arity /)"), "(1 . #f)");
+    assert_eq!(e.eval_to_str("(procedure-arity -)"), "(1 . #f)");
+    assert_eq!(
+        e.eval_to_str("(procedure-arity (lambda (a b) a))"),
+        "(2 . 2)"
+    );
+    assert_eq!(
+        e.eval_to_str("(procedure-arity (lambda (a #!optional b) a))"),
+        "(1 . 2)"
+    );
+    assert_eq!(
+        e.eval_to_str("(procedure-arity (lambda (a . b) a))"),
+        "(1 . #f)"
+    );
+    assert_eq!(
+        e.eval_to_str("(procedure-arity (case-lambda ((a) a) ((a b c) a)))"),
+        "(1 . 3)"
+    );
+    assert_eq!(e.eval_to_str("(procedure-arity if)"), "#f");
+    assert!(e.eval_to_str("(procedure-arity 1)").starts_with("Err:"));
+
+    // the declared arity of a native procedure is checked when it is called
+    assert_eq!(
+        e.eval_to_str("(/)"),
+        "Err: 1:1-3: num-divide: expects at least 1 arg, but got 0."
+    );
+    assert_eq!(
+        e.eval_to_str("(-)"),
+        "Err: 1:1-3: num-subtract: expects at least 1 arg, but got 0."
+    );
+    assert_eq!(
+        e.eval_to_str("(car '(1) '(2))"),
+        "Err: 1:1-15: car: expects 1 arg, but got 2."
+    );
+    assert_eq!(e.eval_to_str("(/ 2)"), "1/2");
+    assert_eq!(e.eval_to_str("(- 2)"), "-2");
+}
+
+#[test]
+fn test_values() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(e.eval_to_str("(values 1)"), "1");
+    assert_eq!(e.eval_to_str("(values 1 2)"), "1 2");
+    assert_eq!(
+        e.eval_to_str("(call-with-values (lambda () (values 1 2)) +)"),
+        "3"
+    );
+    assert_eq!(
+        e.eval_to_str("(call-with-values (lambda () 1) list)"),
+        "(1)"
+    );
+    assert_eq!(e.eval_to_str("(call-with-values values list)"), "()");
+
+    // continuations pass several arguments as multiple values
+    assert_eq!(
+        e.eval_to_str("(call-with-values (lambda () (call/cc (lambda (k) (k 1 2)))) list)"),
+        "(1 2)"
+    );
+    assert_eq!(
+        e.eval_to_str("(receive (a . rest) (call/cc (lambda (k) (k 1 2 3))) rest)"),
+        "(2 3)"
+    );
+    assert_eq!(e.eval_to_str("(call/cc (lambda (k) (k 1)))"), "1");
+    assert_eq!(
+        e.eval_to_str("(procedure-arity (call/cc (lambda (k) k)))"),
+        "(0 . #f)"
+    );
+
+    // values pass through tail calls
+    e.eval_to_str("(define (div-mod a b) (if #t (values (/ (- a (% a b)) b) (% a b))))");
+    assert_eq!(
+        e.eval_to_str("(receive (q r) (div-mod 7 2) (list q r))"),
+        "(3 1)"
+    );
+    assert_eq!(
+        e.eval_to_str("(receive (q . rest) (values 1 2 3) (list q rest))"),
+        "(1 (2 3))"
+    );
+    assert_eq!(e.eval_to_str("(receive all (values 1 2) all)"), "(1 2)");
+    assert!(e
+        .eval_to_str("(receive (a b) (values 1 2 3) a)")
+        .ends_with("receive: expects 2 args, but got 3."));
+
+    assert_eq!(
+        e.eval_to_str("(let-values (((a b) (values 1 2)) ((c) (values 3))) (list a b c))"),
+        "(1 2 3)"
+    );
+    assert_eq!(
+        e.eval_to_str("(let*-values (((a b) (values 1 2)) ((c) (values (+ a b)))) c)"),
+        "3"
+    );
+    assert!(e
+        .eval_to_str("(let-values (((a) 1) ((b) a)) b)")
+        .starts_with("Err:"));
+
+    e.eval_to_str("(define-values (x y . z) (values 1 2 3 4))");
+    assert_eq!(e.eval_to_str("(list x y z)"), "(1 2 (3 4))");
+
+    // multiple values are not accepted where a single value is expected
+    assert!(e.eval_to_str("(list (values 1 2))").starts_with("Err:"));
+    assert!(e.eval_to_str("(+ 1 (values))").starts_with("Err:"));
+    assert!(e
+        .eval_to_str("(if (values #f #f) 'yes 'no)")
+        .starts_with("Err:"));
+    assert!(e.eval_to_str("(define v (values 1 2))").starts_with("Err:"));
+    assert!(e.eval_to_str("v").starts_with("Err:"));
+    assert_eq!(e.eval_to_str("(list (values 1))"), "(1)");
+    assert_eq!(e.eval_to_str("(begin (values 1 2) 3)"), "3");
+    assert_eq!(e.eval_to_str("((lambda () (values 1 2) 3))"), "3");
+}
+
+#[test]
+fn test_evaluator_eval_values() {
+    let e = Evaluator::with_prelude();
+    let eval_values = |src| {
+        let tokens = tokenize(src, None).unwrap();
+        let expr = Parser::with_tokens(tokens).parse().unwrap().unwrap();
+        e.eval_values(&expr).unwrap()
+    };
+    assert_eq!(eval_values("(values 1 \"a\")"), vec![1.into(), "a".into()]);
+    assert_eq!(eval_values("1"), vec![1.into()]);
+    assert_eq!(eval_values("(values)"), vec![]);
+}
+
+#[test]
+fn test_promise() {
+    let e = Evaluator::with_prelude();
+    e.eval_to_str("(define count 0)");
+    e.eval_to_str("(define p (delay (begin (set! count (+ count 1)) count)))");
+    assert_eq!(e.eval_to_str("(promise? p)"), "#t");
+    assert_eq!(e.eval_to_str("(promise? 1)"), "#f");
+    assert_eq!(e.eval_to_str("(force p)"), "1");
+    assert_eq!(e.eval_to_str("(force p)"), "1");
+    assert_eq!(e.eval_to_str("count"), "1");
+    assert_eq!(e.eval_to_str("(force 2)"), "2");
+    assert_eq!(e.eval_to_str("(force (make-promise 3))"), "3");
+    assert_eq!(e.eval_to_str("(eq? p (make-promise p))"), "#t");
+
+    // a promise forcing itself keeps the first value (R7RS)
+    e.eval_to_str("(define count 0)");
+    e.eval_to_str(
+        "(define p (delay (begin (set! count (+ count 1)) (if (> count x) count (force p)))))",
+    );
+    e.eval_to_str("(define x 5)");
+    assert_eq!(e.eval_to_str("(force p)"), "6");
+    e.eval_to_str("(set! x 10)");
+    assert_eq!(e.eval_to_str("(force p)"), "6");
+
+    // long delay-force chains are forced iteratively
+    e.eval_to_str("(define (loop n) (delay-force (if (= n 0) (delay 'done) (loop (- n 1)))))");
+    assert_eq!(e.eval_to_str("(force (loop 20000))"), "done");
+
+    assert!(e
+        .eval_to_str("(force (delay-force 1))")
+        .ends_with("force: `1` is not a promise."));
+}
+
+#[test]
+fn test_streams() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(e.eval_to_str("(stream->list (stream 1 2 3))"), "(1 2 3)");
+    assert_eq!(e.eval_to_str("(stream-null? stream-null)"), "#t");
+    assert_eq!(e.eval_to_str("(stream-pair? (stream 1))"), "#t");
+    assert_eq!(e.eval_to_str("(stream-car (stream-cdr (stream 1 2)))"), "2");
+    assert!(e
+        .eval_to_str("(stream-car stream-null)")
+        .starts_with("Err:"));
+
+    // elements are evaluated lazily, and only once
+    e.eval_to_str("(define count 0)");
+    e.eval_to_str("(define s (stream-cons (begin (set! count (+ count 1)) 1) (error \"boom\")))");
+    assert_eq!(e.eval_to_str("count"), "0");
+    assert_eq!(e.eval_to_str("(+ (stream-car s) (stream-car s))"), "2");
+    assert_eq!(e.eval_to_str("count"), "1");
+
+    assert_eq!(
+        e.eval_to_str("(stream->list (stream-map (lambda (x) (* x x)) (stream-from 1)) 5)"),
+        "(1 4 9 16 25)"
+    );
+    assert_eq!(
+        e.eval_to_str("(stream->list (stream-range 10 0 -3))"),
+        "(10 7 4 1)"
+    );
+    assert_eq!(
+        e.eval_to_str("(stream->list (stream-append (list->stream '(1 2)) (stream 3)))"),
+        "(1 2 3)"
+    );
+    assert_eq!(
+        e.eval_to_str("(stream->list (stream-take 3 (stream-iterate (lambda (x) (* x 2)) 1)))"),
+        "(1 2 4)"
+    );
+    assert_eq!(e.eval_to_str("(stream-ref (stream-from 0 2) 10)"), "20");
+    assert_eq!(
+        e.eval_to_str("(stream-fold + 0 (stream-take 100 (stream-from 1)))"),
+        "5050"
+    );
+
+    // large lazy pipelines do not grow the stack
+    assert_eq!(
+        e.eval_to_str(
+            "(stream-car (stream-filter (lambda (x) (= x 5000)) (stream-drop 10 (stream-from 0))))"
+        ),
+        "5000"
+    );
+    e.eval_to_str("(define-stream (evens s) (stream-filter (lambda (x) (= (% x 2) 0)) s))");
+    assert_eq!(
+        e.eval_to_str("(stream->list (evens (stream-range 0 10)))"),
+        "(0 2 4 6 8)"
+    );
+}
+
+#[test]
+fn test_parameterize() {
+    let e = Evaluator::with_prelude();
+    e.eval_to_str("(define radix (make-parameter 10))");
+    e.eval_to_str("(define (show) (radix))");
+    assert_eq!(e.eval_to_str("(show)"), "10");
+    assert_eq!(e.eval_to_str("(parameterize ((radix 2)) (show))"), "2");
+    assert_eq!(
+        e.eval_to_str("(parameterize ((radix 2)) (parameterize ((radix 8)) (show)))"),
+        "8"
+    );
+    assert_eq!(e.eval_to_str("(show)"), "10");
+
+    // the value is restored when the body exits by an error or a continuation
+    assert!(e
+        .eval_to_str("(parameterize ((radix 2)) (error \"boom\"))")
+        .starts_with("Err:"));
+    assert_eq!(e.eval_to_str("(show)"), "10");
+    assert_eq!(
+        e.eval_to_str("(call/cc (lambda (k) (parameterize ((radix 2)) (k (show)))))"),
+        "2"
+    );
+    assert_eq!(e.eval_to_str("(show)"), "10");
+
+    // converters apply to the initial and the parameterized values
+    e.eval_to_str("(define width (make-parameter 1 (lambda (x) (* x 10))))");
+    assert_eq!(e.eval_to_str("(width)"), "10");
+    assert_eq!(e.eval_to_str("(parameterize ((width 2)) (width))"), "20");
+
+    assert!(e
+        .eval_to_str("(parameterize ((show 1)) 1)")
+        .ends_with("is not a parameter object."));
+    assert!(e.eval_to_str("(radix 1)").starts_with("Err:"));
+}
+
+#[test]
+fn test_host_parameterize() {
+    use rusche::{eval, Expr, Parameter, Proc};
+
+    let e = Evaluator::with_prelude();
+    let locale = Parameter::new("en".into());
+    e.root_env()
+        .define("locale", Expr::Proc(Proc::Parameter(locale.clone()), None));
+
+    let tokens = tokenize("(locale)", None).unwrap();
+    let expr = Parser::with_tokens(tokens).parse().unwrap().unwrap();
+    let result = e
+        .context()
+        .parameterize(vec![(locale.clone(), "ko".into())], |context| {
+            eval(&expr, context)
+        });
+    assert_eq!(result, Ok("ko".into()));
+    assert_eq!(e.eval(&expr), Ok("en".into()));
+}
+
+#[test]
+fn test_equivalence() {
+    let e = Evaluator::with_prelude();
+    e.eval_to_str("(define x '(1 2))");
+    assert_eq!(e.eval_to_str("(eq? x x)"), "#t");
+    assert_eq!(e.eval_to_str("(eq? (list 1 2) (list 1 2))"), "#f");
+    assert_eq!(e.eval_to_str("(eq? car car)"), "#t");
+    assert_eq!(e.eval_to_str("(eqv? 2 2)"), "#t");
+    assert_eq!(e.eval_to_str("(eqv? 2 2.0)"), "#f");
+    assert_eq!(e.eval_to_str("(eqv? (cdr x) (cdr x))"), "#t");
+    assert_eq!(e.eval_to_str("(eqv? (vector 1) (vector 1))"), "#f");
+    assert_eq!(e.eval_to_str("(eqv? 1.5 1.5)"), "#t");
+    assert_eq!(e.eval_to_str("(eqv? 0.0 -0.0)"), "#f");
+    assert_eq!(e.eval_to_str("(= 0.0 -0.0)"), "#t");
+
+    // procedures are compared by identity
+    assert_eq!(e.eval_to_str("(eq? (lambda (x) x) (lambda (x) x))"), "#f");
+    assert_eq!(e.eval_to_str("(let ((p (lambda (x) x))) (eq? p p))"), "#t");
+    e.eval_to_str("(define (id x) x)");
+    assert_eq!(e.eval_to_str("(eqv? id id)"), "#t");
+    assert_eq!(
+        e.eval_to_str("(equal? (case-lambda ((x) x)) (case-lambda ((x) x)))"),
+        "#f"
+    );
+    assert_eq!(e.eval_to_str("(equal? (list 1 2) x)"), "#t");
+    assert_eq!(
+        e.eval_to_str("(equal? (vector 1 '(2)) (vector 1 '(2)))"),
+        "#t"
+    );
+    assert_eq!(e.eval_to_str("(equal? \"abc\" \"abc\")"), "#t");
+    assert_eq!(e.eval_to_str("(equal? 2 2.0)"), "#f");
+    assert_eq!(e.eval_to_str("(= 2 2.0)"), "#t");
+}
+
+#[test]
+fn test_member() {
+    let e = Evaluator::with_prelude();
+    assert_eq!(e.eval_to_str("(member '(a) '(b (a) c))"), "((a) c)");
+    assert_eq!(e.eval_to_str("(memq '(a) '(b (a) c))"), "#f");
+    assert_eq!(e.eval_to_str("(memq 'a '(b a c))"), "(a c)");
+    assert_eq!(e.eval_to_str("(memv 1.0 '(1 1.0 2))"), "(1.0 2)");
+    assert_eq!(e.eval_to_str("(member 2.0 '(1 2 3) =)"), "(2 3)");
+    assert_eq!(e.eval_to_str("(member 4 '(1 2 3))"), "#f");
+
+    assert_eq!(e.eval_to_str("(assoc '(b) '(((a)) ((b) 2)))"), "((b) 2)");
+    assert_eq!(e.eval_to_str("(assq '(b) '(((a)) ((b) 2)))"), "#f");
+    assert_eq!(e.eval_to_str("(assv 2 '((1 one) (2 two)))"), "(2 two)");
+    assert_eq!(e.eval_to_str("(assoc 2.0 '((1 one) (2 two)) =)"), "(2 two)");
+}
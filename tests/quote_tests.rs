@@ -10,6 +10,9 @@ fn test_quote() {
     assert_eq!(e.eval_to_str("'(1)"), "(1)");
     assert_eq!(e.eval_to_str("'(1 2)"), "(1 2)");
     assert_eq!(e.eval_to_str("'(1 2 (3))"), "(1 2 (3))");
+    assert_eq!(e.eval_to_str("'(1 . 2)"), "(1 . 2)");
+    assert_eq!(e.eval_to_str("'(1 2 . 3)"), "(1 2 . 3)");
+    assert_eq!(e.eval_to_str("'(1 . (2 . (3 . ())))"), "(1 2 3)");
 }
 
 #[test]
@@ -24,4 +27,13 @@ fn test_quasiquote() {
 
     // `(0 ,@'(1 2 (3 4)) 5) => (0 1 2 (3 4) 5)
     assert_eq!(e.eval_to_str("`(0 ,@'(1 2 (3 4)) 5)"), "(0 1 2 (3 4) 5)");
+
+    // `(0 . 1) => (0 . 1)
+    assert_eq!(e.eval_to_str("`(0 . 1)"), "(0 . 1)");
+
+    // `(0 . ,(+ 1 2)) => (0 . 3)
+    assert_eq!(e.eval_to_str("`(0 . ,(num-add 1 2))"), "(0 . 3)");
+
+    // `(,@'() . ,(+ 1 2)) => 3
+    assert_eq!(e.eval_to_str("`(,@'() . ,(num-add 1 2))"), "3");
 }